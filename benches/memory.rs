@@ -0,0 +1,64 @@
+//! Compares the memory used by the typed storage of a `Series` with the memory used by a vector of `DataEntry`, and
+//! times reading every entry of the series back with `get`.
+//!
+//! Run with `cargo bench --bench memory`.
+
+extern crate raccoon;
+
+use raccoon::{DataEntry, DataType, Series};
+
+use std::mem::size_of;
+use std::time::Instant;
+
+const ENTRIES: usize = 1_000_000;
+
+fn main() {
+    println!("{} entries per series, every tenth entry missing", ENTRIES);
+    println!(
+        "{:<10} {:>16} {:>16} {:>10} {:>12} {:>12}",
+        "type", "Vec<DataEntry>", "Series", "ratio", "push time", "get time",
+    );
+    compare(DataType::Integer, |idx| DataEntry::Integer(idx as i32));
+    compare(DataType::Long, |idx| DataEntry::Long(idx as i64));
+    compare(DataType::Double, |idx| DataEntry::Double(idx as f64 / 7.0));
+    compare(DataType::Boolean, |idx| DataEntry::Boolean(idx % 3 == 0));
+    compare(DataType::Character, |idx| DataEntry::Character((b'a' + (idx % 26) as u8) as char));
+    compare(DataType::Text, |idx| DataEntry::Text(format!("city-{}", idx % 1000)));
+}
+
+/// Fills a series of `data_type` with the entries built by `entry` and prints its memory usage next to the one of a
+/// vector holding the same entries.
+fn compare<F>(data_type: DataType, entry: F) where F: Fn(usize) -> DataEntry {
+    let entries: Vec<DataEntry> = (0..ENTRIES)
+        .map(|idx| if idx % 10 == 9 { DataEntry::NA } else { entry(idx) })
+        .collect();
+    let heap: usize = entries.iter().map(|entry| match *entry {
+        DataEntry::Text(ref text) => text.capacity(),
+        _                         => 0,
+    }).sum();
+    let vector = entries.capacity() * size_of::<DataEntry>() + heap;
+
+    let start = Instant::now();
+    let mut series = Series::with_capacity(format!("{:?}", data_type), data_type.clone(), ENTRIES);
+    for entry in entries {
+        series.push_entry(entry).unwrap();
+    }
+    let elapsed = start.elapsed();
+
+    let typed = series.memory_usage();
+
+    let start = Instant::now();
+    let missing = (0..series.len()).filter(|&idx| series.get(idx) == Some(DataEntry::NA)).count();
+    let reading = start.elapsed();
+    assert_eq!(missing, ENTRIES / 10);
+
+    println!(
+        "{:<10} {:>14} B {:>14} B {:>9.1}x {:>9.1} ms {:>9.1} ms",
+        format!("{:?}", data_type),
+        vector,
+        typed,
+        vector as f64 / typed as f64,
+        elapsed.as_secs_f64() * 1000.0,
+        reading.as_secs_f64() * 1000.0,
+    );
+}
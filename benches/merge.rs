@@ -0,0 +1,47 @@
+//! Times joins of two dataframes of a million rows each, on a single key column and on two key columns.
+//!
+//! Run with `cargo bench --bench merge`.
+
+extern crate raccoon;
+
+use raccoon::{DataFrame, JoinType, Merge, Series};
+
+use std::time::Instant;
+
+const ROWS: usize = 1_000_000;
+
+fn main() {
+    println!("{} rows per dataframe, about half of the keys matching", ROWS);
+    println!("{:<8} {:<10} {:>12} {:>12}", "keys", "how", "rows", "time");
+    let (left, right) = frames();
+    for keys in [&["id"][..], &["id", "city"][..]].iter() {
+        for &how in [JoinType::Inner, JoinType::Left, JoinType::Outer].iter() {
+            let start = Instant::now();
+            let merged = Merge::new(&left, &right).on(keys).how(how).join().unwrap();
+            let elapsed = start.elapsed();
+            println!(
+                "{:<8} {:<10} {:>12} {:>9.1} ms",
+                keys.len(),
+                format!("{:?}", how),
+                merged.len(),
+                elapsed.as_secs_f64() * 1000.0,
+            );
+        }
+    }
+}
+
+/// Builds the left and the right dataframe. Ids of the left one are the even numbers below `2 * ROWS` and the ids of
+/// the right one are the multiples of four below `4 * ROWS`, so that half of the rows find a match on either side.
+fn frames() -> (DataFrame, DataFrame) {
+    let left = DataFrame::from_series(vec![
+        Series::from_vector("id".to_owned(), (0..ROWS).map(|row| 2 * row as i64).collect()),
+        Series::from_vector("city".to_owned(), (0..ROWS).map(|row| format!("city-{}", row % 100)).collect()),
+        Series::from_vector("price".to_owned(), (0..ROWS).map(|row| row as f64 / 7.0).collect()),
+    ]).unwrap();
+    let right = DataFrame::from_series(vec![
+        Series::from_vector("id".to_owned(), (0..ROWS).map(|row| 4 * row as i64).collect()),
+        Series::from_vector("city".to_owned(), (0..ROWS).map(|row| format!("city-{}", 2 * row % 100)).collect()),
+        Series::from_vector("amount".to_owned(), (0..ROWS).map(|row| row as i32).collect()),
+    ]).unwrap();
+    (left, right)
+}
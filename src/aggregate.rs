@@ -0,0 +1,544 @@
+//! Aggregate operations reducing a [`Series`] to a single [`DataEntry`].
+//!
+//! [`Series`]: ../series/struct.Series.html
+//! [`DataEntry`]: ../entry/enum.DataEntry.html
+//!
+//! The aggregations are usually called directly on a series, such as `series.sum()`, which skips missing entries,
+//! that is `DataEntry::NA` and NaN floating point numbers. An [`Aggregator`] allows to change this behaviour, or the
+//! degrees of freedom used to compute the variance and standard deviation.
+//!
+//! [`Aggregator`]: ./struct.Aggregator.html
+//!
+//! # Data Types
+//! Numerical aggregations (`sum`, `prod`, `mean`, `var`, `std`, `median` and `quantile`) are only defined for
+//! numerical series, and fail with `RaccoonError::UnsupportedOperation` on text, character, or boolean series. The
+//! entry returned is widened to avoid overflows and loss of precision:
+//! - `sum` and `prod` return a `DataEntry::Long` for signed integers, a `DataEntry::ULong` for unsigned integers and a
+//!   `DataEntry::Double` for floating point numbers.
+//! - `mean`, `var`, `std`, `median` and `quantile` always return a `DataEntry::Double`.
+//!
+//! Ordering aggregations (`min`, `max`, `argmin` and `argmax`) are defined for every data type, and `min` and `max`
+//! return an entry of the data type of the series. `count`, `argmin` and `argmax` return a `DataEntry::ULong`.
+//!
+//! If there is no entry to aggregate, for instance in an empty series, the result is `DataEntry::NA`, except for
+//! `count`, `sum` and `prod` which return 0, 0 and 1 respectively.
+//!
+//! # Examples
+//! ```
+//! use raccoon::{DataEntry, Series};
+//!
+//! let mut series = Series::from(vec![4, 1, 3]);
+//! series.push_entry(DataEntry::NA).unwrap();
+//!
+//! assert_eq!(series.sum().unwrap(), DataEntry::Long(8));
+//! assert_eq!(series.mean().unwrap(), DataEntry::Double(8.0 / 3.0));
+//! assert_eq!(series.argmin().unwrap(), DataEntry::ULong(1));
+//!
+//! // propagate `DataEntry::NA` instead
+//! assert_eq!(series.aggregate().skip_na(false).sum().unwrap(), DataEntry::NA);
+//! ```
+
+use entry::{DataEntry, DataType};
+use error::{RaccoonResult, RaccoonError};
+use series::Series;
+
+use std::cmp::Ordering;
+
+/// Computes aggregations over a series using configurable options.
+///
+/// An `Aggregator` is obtained using [`Series::aggregate`]. By default it skips missing entries and uses one degree
+/// of freedom for the variance and standard deviation, like the aggregation methods of `Series`.
+///
+/// [`Series::aggregate`]: ../series/struct.Series.html#method.aggregate
+///
+/// # Example
+/// ```
+/// use raccoon::{DataEntry, Series};
+///
+/// let series = Series::from(vec![2.0f64, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]);
+///
+/// // population standard deviation
+/// let std = series.aggregate().ddof(0).std().unwrap();
+/// assert_eq!(std, DataEntry::Double(2.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Aggregator<'a> {
+    series: &'a Series,
+    skip_na: bool,
+    ddof: usize,
+}
+
+/// The family of numerical data types, which determines the widened type of sums and products.
+enum Numeric {
+    Signed,
+    Unsigned,
+    Floating,
+}
+
+impl<'a> Aggregator<'a> {
+    /// Constructs a new `Aggregator` over `series` using the default options.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataEntry, Series};
+    /// # use raccoon::aggregate::Aggregator;
+    /// let series = Series::from(vec![1, 2, 3]);
+    /// assert_eq!(Aggregator::new(&series).max().unwrap(), DataEntry::Integer(3));
+    /// ```
+    pub fn new(series: &'a Series) -> Aggregator<'a> {
+        Aggregator {
+            series,
+            skip_na: true,
+            ddof: 1,
+        }
+    }
+
+    /// Sets whether missing entries, `DataEntry::NA` or NaN, are skipped. If they are not skipped, any missing entry
+    /// in the series makes the aggregation return `DataEntry::NA`. This has no effect on `count`.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataEntry, Series};
+    /// let series = Series::from(vec![DataEntry::Double(1.5), DataEntry::NA]);
+    /// assert_eq!(series.aggregate().skip_na(true).max().unwrap(), DataEntry::Double(1.5));
+    /// assert_eq!(series.aggregate().skip_na(false).max().unwrap(), DataEntry::NA);
+    /// ```
+    pub fn skip_na(mut self, skip_na: bool) -> Aggregator<'a> {
+        self.skip_na = skip_na;
+        self
+    }
+
+    /// Sets the delta degrees of freedom used by `var` and `std`. The divisor used is `n - ddof`, where `n` is the
+    /// number of entries aggregated. Defaults to 1, giving the sample variance.
+    pub fn ddof(mut self, ddof: usize) -> Aggregator<'a> {
+        self.ddof = ddof;
+        self
+    }
+
+    /// Returns the number of entries that are not missing, as a `DataEntry::ULong`.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataEntry, Series};
+    /// let series = Series::from(vec![DataEntry::from("a"), DataEntry::NA, DataEntry::from("b")]);
+    /// assert_eq!(series.aggregate().count().unwrap(), DataEntry::ULong(2));
+    /// ```
+    pub fn count(&self) -> RaccoonResult<DataEntry> {
+        let count = self.series.iter().filter(|x| !x.is_missing()).count();
+        Ok(DataEntry::ULong(count as u64))
+    }
+
+    /// Returns the sum of the entries.
+    ///
+    /// # Errors
+    /// - `RaccoonError::UnsupportedOperation` if the series is not numerical.
+    /// - `RaccoonError::ArithmeticOverflow` if the sum of integers does not fit into the widened type.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataEntry, Series};
+    /// let series = Series::from(vec![1u32, 2, 3]);
+    /// assert_eq!(series.aggregate().sum().unwrap(), DataEntry::ULong(6));
+    /// ```
+    pub fn sum(&self) -> RaccoonResult<DataEntry> {
+        let numeric = numeric("sum", self.series.data_type())?;
+        let entries = match self.entries() {
+            Some(entries) => entries,
+            None          => return Ok(DataEntry::NA),
+        };
+        match numeric {
+            Some(Numeric::Floating) => Ok(DataEntry::Double(entries.iter().filter_map(as_f64).sum())),
+            Some(numeric)           => {
+                let mut sum = 0i128;
+                for entry in entries.iter().filter_map(as_i128) {
+                    sum = sum.checked_add(entry).ok_or(RaccoonError::ArithmeticOverflow)?;
+                }
+                widen(sum, &numeric)
+            },
+            None                    => Ok(DataEntry::NA),
+        }
+    }
+
+    /// Returns the product of the entries.
+    ///
+    /// # Errors
+    /// - `RaccoonError::UnsupportedOperation` if the series is not numerical.
+    /// - `RaccoonError::ArithmeticOverflow` if the product of integers does not fit into the widened type.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataEntry, Series};
+    /// let series = Series::from(vec![-1, 2, 3]);
+    /// assert_eq!(series.aggregate().prod().unwrap(), DataEntry::Long(-6));
+    /// ```
+    pub fn prod(&self) -> RaccoonResult<DataEntry> {
+        let numeric = numeric("prod", self.series.data_type())?;
+        let entries = match self.entries() {
+            Some(entries) => entries,
+            None          => return Ok(DataEntry::NA),
+        };
+        match numeric {
+            Some(Numeric::Floating) => Ok(DataEntry::Double(entries.iter().filter_map(as_f64).product())),
+            Some(numeric)           => {
+                let mut product = 1i128;
+                for entry in entries.iter().filter_map(as_i128) {
+                    product = product.checked_mul(entry).ok_or(RaccoonError::ArithmeticOverflow)?;
+                }
+                widen(product, &numeric)
+            },
+            None                    => Ok(DataEntry::NA),
+        }
+    }
+
+    /// Returns the arithmetic mean of the entries.
+    ///
+    /// # Errors
+    /// `RaccoonError::UnsupportedOperation` if the series is not numerical.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataEntry, Series};
+    /// let series = Series::from(vec![1, 2, 3, 4]);
+    /// assert_eq!(series.aggregate().mean().unwrap(), DataEntry::Double(2.5));
+    /// ```
+    pub fn mean(&self) -> RaccoonResult<DataEntry> {
+        let values = match self.values("mean")? {
+            Some(ref values) if !values.is_empty() => values.clone(),
+            _                                      => return Ok(DataEntry::NA),
+        };
+        Ok(DataEntry::Double(mean(&values)))
+    }
+
+    /// Returns the variance of the entries, using `n - ddof` as divisor. The result is `DataEntry::NA` if there are
+    /// not more entries than degrees of freedom.
+    ///
+    /// # Errors
+    /// `RaccoonError::UnsupportedOperation` if the series is not numerical.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataEntry, Series};
+    /// let series = Series::from(vec![1, 2, 3, 4]);
+    /// assert_eq!(series.aggregate().var().unwrap(), DataEntry::Double(5.0 / 3.0));
+    /// ```
+    pub fn var(&self) -> RaccoonResult<DataEntry> {
+        let values = match self.values("var")? {
+            Some(ref values) if values.len() > self.ddof => values.clone(),
+            _                                            => return Ok(DataEntry::NA),
+        };
+        let mean = mean(&values);
+        let squares: f64 = values.iter().map(|x| (x - mean) * (x - mean)).sum();
+        Ok(DataEntry::Double(squares / (values.len() - self.ddof) as f64))
+    }
+
+    /// Returns the standard deviation of the entries, which is the square root of the variance.
+    ///
+    /// # Errors
+    /// `RaccoonError::UnsupportedOperation` if the series is not numerical.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataEntry, Series};
+    /// let series = Series::from(vec![1.0f32, 3.0]);
+    /// assert_eq!(series.aggregate().std().unwrap(), DataEntry::Double(2f64.sqrt()));
+    /// ```
+    pub fn std(&self) -> RaccoonResult<DataEntry> {
+        match self.var()? {
+            DataEntry::Double(var) => Ok(DataEntry::Double(var.sqrt())),
+            entry                  => Ok(entry),
+        }
+    }
+
+    /// Returns the median of the entries. For an even number of entries, this is the mean of the two middle entries.
+    ///
+    /// # Errors
+    /// `RaccoonError::UnsupportedOperation` if the series is not numerical.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataEntry, Series};
+    /// let series = Series::from(vec![5, 1, 4, 2]);
+    /// assert_eq!(series.aggregate().median().unwrap(), DataEntry::Double(3.0));
+    /// ```
+    pub fn median(&self) -> RaccoonResult<DataEntry> {
+        self.quantile_of("median", 0.5)
+    }
+
+    /// Returns the `q`-th quantile of the entries, with `q` between 0 and 1. If the quantile lies between two
+    /// entries, it is linearly interpolated.
+    ///
+    /// # Errors
+    /// - `RaccoonError::InvalidArgument` if `q` lies outside of the interval `[0, 1]`.
+    /// - `RaccoonError::UnsupportedOperation` if the series is not numerical.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataEntry, Series};
+    /// let series = Series::from(vec![1, 2, 3, 4, 5]);
+    /// assert_eq!(series.aggregate().quantile(0.25).unwrap(), DataEntry::Double(2.0));
+    /// assert_eq!(series.aggregate().quantile(0.3).unwrap(), DataEntry::Double(2.2));
+    /// assert!(series.aggregate().quantile(1.5).is_err());
+    /// ```
+    pub fn quantile(&self, q: f64) -> RaccoonResult<DataEntry> {
+        self.quantile_of("quantile", q)
+    }
+
+    /// Returns the smallest entry.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataEntry, Series};
+    /// let series = Series::from(vec!["pear", "apple", "plum"]);
+    /// assert_eq!(series.aggregate().min().unwrap(), DataEntry::from("apple"));
+    /// ```
+    pub fn min(&self) -> RaccoonResult<DataEntry> {
+        self.extremum(Ordering::Less).map(|found| found.map_or(DataEntry::NA, |(_, entry)| entry))
+    }
+
+    /// Returns the largest entry.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataEntry, Series};
+    /// let series = Series::from(vec!['x', 'z', 'y']);
+    /// assert_eq!(series.aggregate().max().unwrap(), DataEntry::Character('z'));
+    /// ```
+    pub fn max(&self) -> RaccoonResult<DataEntry> {
+        self.extremum(Ordering::Greater).map(|found| found.map_or(DataEntry::NA, |(_, entry)| entry))
+    }
+
+    /// Returns the position of the first smallest entry, as a `DataEntry::ULong`.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataEntry, Series};
+    /// let series = Series::from(vec![3.5f64, -1.0, 2.0, -1.0]);
+    /// assert_eq!(series.aggregate().argmin().unwrap(), DataEntry::ULong(1));
+    /// ```
+    pub fn argmin(&self) -> RaccoonResult<DataEntry> {
+        self.extremum(Ordering::Less).map(|found| found.map_or(DataEntry::NA, |(idx, _)| DataEntry::ULong(idx as u64)))
+    }
+
+    /// Returns the position of the first largest entry, as a `DataEntry::ULong`.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataEntry, Series};
+    /// let series = Series::from(vec![3.5f64, -1.0, 7.0, 7.0]);
+    /// assert_eq!(series.aggregate().argmax().unwrap(), DataEntry::ULong(2));
+    /// ```
+    pub fn argmax(&self) -> RaccoonResult<DataEntry> {
+        self.extremum(Ordering::Greater)
+            .map(|found| found.map_or(DataEntry::NA, |(idx, _)| DataEntry::ULong(idx as u64)))
+    }
+
+    /// Returns the entries to aggregate, or `None` if the result must be `DataEntry::NA` because the series contains
+    /// missing entries and these are not skipped.
+    fn entries(&self) -> Option<Vec<DataEntry>> {
+        if !self.skip_na && self.series.iter().any(|x| x.is_missing()) {
+            return None;
+        }
+        Some(self.series.iter().filter(|x| !x.is_missing()).collect())
+    }
+
+    /// Returns the entries to aggregate as floating point numbers, see `entries`.
+    fn values(&self, operation: &str) -> RaccoonResult<Option<Vec<f64>>> {
+        numeric(operation, self.series.data_type())?;
+        Ok(self.entries().map(|entries| entries.iter().filter_map(as_f64).collect()))
+    }
+
+    /// Computes the linearly interpolated `q`-th quantile, reporting errors as coming from `operation`.
+    fn quantile_of(&self, operation: &str, q: f64) -> RaccoonResult<DataEntry> {
+        if !(0.0..=1.0).contains(&q) {
+            return Err(RaccoonError::InvalidArgument(format!("quantile {} is not between 0 and 1", q)));
+        }
+        let mut values = match self.values(operation)? {
+            Some(values) => values,
+            None         => return Ok(DataEntry::NA),
+        };
+        if values.is_empty() {
+            return Ok(DataEntry::NA);
+        }
+        values.sort_by(|a, b| a.total_cmp(b));
+        let position = q * (values.len() - 1) as f64;
+        let (lower, upper) = (position.floor() as usize, position.ceil() as usize);
+        let fraction = position - lower as f64;
+        Ok(DataEntry::Double(values[lower] + (values[upper] - values[lower]) * fraction))
+    }
+
+    /// Finds the first entry that is ordered as `ordering` with respect to all other entries, along with its
+    /// position.
+    fn extremum(&self, ordering: Ordering) -> RaccoonResult<Option<(usize, DataEntry)>> {
+        if !self.skip_na && self.series.iter().any(|x| x.is_missing()) {
+            return Ok(None);
+        }
+        let mut found: Option<(usize, DataEntry)> = None;
+        for (idx, entry) in self.series.iter().enumerate().filter(|(_, x)| !x.is_missing()) {
+            match found {
+                Some((_, ref best)) if entry.partial_cmp(best) != Some(ordering) => {},
+                _                                                                => found = Some((idx, entry)),
+            }
+        }
+        Ok(found)
+    }
+}
+
+/// Returns the numerical family of `data_type`, or `None` for `DataType::NA`.
+///
+/// Fails with `RaccoonError::UnsupportedOperation` if `operation` is not defined for non numerical data types.
+fn numeric(operation: &str, data_type: &DataType) -> RaccoonResult<Option<Numeric>> {
+    match *data_type {
+        DataType::Integer | DataType::Long      => Ok(Some(Numeric::Signed)),
+        DataType::UInteger | DataType::ULong    => Ok(Some(Numeric::Unsigned)),
+        DataType::Float | DataType::Double      => Ok(Some(Numeric::Floating)),
+        DataType::NA                            => Ok(None),
+        _                                       => {
+            Err(RaccoonError::UnsupportedOperation(format!("{} of {:?}", operation, data_type)))
+        },
+    }
+}
+
+/// Converts an integer aggregate into the widened data type of its numerical family.
+fn widen(value: i128, numeric: &Numeric) -> RaccoonResult<DataEntry> {
+    let entry = match *numeric {
+        Numeric::Signed     => DataEntry::Long(value as i64),
+        Numeric::Unsigned   => DataEntry::ULong(value as u64),
+        Numeric::Floating   => DataEntry::Double(value as f64),
+    };
+    match entry {
+        DataEntry::Long(int) if int as i128 != value  => Err(RaccoonError::ArithmeticOverflow),
+        DataEntry::ULong(int) if int as i128 != value => Err(RaccoonError::ArithmeticOverflow),
+        _                                             => Ok(entry),
+    }
+}
+
+/// Returns the value of an integer entry.
+fn as_i128(entry: &DataEntry) -> Option<i128> {
+    match *entry {
+        DataEntry::Integer(int)     => Some(int as i128),
+        DataEntry::UInteger(int)    => Some(int as i128),
+        DataEntry::Long(int)        => Some(int as i128),
+        DataEntry::ULong(int)       => Some(int as i128),
+        _                           => None,
+    }
+}
+
+/// Returns the value of a numerical entry as a floating point number.
+fn as_f64(entry: &DataEntry) -> Option<f64> {
+    match *entry {
+        DataEntry::Float(f)     => Some(f as f64),
+        DataEntry::Double(f)    => Some(f),
+        _                       => as_i128(entry).map(|int| int as f64),
+    }
+}
+
+/// Returns the arithmetic mean of non empty `values`.
+fn mean(values: &[f64]) -> f64 {
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skipping_na() {
+        let series = Series::from(vec![DataEntry::Long(4), DataEntry::NA, DataEntry::Long(-2), DataEntry::Long(7)]);
+        assert_eq!(DataEntry::ULong(3), series.count().unwrap());
+        assert_eq!(DataEntry::Long(9), series.sum().unwrap());
+        assert_eq!(DataEntry::Long(-56), series.prod().unwrap());
+        assert_eq!(DataEntry::Double(3.0), series.mean().unwrap());
+        assert_eq!(DataEntry::Double(21.0), series.var().unwrap());
+        assert_eq!(DataEntry::Double(4.0), series.median().unwrap());
+        assert_eq!(DataEntry::Long(-2), series.min().unwrap());
+        assert_eq!(DataEntry::Long(7), series.max().unwrap());
+        assert_eq!(DataEntry::ULong(2), series.argmin().unwrap());
+        assert_eq!(DataEntry::ULong(3), series.argmax().unwrap());
+    }
+
+    #[test]
+    fn skipping_nan() {
+        for series in &[Series::from(vec![f64::NAN, 1.0, 3.0]), Series::from(vec![1.0, 3.0, f64::NAN])] {
+            assert_eq!(DataEntry::ULong(2), series.count().unwrap());
+            assert_eq!(DataEntry::Double(4.0), series.sum().unwrap());
+            assert_eq!(DataEntry::Double(2.0), series.mean().unwrap());
+            assert_eq!(DataEntry::Double(2.0), series.median().unwrap());
+            assert_eq!(DataEntry::Double(1.0), series.min().unwrap());
+            assert_eq!(DataEntry::Double(3.0), series.max().unwrap());
+            assert_eq!(DataEntry::NA, series.aggregate().skip_na(false).max().unwrap());
+        }
+        let series = Series::from(vec![f32::NAN, 2.0, -1.0, f32::NAN]);
+        assert_eq!(DataEntry::ULong(2), series.argmin().unwrap());
+        assert_eq!(DataEntry::ULong(1), series.argmax().unwrap());
+        assert_eq!(DataEntry::NA, Series::from(vec![f64::NAN]).min().unwrap());
+    }
+
+    #[test]
+    fn propagating_na() {
+        let series = Series::from(vec![DataEntry::Float(1.5), DataEntry::NA]);
+        let aggregator = series.aggregate().skip_na(false);
+        assert_eq!(DataEntry::ULong(1), aggregator.count().unwrap());
+        assert_eq!(DataEntry::NA, aggregator.sum().unwrap());
+        assert_eq!(DataEntry::NA, aggregator.prod().unwrap());
+        assert_eq!(DataEntry::NA, aggregator.mean().unwrap());
+        assert_eq!(DataEntry::NA, aggregator.std().unwrap());
+        assert_eq!(DataEntry::NA, aggregator.quantile(0.1).unwrap());
+        assert_eq!(DataEntry::NA, aggregator.min().unwrap());
+        assert_eq!(DataEntry::NA, aggregator.argmax().unwrap());
+    }
+
+    #[test]
+    fn empty_series() {
+        let series = Series::new("empty".to_owned(), DataType::UInteger);
+        assert_eq!(DataEntry::ULong(0), series.count().unwrap());
+        assert_eq!(DataEntry::ULong(0), series.sum().unwrap());
+        assert_eq!(DataEntry::ULong(1), series.prod().unwrap());
+        assert_eq!(DataEntry::NA, series.mean().unwrap());
+        assert_eq!(DataEntry::NA, series.median().unwrap());
+        assert_eq!(DataEntry::NA, series.max().unwrap());
+        assert_eq!(DataEntry::NA, series.argmin().unwrap());
+
+        let series = Series::from(vec![DataEntry::NA, DataEntry::NA]);
+        assert_eq!(&DataType::NA, series.data_type());
+        assert_eq!(DataEntry::NA, series.sum().unwrap());
+
+        let series = Series::from(vec![1.0f64]);
+        assert_eq!(DataEntry::NA, series.var().unwrap());
+        assert_eq!(DataEntry::Double(0.0), series.aggregate().ddof(0).var().unwrap());
+    }
+
+    #[test]
+    fn widened_types() {
+        let series = Series::from(vec![i32::MAX, i32::MAX]);
+        assert_eq!(DataEntry::Long(2 * i32::MAX as i64), series.sum().unwrap());
+        let series = Series::from(vec![u64::MAX, 1]);
+        assert!(matches!(series.sum(), Err(RaccoonError::ArithmeticOverflow)));
+        let series = Series::from(vec![0.5f32, 0.25]);
+        assert_eq!(DataEntry::Double(0.75), series.sum().unwrap());
+        assert_eq!(DataEntry::Float(0.25), series.min().unwrap());
+    }
+
+    #[test]
+    fn unsupported_types() {
+        let series = Series::from(vec![true, false]);
+        assert!(matches!(series.sum(), Err(RaccoonError::UnsupportedOperation(ref op)) if op == "sum of Boolean"));
+        assert!(matches!(series.mean(), Err(RaccoonError::UnsupportedOperation(_))));
+        let series = Series::from(vec!["a", "b"]);
+        assert!(matches!(series.median(), Err(RaccoonError::UnsupportedOperation(_))));
+        assert!(matches!(series.std(), Err(RaccoonError::UnsupportedOperation(_))));
+        assert_eq!(DataEntry::from("b"), series.max().unwrap());
+        assert_eq!(DataEntry::ULong(2), series.count().unwrap());
+    }
+
+    #[test]
+    fn quantiles() {
+        let series = Series::from(vec![10, 40, 20, 30]);
+        assert_eq!(DataEntry::Double(10.0), series.quantile(0.0).unwrap());
+        assert_eq!(DataEntry::Double(25.0), series.quantile(0.5).unwrap());
+        assert_eq!(DataEntry::Double(37.0), series.quantile(0.9).unwrap());
+        assert_eq!(DataEntry::Double(40.0), series.quantile(1.0).unwrap());
+        assert!(matches!(series.quantile(-0.1), Err(RaccoonError::InvalidArgument(_))));
+        assert!(matches!(series.quantile(f64::NAN), Err(RaccoonError::InvalidArgument(_))));
+    }
+}
@@ -0,0 +1,322 @@
+//! Label alignment of element-wise operations on two [`Series`], similar to the arithmetic of python's
+//! `pandas.Series`.
+//!
+//! [`Series`]: ../series/struct.Series.html
+//!
+//! Arithmetic operators and comparisons between two series match their entries by the labels of their [`Index`]es
+//! before combining them. The entries are matched as the rows of a join on the indexes, see [`raccoon::merge`]: an
+//! outer join by default, keeping the labels of both series, which are `DataEntry::NA` on the side that does not have
+//! them. The result is labelled by the index of the join.
+//!
+//! If either series has a range index, or if both indexes hold the same labels in the same order without repeating
+//! any, entries are matched by position instead, as for series without labels. The series must then have the same
+//! length.
+//!
+//! An [`Alignment`] performs these operations using other types of joins, and can fill the entries missing on one side
+//! with a value before the operation.
+//!
+//! [`Index`]: ../index/struct.Index.html
+//! [`raccoon::merge`]: ../merge/index.html
+//! [`Alignment`]: ./struct.Alignment.html
+//!
+//! # Examples
+//! ```
+//! use raccoon::{DataEntry, DataFrame, Index, JoinType, Series};
+//!
+//! let stock = Series::from(vec![4, 0, 7]).with_index(Index::from(vec!["pen", "ink", "pad"])).unwrap();
+//! let sold = Series::from(vec![1, 3]).with_index(Index::from(vec!["pad", "pen"])).unwrap();
+//!
+//! let left = (&stock - &sold).unwrap();
+//! assert_eq!(left.index().to_series(), vec!["pen", "ink", "pad"]);
+//! assert_eq!(left, vec![DataEntry::Integer(1), DataEntry::NA, DataEntry::Integer(6)]);
+//!
+//! let left = stock.align(&sold).fill_value(0).sub().unwrap();
+//! assert_eq!(left, vec![1, 0, 6]);
+//!
+//! let left = stock.align(&sold).how(JoinType::Inner).sub().unwrap();
+//! assert_eq!(left.index().to_series(), vec!["pen", "pad"]);
+//! assert_eq!(left, vec![1, 6]);
+//! ```
+
+use entry::{DataEntry, DataType};
+use error::{RaccoonResult, RaccoonError};
+use index::{Index, IndexKind};
+use merge::{self, JoinType};
+use series::{self, Series};
+
+use std::cmp::Ordering;
+
+/// The pairs of matched entries of the left and the right series.
+type Matched<'a> = Box<dyn Iterator<Item = (DataEntry, DataEntry)> + 'a>;
+
+/// An element-wise operation on two series matched by label, using configurable options. See `raccoon::align`.
+///
+/// # Example
+/// ```
+/// use raccoon::{DataEntry, Index, JoinType, Series};
+///
+/// let a = Series::from(vec![1.0, 2.0]).with_index(Index::from(vec!['x', 'y'])).unwrap();
+/// let b = Series::from(vec![10.0, 30.0]).with_index(Index::from(vec!['x', 'z'])).unwrap();
+///
+/// let sum = a.align(&b).how(JoinType::Left).add().unwrap();
+/// assert_eq!(sum, vec![DataEntry::Double(11.0), DataEntry::NA]);
+///
+/// let larger = a.align(&b).fill_value(0.0).gt().unwrap();
+/// assert_eq!(larger, vec![false, true, false]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Alignment<'a> {
+    left: &'a Series,
+    right: &'a Series,
+    how: JoinType,
+    fill_value: Option<DataEntry>,
+}
+
+impl<'a> Alignment<'a> {
+    /// Constructs a new outer `Alignment` of `left` and `right`, without fill value.
+    pub fn new(left: &'a Series, right: &'a Series) -> Alignment<'a> {
+        Alignment {
+            left,
+            right,
+            how: JoinType::Outer,
+            fill_value: None,
+        }
+    }
+
+    /// Sets the type of join matching the labels. Defaults to `JoinType::Outer`.
+    pub fn how(mut self, how: JoinType) -> Alignment<'a> {
+        self.how = how;
+        self
+    }
+
+    /// Sets the value replacing entries that are missing on one side only, either because the series does not have
+    /// the label or because the entry is missing, before the operation. Entries missing on both sides stay missing.
+    pub fn fill_value<T>(mut self, fill_value: T) -> Alignment<'a> where T: Into<DataEntry> {
+        self.fill_value = Some(fill_value.into());
+        self
+    }
+
+    /// Returns copies of both series matched by label, both labelled by the index of the join.
+    ///
+    /// # Errors
+    /// See [`add`](#method.add).
+    pub fn series(&self) -> RaccoonResult<(Series, Series)> {
+        let (entries, index) = self.entries()?;
+        let (left, right): (Vec<DataEntry>, Vec<DataEntry>) = entries.unzip();
+        let left = Series::from_entries(self.left.name().to_owned(), self.left.data_type().clone(), left);
+        let right = Series::from_entries(self.right.name().to_owned(), self.right.data_type().clone(), right);
+        Ok((left.with_index(index.clone())?, right.with_index(index)?))
+    }
+
+    /// Adds the entries of both series. See `DataEntry::checked_add`.
+    ///
+    /// # Errors
+    /// - `RaccoonError::LengthMismatch` if the entries are matched by position and the series have different lengths.
+    /// - `RaccoonError::TypeMismatch` if the labels of both indexes cannot be compared.
+    /// - `RaccoonError::InvalidArgument` for `JoinType::Cross`.
+    /// - `RaccoonError::UnsupportedOperation` if the operation is not defined for the data types of the series.
+    pub fn add(&self) -> RaccoonResult<Series> {
+        self.zip_with(DataEntry::checked_add)
+    }
+
+    /// Subtracts the entries of the right series from those of the left series. See `DataEntry::checked_sub`.
+    ///
+    /// # Errors
+    /// See [`add`](#method.add).
+    pub fn sub(&self) -> RaccoonResult<Series> {
+        self.zip_with(DataEntry::checked_sub)
+    }
+
+    /// Multiplies the entries of both series. See `DataEntry::checked_mul`.
+    ///
+    /// # Errors
+    /// See [`add`](#method.add).
+    pub fn mul(&self) -> RaccoonResult<Series> {
+        self.zip_with(DataEntry::checked_mul)
+    }
+
+    /// Divides the entries of the left series by those of the right series. See `DataEntry::checked_div`.
+    ///
+    /// # Errors
+    /// See [`add`](#method.add).
+    pub fn div(&self) -> RaccoonResult<Series> {
+        self.zip_with(DataEntry::checked_div)
+    }
+
+    /// Compares the entries of both series, and builds a boolean series from the result of `predicate` applied to
+    /// each comparison. See [`Series::compare_with`].
+    ///
+    /// [`Series::compare_with`]: ../series/struct.Series.html#method.compare_with
+    ///
+    /// # Errors
+    /// See [`add`](#method.add).
+    pub fn compare_with<F>(&self, predicate: F) -> RaccoonResult<Series> where F: Fn(Option<Ordering>) -> bool {
+        let (entries, index) = self.entries()?;
+        self.left.check_comparable(self.right.data_type())?;
+        let entries = entries.map(|(x1, x2)| series::compare(x1, x2, &predicate));
+        Series::from_entries(self.left.name().to_owned(), DataType::Boolean, entries).with_index(index)
+    }
+
+    /// Checks whether the entries of the left series are equal to those of the right series.
+    ///
+    /// # Errors
+    /// See [`add`](#method.add).
+    pub fn eq(&self) -> RaccoonResult<Series> {
+        self.compare_with(|ordering| ordering == Some(Ordering::Equal))
+    }
+
+    /// Checks whether the entries of the left series are not equal to those of the right series.
+    ///
+    /// # Errors
+    /// See [`add`](#method.add).
+    pub fn ne(&self) -> RaccoonResult<Series> {
+        self.compare_with(|ordering| ordering != Some(Ordering::Equal))
+    }
+
+    /// Checks whether the entries of the left series are smaller than those of the right series.
+    ///
+    /// # Errors
+    /// See [`add`](#method.add).
+    pub fn lt(&self) -> RaccoonResult<Series> {
+        self.compare_with(|ordering| ordering == Some(Ordering::Less))
+    }
+
+    /// Checks whether the entries of the left series are smaller than or equal to those of the right series.
+    ///
+    /// # Errors
+    /// See [`add`](#method.add).
+    pub fn le(&self) -> RaccoonResult<Series> {
+        self.compare_with(|ordering| ordering.is_some_and(|x| x != Ordering::Greater))
+    }
+
+    /// Checks whether the entries of the left series are greater than those of the right series.
+    ///
+    /// # Errors
+    /// See [`add`](#method.add).
+    pub fn gt(&self) -> RaccoonResult<Series> {
+        self.compare_with(|ordering| ordering == Some(Ordering::Greater))
+    }
+
+    /// Checks whether the entries of the left series are greater than or equal to those of the right series.
+    ///
+    /// # Errors
+    /// See [`add`](#method.add).
+    pub fn ge(&self) -> RaccoonResult<Series> {
+        self.compare_with(|ordering| ordering.is_some_and(|x| x != Ordering::Less))
+    }
+
+    /// Applies `operator` to the matched entries of both series. The result is named after the left series.
+    pub(crate) fn zip_with<F>(&self, operator: F) -> RaccoonResult<Series>
+        where F: Fn(DataEntry, DataEntry) -> RaccoonResult<DataEntry> {
+        let (left_unit, right_unit) = (series::unit(self.left.data_type()), series::unit(self.right.data_type()));
+        let data_type = operator(left_unit, right_unit)?.data_type();
+        let (entries, index) = self.entries()?;
+        let entries = entries.map(|(x1, x2)| operator(x1, x2).unwrap_or(DataEntry::NA));
+        Series::from_entries(self.left.name().to_owned(), data_type, entries).with_index(index)
+    }
+
+    /// Returns the pairs of matched entries of both series, filled with the fill value, along with the index labelling
+    /// them.
+    fn entries(&self) -> RaccoonResult<(Matched<'_>, Index)> {
+        if self.how == JoinType::Cross {
+            return Err(RaccoonError::InvalidArgument("series cannot be aligned with a cross join".to_owned()));
+        }
+        let (left_index, right_index) = (self.left.index(), self.right.index());
+        let positional = left_index.kind() == IndexKind::Range || right_index.kind() == IndexKind::Range
+            || left_index.is_aligned_with(right_index);
+        let (pairs, index): (Matched<'_>, Index) = if positional {
+            if self.left.len() != self.right.len() {
+                return Err(RaccoonError::LengthMismatch { expected: self.left.len(), found: self.right.len() });
+            }
+            (Box::new(self.left.iter().zip(self.right.iter())), left_index.clone())
+        } else {
+            let (rows, index) = merge::align(left_index, right_index, self.how)?;
+            let entry = |series: &Series, row: Option<usize>| {
+                row.and_then(|row| series.get(row)).unwrap_or(DataEntry::NA)
+            };
+            let pairs = rows.into_iter().map(move |(left, right)| (entry(self.left, left), entry(self.right, right)));
+            (Box::new(pairs), index)
+        };
+        let fill_value = self.fill_value.clone();
+        let pairs = pairs.map(move |(x1, x2)| match (fill_value.as_ref(), x1.is_missing(), x2.is_missing()) {
+            (Some(value), true, false)  => (value.clone(), x2),
+            (Some(value), false, true)  => (x1, value.clone()),
+            _                           => (x1, x2),
+        });
+        Ok((Box::new(pairs), index))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labelled<T>(entries: Vec<T>, labels: Vec<&str>) -> Series where T: Into<DataEntry> {
+        Series::from_vector("s".to_owned(), entries).with_index(Index::from(labels)).unwrap()
+    }
+
+    #[test]
+    fn label_alignment() {
+        let a = labelled(vec![1, 2, 3], vec!["a", "b", "c"]);
+        let b = labelled(vec![10u32, 20], vec!["c", "d"]);
+
+        let sum = (&a + &b).unwrap();
+        assert_eq!(sum.data_type(), &DataType::Long);
+        assert_eq!(sum.index().to_series(), vec!["a", "b", "c", "d"]);
+        assert_eq!(sum, vec![DataEntry::NA, DataEntry::NA, DataEntry::Long(13), DataEntry::NA]);
+
+        let product = a.align(&b).how(JoinType::Left).fill_value(1).mul().unwrap();
+        assert_eq!(product.index().to_series(), vec!["a", "b", "c"]);
+        assert_eq!(product, vec![1i64, 2, 30]);
+
+        let equal = a.align(&b).how(JoinType::Inner).ne().unwrap();
+        assert_eq!(equal.index().to_series(), vec!["c"]);
+        assert_eq!(equal, vec![true]);
+        assert_eq!(a.gt(&b).unwrap(), vec![DataEntry::NA, DataEntry::NA, DataEntry::Boolean(false), DataEntry::NA]);
+
+        let (left, right) = a.align(&b).how(JoinType::Right).series().unwrap();
+        assert_eq!(left, vec![DataEntry::Integer(3), DataEntry::NA]);
+        assert_eq!(right.data_type(), &DataType::UInteger);
+        assert_eq!(left.index(), right.index());
+
+        // both entries missing stay missing
+        let c = labelled(vec![DataEntry::NA, DataEntry::Integer(5)], vec!["a", "e"]);
+        let sum = a.align(&c).fill_value(0).add().unwrap();
+        assert_eq!(sum, vec![1, 2, 3, 5]);
+        let sum = c.align(&c).fill_value(0).add().unwrap();
+        assert_eq!(sum, vec![DataEntry::NA, DataEntry::Integer(10)]);
+    }
+
+    #[test]
+    fn positional_alignment() {
+        let a = Series::from(vec![1, 2, 3]);
+        let b = labelled(vec![1, 1, 1], vec!["x", "y", "z"]);
+        let sum = (&b + &a).unwrap();
+        assert_eq!(sum, vec![2, 3, 4]);
+        assert_eq!(sum.index(), b.index());
+        assert_eq!((&a + &b).unwrap().index(), &Index::range(3));
+        assert!(matches!(&a + &Series::from(vec![1]), Err(RaccoonError::LengthMismatch { expected: 3, found: 1 })));
+
+        let c = Series::from(vec![DataEntry::NA, DataEntry::Integer(2), DataEntry::NA]);
+        assert_eq!(a.align(&c).fill_value(10).sub().unwrap(), vec![-9, 0, -7]);
+
+        // repeated labels are joined rather than matched by position
+        let d = labelled(vec![1, 2], vec!["x", "x"]);
+        assert_eq!((&d + &d).unwrap(), vec![2, 3, 3, 4]);
+    }
+
+    #[test]
+    fn errors() {
+        let a = labelled(vec![1, 2], vec!["x", "y"]);
+        let b = Series::from(vec![1, 2]).with_index(Index::from(vec![1, 2])).unwrap();
+        assert!(matches!(&a + &b, Err(RaccoonError::TypeMismatch { .. })));
+        assert!(matches!(a.align(&a).how(JoinType::Cross).add(), Err(RaccoonError::InvalidArgument(_))));
+        assert!(matches!(Series::from(vec![1]).align(&a).how(JoinType::Cross).eq(),
+                         Err(RaccoonError::InvalidArgument(_))));
+        let c = labelled(vec![1, 2], vec!["y", "z"]);
+        assert!(matches!(a.align(&c).how(JoinType::Cross).add(), Err(RaccoonError::InvalidArgument(_))));
+        let d = labelled(vec!["a", "b"], vec!["y", "z"]);
+        assert!(matches!(a.align(&d).lt(), Err(RaccoonError::UnsupportedOperation(_))));
+    }
+}
@@ -0,0 +1,305 @@
+//! Concatenation of several [`DataFrame`]s along their rows or their columns, similar to python's `pandas.concat`.
+//!
+//! [`DataFrame`]: ../dataframe/struct.DataFrame.html
+//!
+//! Concatenating along [`Axis::Rows`] places the rows of the frames one after the other. The columns of the result are
+//! the union of the columns of all frames for `JoinType::Outer`, in the order of first appearance, and their
+//! intersection for `JoinType::Inner`, in the order of the first frame. `JoinType::Left` and `JoinType::Right` keep the
+//! columns of the first and the last frame. A column missing from a frame is filled with `DataEntry::NA` for its rows,
+//! and the entries of a column are converted into the common data type of the frames having it, see
+//! [`DataType::common_type`]: an integer column concatenated with a floating point column holds doubles. The labels of
+//! the indexes are concatenated as well, keeping a range index if all frames have one.
+//!
+//! Concatenating along [`Axis::Columns`] places the columns of the frames side by side, matching rows by the labels of
+//! their indexes like [`DataFrame::join`], where the join type selects the labels kept. Frames whose indexes have the
+//! same labels in the same order are matched by position.
+//!
+//! [`Axis::Rows`]: ./enum.Axis.html#variant.Rows
+//! [`DataType::common_type`]: ../entry/enum.DataType.html#method.common_type
+//! [`Axis::Columns`]: ./enum.Axis.html#variant.Columns
+//! [`DataFrame::join`]: ../dataframe/struct.DataFrame.html#method.join
+//!
+//! # Examples
+//! ```
+//! use raccoon::{concat, Axis, DataEntry, DataFrame, DataType, Series};
+//!
+//! let first = DataFrame::from_series(vec![
+//!     Series::from_vector("city".to_owned(), vec!["Bern", "Chur"]),
+//!     Series::from_vector("rain".to_owned(), vec![62, 50]),
+//! ]).unwrap();
+//! let second = DataFrame::from_series(vec![
+//!     Series::from_vector("rain".to_owned(), vec![55.5]),
+//!     Series::from_vector("snow".to_owned(), vec![true]),
+//! ]).unwrap();
+//!
+//! let rows = concat(&[first.clone(), second], Axis::Rows).unwrap();
+//! assert_eq!(rows.columns(), vec!["city", "rain", "snow"]);
+//! assert_eq!(rows["city"], vec![DataEntry::from("Bern"), DataEntry::from("Chur"), DataEntry::NA]);
+//! assert_eq!(rows["rain"].data_type(), &DataType::Double);
+//! assert_eq!(rows["rain"], vec![62.0, 50.0, 55.5]);
+//! assert_eq!(rows.index().len(), 3);
+//!
+//! let sun = DataFrame::from_series(vec![Series::from_vector("sun".to_owned(), vec![1_650, 1_720])]).unwrap();
+//! let columns = concat(&[first, sun], Axis::Columns).unwrap();
+//! assert_eq!(columns.columns(), vec!["city", "rain", "sun"]);
+//! assert_eq!(columns["sun"], vec![1_650, 1_720]);
+//! ```
+
+use dataframe::DataFrame;
+use entry::{DataEntry, DataType};
+use error::{RaccoonResult, RaccoonError};
+use index::Index;
+use merge::JoinType;
+use series::Series;
+
+use std::collections::HashSet;
+
+/// The axis along which frames are concatenated by a [`Concat`].
+///
+/// [`Concat`]: ./struct.Concat.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    /// Places the rows of the frames one after the other.
+    Rows,
+    /// Places the columns of the frames side by side.
+    Columns,
+}
+
+/// A concatenation of dataframes using configurable options, see `raccoon::concat`.
+///
+/// # Example
+/// ```
+/// use raccoon::{Axis, Concat, DataFrame, Index, JoinType, Series};
+///
+/// let first = DataFrame::from_series(vec![
+///     Series::from_vector("a".to_owned(), vec![1, 2]),
+///     Series::from_vector("b".to_owned(), vec!['x', 'y']),
+/// ]).unwrap();
+/// let second = DataFrame::from_series(vec![Series::from_vector("a".to_owned(), vec![3])]).unwrap();
+///
+/// let rows = Concat::new(&[first.clone(), second]).how(JoinType::Inner).concat().unwrap();
+/// assert_eq!(rows.columns(), vec!["a"]);
+/// assert_eq!(rows["a"], vec![1, 2, 3]);
+///
+/// let other = DataFrame::from_series(vec![Series::from_vector("c".to_owned(), vec![true, false])]).unwrap()
+///     .with_index(Index::from(vec![1i64, 2])).unwrap();
+/// let columns = Concat::new(&[first, other]).axis(Axis::Columns).how(JoinType::Inner).concat().unwrap();
+/// assert_eq!(columns.index().to_series(), vec![1i64]);
+/// assert_eq!(columns["b"], vec!['y']);
+/// assert_eq!(columns["c"], vec![true]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Concat<'a> {
+    frames: &'a [DataFrame],
+    axis: Axis,
+    how: JoinType,
+}
+
+impl<'a> Concat<'a> {
+    /// Constructs a new outer `Concat` of `frames` along their rows.
+    pub fn new(frames: &'a [DataFrame]) -> Concat<'a> {
+        Concat {
+            frames,
+            axis: Axis::Rows,
+            how: JoinType::Outer,
+        }
+    }
+
+    /// Sets the axis along which the frames are concatenated. Defaults to `Axis::Rows`.
+    pub fn axis(mut self, axis: Axis) -> Concat<'a> {
+        self.axis = axis;
+        self
+    }
+
+    /// Sets how the columns of the frames, or the labels of their rows when concatenating along `Axis::Columns`, are
+    /// combined. Defaults to `JoinType::Outer`.
+    pub fn how(mut self, how: JoinType) -> Concat<'a> {
+        self.how = how;
+        self
+    }
+
+    /// Concatenates the frames. Concatenating no frames gives an empty frame.
+    ///
+    /// # Errors
+    /// - `RaccoonError::InvalidArgument` for `JoinType::Cross`.
+    /// - `RaccoonError::TypeMismatch` if the columns of the same name, or the labels of the indexes, have no common
+    ///   data type.
+    /// - `RaccoonError::LengthMismatch` if the indexes have different numbers of levels.
+    /// - `RaccoonError::DuplicateColumn` if two frames concatenated along `Axis::Columns` have a column of the same
+    ///   name.
+    pub fn concat(&self) -> RaccoonResult<DataFrame> {
+        if self.how == JoinType::Cross {
+            return Err(RaccoonError::InvalidArgument("frames cannot be concatenated using a cross join".to_owned()));
+        }
+        match self.axis {
+            Axis::Rows      => self.rows(),
+            Axis::Columns   => self.columns(),
+        }
+    }
+
+    /// Concatenates the rows of the frames.
+    fn rows(&self) -> RaccoonResult<DataFrame> {
+        let names = self.names();
+        let indexes: Vec<&Index> = self.frames.iter().map(|df| df.index()).collect();
+        let index = Index::concat(&indexes)?;
+
+        // frames without columns still have rows, labelled by the concatenated index
+        let mut columns = Vec::with_capacity(names.len());
+        for name in names {
+            let mut data_type = DataType::NA;
+            for series in self.frames.iter().filter_map(|df| df.column(name)) {
+                data_type = data_type.common_type(series.data_type()).ok_or_else(|| {
+                    RaccoonError::TypeMismatch { expected: data_type.clone(), found: series.data_type().clone() }
+                })?;
+            }
+            let mut entries = Vec::with_capacity(index.len());
+            for frame in self.frames {
+                match frame.column(name) {
+                    Some(series)    => entries.extend(series.iter().map(|entry| entry.convert_to(&data_type))),
+                    None            => entries.extend((0..frame.len()).map(|_| DataEntry::NA)),
+                }
+            }
+            columns.push(Series::from_entries(name.to_owned(), data_type, entries));
+        }
+        Ok(DataFrame::from_parts(columns, index, None))
+    }
+
+    /// Concatenates the columns of the frames, joining the rows of each frame with the rows of the preceding frames.
+    fn columns(&self) -> RaccoonResult<DataFrame> {
+        let mut names = HashSet::new();
+        for name in self.frames.iter().flat_map(|df| df.columns()) {
+            if !names.insert(name) {
+                return Err(RaccoonError::DuplicateColumn(name.to_owned()));
+            }
+        }
+        let mut frames = self.frames.iter();
+        let mut df = match frames.next() {
+            Some(first) => first.clone(),
+            None        => return Ok(DataFrame::new()),
+        };
+        for frame in frames {
+            if df.index().is_aligned_with(frame.index()) {
+                for series in frame.iter() {
+                    df.add_column(series.clone())?;
+                }
+            } else {
+                df = df.join(frame, self.how)?;
+            }
+        }
+        Ok(df)
+    }
+
+    /// Returns the names of the columns of a concatenation along the rows.
+    fn names(&self) -> Vec<&'a str> {
+        let frames = self.frames;
+        match self.how {
+            JoinType::Left                      => frames.first().map_or_else(Vec::new, |df| df.columns()),
+            JoinType::Right                     => frames.last().map_or_else(Vec::new, |df| df.columns()),
+            JoinType::Inner                     => frames.first().map_or_else(Vec::new, |first| {
+                first.columns().into_iter().filter(|name| frames.iter().all(|df| df.column(name).is_some())).collect()
+            }),
+            JoinType::Outer | JoinType::Cross   => {
+                let mut seen = HashSet::new();
+                frames.iter().flat_map(|df| df.columns()).filter(|name| seen.insert(*name)).collect()
+            },
+        }
+    }
+}
+
+/// Concatenates `frames` along `axis`, keeping all columns or all rows, see `raccoon::concat`. Use a [`Concat`] to
+/// keep only the columns or rows common to all frames.
+///
+/// [`Concat`]: ./struct.Concat.html
+///
+/// # Errors
+/// See [`Concat::concat`].
+///
+/// [`Concat::concat`]: ./struct.Concat.html#method.concat
+pub fn concat(frames: &[DataFrame], axis: Axis) -> RaccoonResult<DataFrame> {
+    Concat::new(frames).axis(axis).concat()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rows() {
+        let frames = vec![
+            DataFrame::from_series(vec![
+                Series::from_vector("id".to_owned(), vec![1, 2]),
+                Series::from_vector("score".to_owned(), vec![DataEntry::UInteger(7), DataEntry::NA]),
+            ]).unwrap(),
+            DataFrame::from_series(vec![
+                Series::from_vector("score".to_owned(), vec![-3i64]),
+                Series::from_vector("name".to_owned(), vec!["Eva"]),
+            ]).unwrap(),
+        ];
+        let df = concat(&frames, Axis::Rows).unwrap();
+        assert_eq!(df.columns(), vec!["id", "score", "name"]);
+        assert_eq!(df.dtypes(), vec![&DataType::Integer, &DataType::Long, &DataType::Text]);
+        assert_eq!(df["id"], vec![DataEntry::Integer(1), DataEntry::Integer(2), DataEntry::NA]);
+        assert_eq!(df["score"], vec![DataEntry::Long(7), DataEntry::NA, DataEntry::Long(-3)]);
+        assert_eq!(df.index(), &Index::range(3));
+
+        let df = Concat::new(&frames).how(JoinType::Right).concat().unwrap();
+        assert_eq!(df.columns(), vec!["score", "name"]);
+        assert_eq!(df["name"], vec![DataEntry::NA, DataEntry::NA, DataEntry::from("Eva")]);
+        assert_eq!(Concat::new(&frames).how(JoinType::Left).concat().unwrap().columns(), vec!["id", "score"]);
+        assert!(concat(&[], Axis::Rows).unwrap().is_empty());
+        let keys = frames[0].set_multi_index(&["id", "score"]).unwrap();
+        let df = concat(&[keys.clone(), keys], Axis::Rows).unwrap();
+        assert_eq!(df.shape(), (4, 0));
+        assert_eq!(df.index().nlevels(), 2);
+        assert_eq!(df.index().get(2), Some(DataEntry::Integer(1)));
+
+        let labelled: Vec<DataFrame> = frames.iter().map(|df| df.set_index("score").unwrap()).collect();
+        let df = concat(&labelled, Axis::Rows).unwrap();
+        assert_eq!(df.index().name(), Some("score"));
+        assert_eq!(df.index().to_series(), vec![DataEntry::Long(7), DataEntry::NA, DataEntry::Long(-3)]);
+        let df = concat(&[labelled[1].clone(), frames[0].clone()], Axis::Rows).unwrap();
+        assert_eq!(df.index().to_series(), vec![-3i64, 0, 1]);
+        let multi = frames[0].set_multi_index(&["id", "score"]).unwrap();
+        let result = concat(&[multi, labelled[0].clone()], Axis::Rows);
+        assert!(matches!(result, Err(RaccoonError::LengthMismatch { expected: 2, found: 1 })));
+
+        let texts = frames[1].clone().with_index(Index::from(vec!["x"])).unwrap();
+        let result = concat(&[frames[0].set_index("id").unwrap(), texts], Axis::Rows);
+        assert!(matches!(result, Err(RaccoonError::TypeMismatch { .. })));
+        let mismatched = DataFrame::from_series(vec![Series::from_vector("score".to_owned(), vec!["high"])]).unwrap();
+        let result = Concat::new(&[frames[0].clone(), mismatched]).how(JoinType::Right).concat();
+        assert!(matches!(result, Err(RaccoonError::TypeMismatch { expected: DataType::UInteger, .. })));
+    }
+
+    #[test]
+    fn columns() {
+        let frames = vec![
+            DataFrame::from_series(vec![
+                Series::from_vector("id".to_owned(), vec![1, 2]),
+                Series::from_vector("score".to_owned(), vec![DataEntry::UInteger(7), DataEntry::NA]),
+            ]).unwrap(),
+            DataFrame::from_series(vec![
+                Series::from_vector("score".to_owned(), vec![-3i64]),
+                Series::from_vector("name".to_owned(), vec!["Eva"]),
+            ]).unwrap(),
+        ];
+        assert!(matches!(concat(&frames, Axis::Columns), Err(RaccoonError::DuplicateColumn(_))));
+
+        let scores = frames[0].set_index("id").unwrap();
+        let names = DataFrame::from_series(vec![
+            Series::from_vector("id".to_owned(), vec![3, 2]),
+            Series::from_vector("name".to_owned(), vec!["Tom", "Eva"]),
+        ]).unwrap().set_index("id").unwrap();
+        let df = concat(&[scores.clone(), names.clone()], Axis::Columns).unwrap();
+        assert_eq!(df.index().to_series(), vec![1, 2, 3]);
+        assert_eq!(df["name"], vec![DataEntry::NA, DataEntry::from("Eva"), DataEntry::from("Tom")]);
+        let df = Concat::new(&[scores.clone(), names]).axis(Axis::Columns).how(JoinType::Inner).concat().unwrap();
+        assert_eq!(df.index().to_series(), vec![2]);
+
+        let df = concat(&[scores, DataFrame::new()], Axis::Columns).unwrap();
+        assert_eq!(df.shape(), (2, 1));
+        let result = Concat::new(&frames).how(JoinType::Cross).concat();
+        assert!(matches!(result, Err(RaccoonError::InvalidArgument(_))));
+    }
+}
@@ -1 +1,1673 @@
-//! `dataframe` module.
+//! A two-dimensional, column oriented table similar to python's `pandas.DataFrame`.
+//!
+//! A [`DataFrame`] is a list of named [`Series`] that all have the same length. Each series forms a column of the
+//! frame, and the entries at the same position in each series form a row.
+//!
+//! [`DataFrame`]: ./struct.DataFrame.html
+//! [`Series`]: ../series/struct.Series.html
+//!
+//! # Examples
+//! You can create a new [`DataFrame`] from a vector of series using [`from_series`]:
+//!
+//! [`from_series`]: ./struct.DataFrame.html#method.from_series
+//! ```
+//! use raccoon::{DataFrame, Series};
+//!
+//! let names = Series::from_vector("name".to_owned(), vec!["Tom", "Anna", "Bob"]);
+//! let ages = Series::from_vector("age".to_owned(), vec![34, 28, 51]);
+//!
+//! let df = DataFrame::from_series(vec![names, ages]).unwrap();
+//! assert_eq!(df.shape(), (3, 2));
+//! assert_eq!(df.columns(), vec!["name", "age"]);
+//! ```
+//!
+//! Columns can be accessed by name or by position:
+//! ```
+//! use raccoon::{DataFrame, DataEntry, Series};
+//!
+//! let df = DataFrame::from_series(vec![
+//!     Series::from_vector("a".to_owned(), vec![1, 2]),
+//!     Series::from_vector("b".to_owned(), vec![true, false]),
+//! ]).unwrap();
+//!
+//! assert_eq!(df["a"].get(1), Some(DataEntry::Integer(2)));
+//! assert_eq!(df[1usize].get(0), Some(DataEntry::Boolean(true)));
+//! assert!(df.column("c").is_none());
+//! ```
+//!
+//! Rows are labelled by an [`Index`], which defaults to their positions. A column can be moved into the index using
+//! [`set_index`], to select rows by label using [`loc`], see [`raccoon::index`]:
+//!
+//! [`Index`]: ../index/struct.Index.html
+//! [`set_index`]: ./struct.DataFrame.html#method.set_index
+//! [`loc`]: ./struct.DataFrame.html#method.loc
+//! [`raccoon::index`]: ../index/index.html
+//! ```
+//! use raccoon::{DataEntry, DataFrame, Series};
+//!
+//! let df = DataFrame::from_series(vec![
+//!     Series::from_vector("id".to_owned(), vec!["a7", "c3"]),
+//!     Series::from_vector("value".to_owned(), vec![1.5, 2.5]),
+//! ]).unwrap();
+//!
+//! let df = df.set_index("id").unwrap();
+//! assert_eq!(df.columns(), vec!["value"]);
+//! assert_eq!(df.loc("c3").unwrap()["value"].get(0), Some(DataEntry::Double(2.5)));
+//! ```
+
+use entry::{DataEntry, DataType, OrderedEntry};
+use error::{RaccoonResult, RaccoonError};
+use groupby::GroupBy;
+use index::{Index, IndexKind, LabelSelector, PositionSelector};
+use io::{CsvReader, CsvWriter, Sheet, XlsxReader};
+use merge::{self, JoinType, Merge};
+use missing::{DropHow, Fill};
+use multiindex::{self, Level, MultiIndex};
+use reshape::{Melt, PivotTable};
+use series::Series;
+use sort::{Sorter, SortOrder};
+
+use std::collections::HashMap;
+use std::io::Write;
+use std::ops;
+use std::path::Path;
+
+/// A table of named columns, all having the same length, with an [`Index`] labelling its rows. This tries to conform to
+/// the behaviour of python's `pandas.DataFrame`.
+///
+/// [`Index`]: ../index/struct.Index.html
+///
+/// # Examples
+/// ```
+/// use raccoon::{DataFrame, DataType, Series};
+///
+/// let mut df = DataFrame::new();
+/// df.add_column(Series::from_vector("x".to_owned(), vec![1.5f64, 2.5, 3.5])).unwrap();
+/// df.add_column(Series::from_vector("y".to_owned(), vec!['a', 'b', 'c'])).unwrap();
+///
+/// assert_eq!(df.shape(), (3, 2));
+/// assert_eq!(df.dtypes(), vec![&DataType::Double, &DataType::Character]);
+///
+/// // columns must all have the same length
+/// let result = df.add_column(Series::from_vector("z".to_owned(), vec![1, 2]));
+/// assert!(result.is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DataFrame {
+    columns: Vec<Series>,
+    index: Index,
+    // labels of the columns built by `unstack`, dropped when columns are added or removed
+    column_index: Option<MultiIndex>,
+}
+
+impl DataFrame {
+    /// Constructs a new, empty `DataFrame` without any columns.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::DataFrame;
+    /// let df = DataFrame::new();
+    /// assert!(df.is_empty());
+    /// assert_eq!(df.shape(), (0, 0));
+    /// ```
+    pub fn new() -> DataFrame {
+        DataFrame {
+            columns: Vec::new(),
+            index: Index::range(0),
+            column_index: None,
+        }
+    }
+
+    /// Builds a `DataFrame` from a vector of series. Each series becomes a column named after the series.
+    ///
+    /// This fails if the series do not all have the same length, or if two series share the same name.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataFrame, Series};
+    /// let a = Series::from_vector("a".to_owned(), vec![1, 2, 3]);
+    /// let b = Series::from_vector("b".to_owned(), vec![4, 5, 6]);
+    /// let df = DataFrame::from_series(vec![a, b]).unwrap();
+    /// assert_eq!(df.shape(), (3, 2));
+    ///
+    /// // lengths do not match
+    /// let a = Series::from_vector("a".to_owned(), vec![1, 2, 3]);
+    /// let b = Series::from_vector("b".to_owned(), vec![4, 5]);
+    /// assert!(DataFrame::from_series(vec![a, b]).is_err());
+    /// ```
+    pub fn from_series(series: Vec<Series>) -> RaccoonResult<DataFrame> {
+        let mut df = DataFrame::with_capacity(series.len());
+        for column in series {
+            df.add_column(column)?;
+        }
+        Ok(df)
+    }
+
+    /// Reads a `DataFrame` from the CSV file at `path` using the default options of [`CsvReader`]. The data type of
+    /// each column is inferred from its content.
+    ///
+    /// Use a [`CsvReader`] directly to change the delimiter, the NA markers, or other options.
+    ///
+    /// [`CsvReader`]: ../io/csv/struct.CsvReader.html
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataFrame, DataType};
+    /// let df = DataFrame::read_csv("tests/data/cities.csv").unwrap();
+    /// assert_eq!(df.shape(), (6, 5));
+    /// assert_eq!(df["population"].data_type(), &DataType::Integer);
+    /// ```
+    pub fn read_csv<P>(path: P) -> RaccoonResult<DataFrame> where P: AsRef<Path> {
+        CsvReader::new().read_path(path)
+    }
+
+    /// Reads a `DataFrame` from a sheet of the Excel workbook at `path`. The sheet is selected either by name or by
+    /// position, and its first row contains the column names.
+    ///
+    /// Use a [`XlsxReader`] directly to read a range of cells or a sheet without a header.
+    ///
+    /// [`XlsxReader`]: ../io/xlsx/struct.XlsxReader.html
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataEntry, DataFrame, DataType};
+    /// let df = DataFrame::read_xlsx("tests/data/cities.xlsx", "cities").unwrap();
+    /// assert_eq!(df.shape(), (6, 5));
+    /// assert_eq!(df["area"].data_type(), &DataType::Double);
+    ///
+    /// // the same sheet by position
+    /// let other = DataFrame::read_xlsx("tests/data/cities.xlsx", 0).unwrap();
+    /// assert_eq!(df, other);
+    /// ```
+    pub fn read_xlsx<P, S>(path: P, sheet: S) -> RaccoonResult<DataFrame> where P: AsRef<Path>, S: Into<Sheet> {
+        XlsxReader::new().sheet(sheet).read_path(path)
+    }
+
+    /// Writes the dataframe as CSV data into `writer` using the default options of [`CsvWriter`]. A header containing
+    /// the column names is written first.
+    ///
+    /// Use a [`CsvWriter`] directly to change the delimiter, the text written for `DataEntry::NA`, or other options.
+    ///
+    /// [`CsvWriter`]: ../io/csv/struct.CsvWriter.html
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataEntry, DataFrame, Series};
+    /// let mut ids = Series::from_vector("id".to_owned(), vec![1, 2]);
+    /// ids.push_entry(DataEntry::NA).unwrap();
+    /// let names = Series::from_vector("name".to_owned(), vec!["Anna", "Tom", "Bob"]);
+    /// let df = DataFrame::from_series(vec![ids, names]).unwrap();
+    ///
+    /// let mut buffer = Vec::new();
+    /// df.to_csv(&mut buffer).unwrap();
+    /// assert_eq!(String::from_utf8(buffer).unwrap(), "id,name\n1,Anna\n2,Tom\n,Bob\n");
+    /// ```
+    pub fn to_csv<W>(&self, writer: W) -> RaccoonResult where W: Write {
+        CsvWriter::new().write(self, writer)
+    }
+
+    /// Constructs a new, empty `DataFrame` with space for `capacity` columns.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::DataFrame;
+    /// let df = DataFrame::with_capacity(10);
+    /// assert_eq!(df.shape(), (0, 0));
+    /// ```
+    pub fn with_capacity(capacity: usize) -> DataFrame {
+        DataFrame {
+            columns: Vec::with_capacity(capacity),
+            index: Index::range(0),
+            column_index: None,
+        }
+    }
+
+    /// Appends a column to the end of the dataframe.
+    ///
+    /// The series must have the same length as the existing columns, and its name must not already be used by another
+    /// column. Its entries take the labels of the rows at the same positions. The first column added to a dataframe
+    /// without columns defines the number of rows and brings its index, unless it has a range index and the index of
+    /// the dataframe has as many labels.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataFrame, Series};
+    /// let mut df = DataFrame::new();
+    /// assert!(df.add_column(Series::from_vector("a".to_owned(), vec![1, 2])).is_ok());
+    ///
+    /// // wrong length
+    /// assert!(df.add_column(Series::from_vector("b".to_owned(), vec![1, 2, 3])).is_err());
+    ///
+    /// // name already taken
+    /// assert!(df.add_column(Series::from_vector("a".to_owned(), vec![3, 4])).is_err());
+    /// ```
+    pub fn add_column(&mut self, mut series: Series) -> RaccoonResult {
+        if self.position(series.name()).is_some() {
+            return Err(RaccoonError::DuplicateColumn(series.name().to_owned()));
+        }
+        if !self.columns.is_empty() && series.len() != self.len() {
+            return Err(RaccoonError::LengthMismatch { expected: self.len(), found: series.len() });
+        }
+        let keep_index = series.len() == self.index.len() && series.index().kind() == IndexKind::Range;
+        if self.columns.is_empty() && !keep_index {
+            self.index = series.index().clone();
+        }
+        series.relabel(self.index.clone());
+        self.columns.push(series);
+        self.column_index = None;
+        Ok(())
+    }
+
+    /// Removes the column with the given name from the dataframe and returns it. Removing the last column also removes
+    /// the index.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataFrame, Series};
+    /// let mut df = DataFrame::from_series(vec![
+    ///     Series::from_vector("a".to_owned(), vec![1, 2]),
+    ///     Series::from_vector("b".to_owned(), vec![3, 4]),
+    /// ]).unwrap();
+    ///
+    /// let dropped = df.drop_column("a").unwrap();
+    /// assert_eq!(dropped, vec![1, 2]);
+    /// assert_eq!(df.columns(), vec!["b"]);
+    ///
+    /// assert!(df.drop_column("a").is_err());
+    /// ```
+    pub fn drop_column(&mut self, name: &str) -> RaccoonResult<Series> {
+        match self.position(name) {
+            Some(idx) => {
+                let series = self.columns.remove(idx);
+                self.column_index = None;
+                if self.columns.is_empty() {
+                    self.index = Index::range(0);
+                }
+                Ok(series)
+            },
+            None      => Err(RaccoonError::ColumnNotFound(name.to_owned())),
+        }
+    }
+
+    /// Returns the column with the given name, or `None` if no such column exists.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataFrame, Series};
+    /// let df = DataFrame::from_series(vec![Series::from_vector("a".to_owned(), vec![1, 2])]).unwrap();
+    /// assert_eq!(df.column("a").unwrap(), &vec![1, 2]);
+    /// assert!(df.column("b").is_none());
+    /// ```
+    pub fn column(&self, name: &str) -> Option<&Series> {
+        self.position(name).map(|idx| &self.columns[idx])
+    }
+
+    /// Returns the column at the given position, or `None` if the position is out of bounds.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataFrame, Series};
+    /// let df = DataFrame::from_series(vec![Series::from_vector("a".to_owned(), vec![1, 2])]).unwrap();
+    /// assert_eq!(df.column_at(0).unwrap().name(), "a");
+    /// assert!(df.column_at(1).is_none());
+    /// ```
+    pub fn column_at(&self, idx: usize) -> Option<&Series> {
+        self.columns.get(idx)
+    }
+
+    /// Returns the position of the column with the given name.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataFrame, Series};
+    /// let df = DataFrame::from_series(vec![
+    ///     Series::from_vector("a".to_owned(), vec![1, 2]),
+    ///     Series::from_vector("b".to_owned(), vec![3, 4]),
+    /// ]).unwrap();
+    /// assert_eq!(df.position("b"), Some(1));
+    /// assert_eq!(df.position("c"), None);
+    /// ```
+    pub fn position(&self, name: &str) -> Option<usize> {
+        self.columns.iter().position(|series| series.name() == name)
+    }
+
+    /// Returns the names of the columns, in order.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataFrame, Series};
+    /// let df = DataFrame::from_series(vec![
+    ///     Series::from_vector("first".to_owned(), vec![1]),
+    ///     Series::from_vector("second".to_owned(), vec![2]),
+    /// ]).unwrap();
+    /// assert_eq!(df.columns(), vec!["first", "second"]);
+    /// ```
+    pub fn columns(&self) -> Vec<&str> {
+        self.columns.iter().map(|series| series.name()).collect()
+    }
+
+    /// Returns the data types of the columns, in order.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataFrame, DataType, Series};
+    /// let df = DataFrame::from_series(vec![
+    ///     Series::from_vector("a".to_owned(), vec![1u64]),
+    ///     Series::from_vector("b".to_owned(), vec!["text"]),
+    /// ]).unwrap();
+    /// assert_eq!(df.dtypes(), vec![&DataType::ULong, &DataType::Text]);
+    /// ```
+    pub fn dtypes(&self) -> Vec<&DataType> {
+        self.columns.iter().map(|series| series.data_type()).collect()
+    }
+
+    /// Returns the shape of the dataframe as `(rows, columns)`.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataFrame, Series};
+    /// let df = DataFrame::from_series(vec![
+    ///     Series::from_vector("a".to_owned(), vec![1, 2, 3]),
+    ///     Series::from_vector("b".to_owned(), vec![4, 5, 6]),
+    /// ]).unwrap();
+    /// assert_eq!(df.shape(), (3, 2));
+    /// ```
+    pub fn shape(&self) -> (usize, usize) {
+        (self.len(), self.columns.len())
+    }
+
+    /// Returns the number of rows in the dataframe.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataFrame, Series};
+    /// let df = DataFrame::from_series(vec![Series::from_vector("a".to_owned(), vec![1, 2, 3])]).unwrap();
+    /// assert_eq!(df.len(), 3);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.index.len()
+    }
+
+    /// Checks if the dataframe contains no rows.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataFrame, DataType, Series};
+    /// let mut df = DataFrame::new();
+    /// assert!(df.is_empty());
+    ///
+    /// // a column without entries does not add any rows
+    /// df.add_column(Series::new("a".to_owned(), DataType::Integer)).unwrap();
+    /// assert!(df.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns an iterator over the columns of the dataframe.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataFrame, Series};
+    /// let df = DataFrame::from_series(vec![
+    ///     Series::from_vector("a".to_owned(), vec![1, 2]),
+    ///     Series::from_vector("b".to_owned(), vec![3, 4]),
+    /// ]).unwrap();
+    /// let lengths: Vec<usize> = df.iter().map(|series| series.len()).collect();
+    /// assert_eq!(lengths, vec![2, 2]);
+    /// ```
+    pub fn iter(&self) -> ::std::slice::Iter<'_, Series> {
+        self.columns.iter()
+    }
+
+    /// Returns a new dataframe containing the rows at the given positions, in the order of `positions`, along with
+    /// their labels. Positions may be repeated.
+    ///
+    /// # Errors
+    /// `RaccoonError::IndexOutOfBounds` if a position is not smaller than the number of rows.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataFrame, Series};
+    /// let df = DataFrame::from_series(vec![
+    ///     Series::from_vector("a".to_owned(), vec![1, 2, 3]),
+    ///     Series::from_vector("b".to_owned(), vec!['x', 'y', 'z']),
+    /// ]).unwrap();
+    ///
+    /// let rows = df.take(&[2, 0]).unwrap();
+    /// assert_eq!(rows["a"], vec![3, 1]);
+    /// assert_eq!(rows["b"], vec!['z', 'x']);
+    /// ```
+    pub fn take(&self, positions: &[usize]) -> RaccoonResult<DataFrame> {
+        if let Some(&index) = positions.iter().find(|&&idx| idx >= self.len()) {
+            return Err(RaccoonError::IndexOutOfBounds { index, length: self.len() });
+        }
+        let columns = self.columns.iter().map(|series| series.take_entries(positions)).collect::<RaccoonResult<_>>()?;
+        Ok(DataFrame::from_parts(columns, self.index.take(positions), self.column_index.clone()))
+    }
+
+    /// Returns a new dataframe containing the rows for which `mask` is true. Rows for which the mask is false or
+    /// `DataEntry::NA` are dropped.
+    ///
+    /// # Errors
+    /// - `RaccoonError::TypeMismatch` if `mask` is not a boolean series.
+    /// - `RaccoonError::LengthMismatch` if `mask` does not have as many entries as the dataframe has rows.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataFrame, Series};
+    /// let df = DataFrame::from_series(vec![
+    ///     Series::from_vector("city".to_owned(), vec!["Zürich", "Geneva", "Basel"]),
+    ///     Series::from_vector("population".to_owned(), vec![415_367, 201_818, 171_017]),
+    /// ]).unwrap();
+    ///
+    /// let mask = df["population"].gt(200_000).unwrap();
+    /// let large = df.filter(&mask).unwrap();
+    /// assert_eq!(large["city"], vec!["Zürich", "Geneva"]);
+    /// ```
+    pub fn filter(&self, mask: &Series) -> RaccoonResult<DataFrame> {
+        self.take(&mask.positions(self.len())?)
+    }
+
+    /// Groups the rows by the values of the `keys` columns, to aggregate the other columns per group. See
+    /// [`raccoon::groupby`] for more details.
+    ///
+    /// [`raccoon::groupby`]: ../groupby/index.html
+    ///
+    /// # Errors
+    /// - `RaccoonError::InvalidArgument` if `keys` is empty.
+    /// - `RaccoonError::ColumnNotFound` if a key column does not exist.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataFrame, Series};
+    /// let df = DataFrame::from_series(vec![
+    ///     Series::from_vector("team".to_owned(), vec!["red", "blue", "red"]),
+    ///     Series::from_vector("score".to_owned(), vec![3, 5, 4]),
+    /// ]).unwrap();
+    ///
+    /// let best = df.groupby(&["team"]).unwrap().max().unwrap();
+    /// assert_eq!(best["team"], vec!["red", "blue"]);
+    /// assert_eq!(best["score"], vec![4, 5]);
+    /// ```
+    pub fn groupby(&self, keys: &[&str]) -> RaccoonResult<GroupBy<'_>> {
+        GroupBy::new(self, keys)
+    }
+
+    /// Returns a new dataframe without the rows having missing values, see [`raccoon::missing`]. Only the columns
+    /// named in `subset` are considered, or all columns if `subset` is empty. Depending on `how`, a row is dropped if
+    /// any or all of these columns are missing. A dataframe without columns is returned unchanged.
+    ///
+    /// [`raccoon::missing`]: ../missing/index.html
+    ///
+    /// # Errors
+    /// `RaccoonError::ColumnNotFound` if a column of `subset` does not exist.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataEntry, DataFrame, DropHow, Series};
+    /// let df = DataFrame::from_series(vec![
+    ///     Series::from_vector("a".to_owned(), vec![DataEntry::Integer(1), DataEntry::NA, DataEntry::NA]),
+    ///     Series::from_vector("b".to_owned(), vec![DataEntry::NA, DataEntry::Integer(2), DataEntry::NA]),
+    /// ]).unwrap();
+    ///
+    /// assert_eq!(df.dropna(DropHow::Any, &[]).unwrap().len(), 0);
+    /// assert_eq!(df.dropna(DropHow::All, &[]).unwrap().len(), 2);
+    /// assert_eq!(df.dropna(DropHow::Any, &["a"]).unwrap()["a"], vec![1]);
+    /// ```
+    pub fn dropna(&self, how: DropHow, subset: &[&str]) -> RaccoonResult<DataFrame> {
+        let columns: Vec<&Series> = if subset.is_empty() {
+            self.columns.iter().collect()
+        } else {
+            subset.iter()
+                .map(|name| self.column(name).ok_or_else(|| RaccoonError::ColumnNotFound(name.to_string())))
+                .collect::<RaccoonResult<_>>()?
+        };
+        if columns.is_empty() {
+            return Ok(self.clone());
+        }
+        let missing: Vec<Vec<bool>> = columns.iter()
+            .map(|series| series.iter().map(|x| x.is_missing()).collect())
+            .collect();
+        let positions: Vec<usize> = (0..self.len())
+            .filter(|&row| match how {
+                DropHow::Any    => !missing.iter().any(|column| column[row]),
+                DropHow::All    => !missing.iter().all(|column| column[row]),
+            })
+            .collect();
+        self.take(&positions)
+    }
+
+    /// Returns a new dataframe in which the missing values of each column named in `fills` are replaced according to
+    /// its fill strategy, see [`Series::fillna`]. The other columns are kept as they are.
+    ///
+    /// [`Series::fillna`]: ../series/struct.Series.html#method.fillna
+    ///
+    /// # Errors
+    /// - `RaccoonError::ColumnNotFound` if a column of `fills` does not exist.
+    /// - `RaccoonError::TypeMismatch` if a fill value cannot be converted to the data type of its column.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataEntry, DataFrame, Fill, Series};
+    /// # use std::collections::HashMap;
+    /// let df = DataFrame::from_series(vec![
+    ///     Series::from_vector("name".to_owned(), vec![DataEntry::NA, DataEntry::from("Tom")]),
+    ///     Series::from_vector("age".to_owned(), vec![DataEntry::Integer(31), DataEntry::NA]),
+    /// ]).unwrap();
+    ///
+    /// let mut fills = HashMap::new();
+    /// fills.insert("name", Fill::Value(DataEntry::from("unknown")));
+    /// fills.insert("age", Fill::Forward);
+    ///
+    /// let filled = df.fillna(&fills).unwrap();
+    /// assert_eq!(filled["name"], vec!["unknown", "Tom"]);
+    /// assert_eq!(filled["age"], vec![31, 31]);
+    /// ```
+    pub fn fillna(&self, fills: &HashMap<&str, Fill>) -> RaccoonResult<DataFrame> {
+        if let Some(name) = fills.keys().find(|name| self.column(name).is_none()) {
+            return Err(RaccoonError::ColumnNotFound(name.to_string()));
+        }
+        let columns = self.columns.iter()
+            .map(|series| match fills.get(series.name()) {
+                Some(fill)  => series.fillna(fill.clone()),
+                None        => Ok(series.clone()),
+            })
+            .collect::<RaccoonResult<_>>()?;
+        Ok(DataFrame::from_parts(columns, self.index.clone(), self.column_index.clone()))
+    }
+
+    /// Returns a new dataframe with its rows sorted by the given key columns. Rows are compared by the first key, and
+    /// rows having equal first keys by the following keys. The sort is stable and places missing values last. See
+    /// [`raccoon::sort`] for details, and [`Sorter`] to place missing values first.
+    ///
+    /// [`raccoon::sort`]: ../sort/index.html
+    /// [`Sorter`]: ../sort/struct.Sorter.html
+    ///
+    /// # Errors
+    /// `RaccoonError::ColumnNotFound` if a key column does not exist.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataFrame, Series, SortOrder};
+    /// let df = DataFrame::from_series(vec![
+    ///     Series::from_vector("city".to_owned(), vec!["Zürich", "Geneva", "Basel"]),
+    ///     Series::from_vector("population".to_owned(), vec![415_367, 201_818, 171_017]),
+    /// ]).unwrap();
+    ///
+    /// let sorted = df.sort_by(&[("population", SortOrder::Ascending)]).unwrap();
+    /// assert_eq!(sorted["city"], vec!["Basel", "Geneva", "Zürich"]);
+    /// ```
+    pub fn sort_by(&self, keys: &[(&str, SortOrder)]) -> RaccoonResult<DataFrame> {
+        let mut sorter = Sorter::new();
+        for &(name, order) in keys {
+            let series = self.column(name).ok_or_else(|| RaccoonError::ColumnNotFound(name.to_owned()))?;
+            sorter = sorter.by(series, order);
+        }
+        if keys.is_empty() {
+            return Ok(self.clone());
+        }
+        self.take(&sorter.argsort()?)
+    }
+
+    /// Joins the rows of the dataframe with the rows of `other` having the same values in the `on` key columns. See
+    /// [`raccoon::merge`] for more details, and [`Merge`] for more options.
+    ///
+    /// [`raccoon::merge`]: ../merge/index.html
+    /// [`Merge`]: ../merge/struct.Merge.html
+    ///
+    /// # Errors
+    /// See [`Merge::join`].
+    ///
+    /// [`Merge::join`]: ../merge/struct.Merge.html#method.join
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataFrame, JoinType, Series};
+    /// let scores = DataFrame::from_series(vec![
+    ///     Series::from_vector("player".to_owned(), vec!["Anna", "Tom", "Eva"]),
+    ///     Series::from_vector("score".to_owned(), vec![12, 9, 15]),
+    /// ]).unwrap();
+    /// let teams = DataFrame::from_series(vec![
+    ///     Series::from_vector("player".to_owned(), vec!["Eva", "Anna"]),
+    ///     Series::from_vector("team".to_owned(), vec!['b', 'a']),
+    /// ]).unwrap();
+    ///
+    /// let merged = scores.merge(&teams, &["player"], JoinType::Inner).unwrap();
+    /// assert_eq!(merged["player"], vec!["Anna", "Eva"]);
+    /// assert_eq!(merged["team"], vec!['a', 'b']);
+    /// ```
+    pub fn merge(&self, other: &DataFrame, on: &[&str], how: JoinType) -> RaccoonResult<DataFrame> {
+        Merge::new(self, other).on(on).how(how).join()
+    }
+
+    /// Joins the rows of the dataframe with the rows of `other` having the same labels in their indexes. Columns
+    /// present in both frames are suffixed with `_x` and `_y`. See [`raccoon::merge`] for more details.
+    ///
+    /// [`raccoon::merge`]: ../merge/index.html
+    ///
+    /// # Errors
+    /// See [`Merge::join`].
+    ///
+    /// [`Merge::join`]: ../merge/struct.Merge.html#method.join
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataEntry, DataFrame, JoinType, Series};
+    /// let heights = DataFrame::from_series(vec![
+    ///     Series::from_vector("peak".to_owned(), vec!["Eiger", "Tödi", "Rigi"]),
+    ///     Series::from_vector("height".to_owned(), vec![3967, 3614, 1798]),
+    /// ]).unwrap().set_index("peak").unwrap();
+    /// let cantons = DataFrame::from_series(vec![
+    ///     Series::from_vector("peak".to_owned(), vec!["Rigi", "Eiger"]),
+    ///     Series::from_vector("canton".to_owned(), vec!["SZ", "BE"]),
+    /// ]).unwrap().set_index("peak").unwrap();
+    ///
+    /// let joined = heights.join(&cantons, JoinType::Left).unwrap();
+    /// assert_eq!(joined.index().to_series(), vec!["Eiger", "Tödi", "Rigi"]);
+    /// assert_eq!(joined["canton"], vec![DataEntry::from("BE"), DataEntry::NA, DataEntry::from("SZ")]);
+    /// ```
+    pub fn join(&self, other: &DataFrame, how: JoinType) -> RaccoonResult<DataFrame> {
+        Merge::new(self, other).left_index(true).right_index(true).how(how).join()
+    }
+
+    /// Returns the index labelling the rows of the dataframe, see [`raccoon::index`].
+    ///
+    /// [`raccoon::index`]: ../index/index.html
+    pub fn index(&self) -> &Index {
+        &self.index
+    }
+
+    /// Returns the dataframe with its rows labelled by `index`.
+    ///
+    /// # Errors
+    /// `RaccoonError::LengthMismatch` if `index` does not have as many labels as the dataframe has rows.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataFrame, Index, Series};
+    /// let df = DataFrame::from_series(vec![Series::from_vector("a".to_owned(), vec![1, 2])]).unwrap();
+    /// let df = df.with_index(Index::from(vec!['x', 'y'])).unwrap();
+    /// assert_eq!(df.loc('y').unwrap()["a"], vec![2]);
+    /// ```
+    pub fn with_index(self, index: Index) -> RaccoonResult<DataFrame> {
+        if index.len() != self.len() {
+            return Err(RaccoonError::LengthMismatch { expected: self.len(), found: index.len() });
+        }
+        Ok(DataFrame::from_parts(self.columns, index, self.column_index))
+    }
+
+    /// Returns a new dataframe whose rows are labelled by the entries of the column `name`, which is removed from the
+    /// columns. The index is a datetime index if the column holds dates or date times, and a label index otherwise.
+    ///
+    /// # Errors
+    /// `RaccoonError::ColumnNotFound` if the column does not exist.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataEntry, DataFrame, Series};
+    /// let df = DataFrame::from_series(vec![
+    ///     Series::from_vector("code".to_owned(), vec!["ZH", "BE"]),
+    ///     Series::from_vector("area".to_owned(), vec![1729, 5959]),
+    /// ]).unwrap();
+    ///
+    /// let df = df.set_index("code").unwrap();
+    /// assert_eq!(df.columns(), vec!["area"]);
+    /// assert_eq!(df.index().name(), Some("code"));
+    /// assert_eq!(df.index().get(1), Some(DataEntry::from("BE")));
+    /// ```
+    pub fn set_index(&self, name: &str) -> RaccoonResult<DataFrame> {
+        let position = self.position(name).ok_or_else(|| RaccoonError::ColumnNotFound(name.to_owned()))?;
+        let mut columns = self.columns.clone();
+        let index = Index::from_series(columns.remove(position));
+        Ok(DataFrame::from_parts(columns, index, None))
+    }
+
+    /// Returns a new dataframe whose rows are labelled by a multi index, whose levels hold the entries of the columns
+    /// `names` and are named after them. The columns are removed. See [`raccoon::multiindex`].
+    ///
+    /// [`raccoon::multiindex`]: ../multiindex/index.html
+    ///
+    /// # Errors
+    /// - `RaccoonError::ColumnNotFound` if a column does not exist.
+    /// - `RaccoonError::InvalidArgument` if `names` is empty.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataEntry, DataFrame, Series};
+    /// let df = DataFrame::from_series(vec![
+    ///     Series::from_vector("canton".to_owned(), vec!["ZH", "ZH"]),
+    ///     Series::from_vector("year".to_owned(), vec![2020, 2021]),
+    ///     Series::from_vector("births".to_owned(), vec![16_324, 16_070]),
+    /// ]).unwrap();
+    ///
+    /// let df = df.set_multi_index(&["canton", "year"]).unwrap();
+    /// assert_eq!(df.columns(), vec!["births"]);
+    /// assert_eq!(df.index().multi().unwrap().get(1), Some(vec![DataEntry::from("ZH"), DataEntry::Integer(2021)]));
+    /// ```
+    pub fn set_multi_index(&self, names: &[&str]) -> RaccoonResult<DataFrame> {
+        let levels = names.iter()
+            .map(|name| self.column(name).cloned().ok_or_else(|| RaccoonError::ColumnNotFound(name.to_string())))
+            .collect::<RaccoonResult<Vec<Series>>>()?;
+        let index = Index::from(MultiIndex::from_series(levels)?);
+        let columns = self.columns.iter().filter(|series| !names.contains(&series.name())).cloned().collect();
+        Ok(DataFrame::from_parts(columns, index, None))
+    }
+
+    /// Returns a new dataframe whose labels are moved into its first column, named after the index or `"index"` if
+    /// the index is unnamed, and whose rows are labelled by a range index. The levels of a multi index are moved into
+    /// the first columns, and unnamed levels are named `"level_"` followed by their position.
+    ///
+    /// # Errors
+    /// `RaccoonError::DuplicateColumn` if a column already has the name of the index.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataFrame, IndexKind, Series};
+    /// let df = DataFrame::from_series(vec![
+    ///     Series::from_vector("code".to_owned(), vec!["ZH", "BE"]),
+    ///     Series::from_vector("area".to_owned(), vec![1729, 5959]),
+    /// ]).unwrap();
+    ///
+    /// let df = df.set_index("code").unwrap().reset_index().unwrap();
+    /// assert_eq!(df.columns(), vec!["code", "area"]);
+    /// assert_eq!(df.index().kind(), IndexKind::Range);
+    ///
+    /// let df = df.reset_index().unwrap();
+    /// assert_eq!(df.columns(), vec!["index", "code", "area"]);
+    /// assert_eq!(df["index"], vec![0i64, 1]);
+    /// ```
+    pub fn reset_index(&self) -> RaccoonResult<DataFrame> {
+        let labels: Vec<Series> = match self.index.multi() {
+            Some(multi) => {
+                multi.levels().iter().enumerate()
+                    .map(|(idx, level)| {
+                        let mut labels = level.to_series();
+                        if level.name().is_none() {
+                            labels.set_name(format!("level_{}", idx));
+                        }
+                        labels
+                    })
+                    .collect()
+            },
+            None        => vec![self.index.to_series()],
+        };
+        if let Some(labels) = labels.iter().find(|labels| self.position(labels.name()).is_some()) {
+            return Err(RaccoonError::DuplicateColumn(labels.name().to_owned()));
+        }
+        let mut columns = Vec::with_capacity(self.columns.len() + labels.len());
+        columns.extend(labels);
+        columns.extend(self.columns.iter().cloned());
+        Ok(DataFrame::from_parts(columns, Index::range(self.len()), None))
+    }
+
+    /// Returns a new dataframe containing the rows selected by label, along with their labels. The selector is a single
+    /// label, a range of labels including both ends, or a vector of labels, see [`raccoon::index`].
+    ///
+    /// [`raccoon::index`]: ../index/index.html
+    ///
+    /// # Errors
+    /// - `RaccoonError::LabelNotFound` if a selected label is not in the index. The ends of a range must be in the
+    ///   index, unless it is a range or a datetime index.
+    /// - `RaccoonError::TypeMismatch` if the ends of a range cannot be compared with the labels.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataFrame, Series};
+    /// # use raccoon::chrono::NaiveDate;
+    /// let days = (1..=5).map(|day| NaiveDate::from_ymd_opt(2021, 6, day).unwrap()).collect();
+    /// let df = DataFrame::from_series(vec![
+    ///     Series::from_vector("day".to_owned(), days),
+    ///     Series::from_vector("rain".to_owned(), vec![0.0, 2.5, 11.0, 0.5, 0.0]),
+    /// ]).unwrap().set_index("day").unwrap();
+    ///
+    /// assert_eq!(df.loc("2021-06-03").unwrap()["rain"], vec![11.0]);
+    /// assert_eq!(df.loc("2021-06-04"..).unwrap()["rain"], vec![0.5, 0.0]);
+    /// assert!(df.loc("2021-07-01").is_err());
+    /// ```
+    pub fn loc<S>(&self, selector: S) -> RaccoonResult<DataFrame> where S: Into<LabelSelector> {
+        self.take(&self.index.select(&selector.into())?)
+    }
+
+    /// Returns a new dataframe containing the rows selected by position, along with their labels. The selector is a
+    /// single position, a range of positions, or a vector of positions, see [`raccoon::index`]. Ranges are clipped to
+    /// the rows available.
+    ///
+    /// [`raccoon::index`]: ../index/index.html
+    ///
+    /// # Errors
+    /// `RaccoonError::IndexOutOfBounds` if a single position or a position of a vector is not smaller than the number
+    /// of rows.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataFrame, Series};
+    /// let df = DataFrame::from_series(vec![Series::from_vector("a".to_owned(), vec![1, 2, 3, 4])]).unwrap();
+    /// assert_eq!(df.iloc(2).unwrap()["a"], vec![3]);
+    /// assert_eq!(df.iloc(..2).unwrap()["a"], vec![1, 2]);
+    /// assert_eq!(df.iloc(3..10).unwrap()["a"], vec![4]);
+    /// assert_eq!(df.iloc(vec![3, 0]).unwrap()["a"], vec![4, 1]);
+    /// assert!(df.iloc(4).is_err());
+    /// ```
+    pub fn iloc<S>(&self, selector: S) -> RaccoonResult<DataFrame> where S: Into<PositionSelector> {
+        self.take(&selector.into().positions(self.len())?)
+    }
+
+    /// Returns a new dataframe labelled by `index`, holding for each label the row having this label, or
+    /// `DataEntry::NA` entries if no row has this label.
+    ///
+    /// # Errors
+    /// `RaccoonError::InvalidArgument` if a label is repeated in the index of the dataframe.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataEntry, DataFrame, Index, Series};
+    /// let df = DataFrame::from_series(vec![
+    ///     Series::from_vector("code".to_owned(), vec!["ZH", "BE"]),
+    ///     Series::from_vector("area".to_owned(), vec![1729, 5959]),
+    /// ]).unwrap().set_index("code").unwrap();
+    ///
+    /// let df = df.reindex(vec!["BE", "GE"]).unwrap();
+    /// assert_eq!(df["area"], vec![DataEntry::Integer(5959), DataEntry::NA]);
+    /// ```
+    pub fn reindex<I>(&self, index: I) -> RaccoonResult<DataFrame> where I: Into<Index> {
+        if !self.index.is_unique() {
+            return Err(RaccoonError::InvalidArgument("cannot reindex an index with repeated labels".to_owned()));
+        }
+        let index = index.into();
+        let positions: Vec<Option<usize>> = index.to_series().iter()
+            .map(|label| self.index.positions(&label).first().cloned())
+            .collect();
+        let columns = self.columns.iter().map(|series| series.take_or_na(&positions)).collect();
+        Ok(DataFrame::from_parts(columns, index, self.column_index.clone()))
+    }
+
+    /// Returns copies of the dataframe and of `other` whose rows are matched by label, such that both have the same
+    /// index. The rows are combined as in [`join`], and columns of a frame are filled with `DataEntry::NA` for the
+    /// labels it does not have.
+    ///
+    /// [`join`]: #method.join
+    ///
+    /// # Errors
+    /// - `RaccoonError::InvalidArgument` if `how` is `JoinType::Cross`.
+    /// - `RaccoonError::TypeMismatch` if the labels of both indexes cannot be compared.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataEntry, DataFrame, JoinType, Series};
+    /// let left = DataFrame::from_series(vec![
+    ///     Series::from_vector("key".to_owned(), vec!['a', 'b']),
+    ///     Series::from_vector("x".to_owned(), vec![1, 2]),
+    /// ]).unwrap().set_index("key").unwrap();
+    /// let right = DataFrame::from_series(vec![
+    ///     Series::from_vector("key".to_owned(), vec!['c', 'a']),
+    ///     Series::from_vector("x".to_owned(), vec![30, 10]),
+    /// ]).unwrap().set_index("key").unwrap();
+    ///
+    /// let (left, right) = left.align(&right, JoinType::Outer).unwrap();
+    /// assert_eq!(left.index(), right.index());
+    /// assert_eq!(left.index().to_series(), vec!['a', 'b', 'c']);
+    /// assert_eq!(left["x"], vec![DataEntry::Integer(1), DataEntry::Integer(2), DataEntry::NA]);
+    /// assert_eq!(right["x"], vec![DataEntry::Integer(10), DataEntry::NA, DataEntry::Integer(30)]);
+    /// ```
+    pub fn align(&self, other: &DataFrame, how: JoinType) -> RaccoonResult<(DataFrame, DataFrame)> {
+        let (pairs, index) = merge::align(&self.index, &other.index, how)?;
+        let left_rows: Vec<Option<usize>> = pairs.iter().map(|pair| pair.0).collect();
+        let right_rows: Vec<Option<usize>> = pairs.iter().map(|pair| pair.1).collect();
+        let left_columns = self.columns.iter().map(|series| series.take_or_na(&left_rows)).collect();
+        let right_columns = other.columns.iter().map(|series| series.take_or_na(&right_rows)).collect();
+        let left = DataFrame::from_parts(left_columns, index.clone(), self.column_index.clone());
+        let right = DataFrame::from_parts(right_columns, index, other.column_index.clone());
+        Ok((left, right))
+    }
+
+    /// Returns the multi index labelling the columns after [`unstack`], or `None` if the columns are only labelled by
+    /// their names. Adding or removing columns drops these labels.
+    ///
+    /// [`unstack`]: #method.unstack
+    pub fn column_index(&self) -> Option<&MultiIndex> {
+        self.column_index.as_ref()
+    }
+
+    /// Returns a new dataframe containing the rows whose label at `level` is `key`, without this level in their labels.
+    /// An index that is not a multi index is its only level, and the rows selected are labelled by a range index. See
+    /// [`raccoon::multiindex`].
+    ///
+    /// [`raccoon::multiindex`]: ../multiindex/index.html
+    ///
+    /// # Errors
+    /// - `RaccoonError::LevelNotFound` if the level does not exist.
+    /// - `RaccoonError::LabelNotFound` if no row has the label.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataFrame, Series};
+    /// let df = DataFrame::from_series(vec![
+    ///     Series::from_vector("canton".to_owned(), vec!["ZH", "ZH", "BE"]),
+    ///     Series::from_vector("year".to_owned(), vec![2020, 2021, 2021]),
+    ///     Series::from_vector("births".to_owned(), vec![16_324, 16_070, 10_127]),
+    /// ]).unwrap().set_multi_index(&["canton", "year"]).unwrap();
+    ///
+    /// let zurich = df.xs("ZH", 0).unwrap();
+    /// assert_eq!(zurich.index().name(), Some("year"));
+    /// assert_eq!(zurich.index().to_series(), vec![2020, 2021]);
+    /// assert!(df.xs(2019, "year").is_err());
+    /// ```
+    pub fn xs<T, L>(&self, key: T, level: L) -> RaccoonResult<DataFrame> where T: Into<DataEntry>, L: Into<Level> {
+        let key = key.into();
+        let multi = MultiIndex::from(self.index.clone());
+        let position = multi.position(level)?;
+        let positions = multi.levels()[position].positions(&key);
+        if positions.is_empty() {
+            return Err(RaccoonError::LabelNotFound(format!("{:?}", key)));
+        }
+        let index = if multi.nlevels() == 1 {
+            Index::range(positions.len())
+        } else {
+            multi.droplevel(position)?.take(&positions)
+        };
+        let columns = self.columns.iter().map(|series| series.take_entries(&positions)).collect::<RaccoonResult<_>>()?;
+        Ok(DataFrame::from_parts(columns, index, self.column_index.clone()))
+    }
+
+    /// Returns a new dataframe whose rows are labelled by the levels of the index, with levels `first` and `second`
+    /// swapped.
+    ///
+    /// # Errors
+    /// `RaccoonError::LevelNotFound` if either level does not exist.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataFrame, Series};
+    /// let df = DataFrame::from_series(vec![
+    ///     Series::from_vector("canton".to_owned(), vec!["ZH", "BE"]),
+    ///     Series::from_vector("year".to_owned(), vec![2020, 2021]),
+    ///     Series::from_vector("births".to_owned(), vec![16_324, 10_127]),
+    /// ]).unwrap().set_multi_index(&["canton", "year"]).unwrap();
+    ///
+    /// let df = df.swaplevel("canton", "year").unwrap();
+    /// assert_eq!(df.index().to_series(), vec![2020, 2021]);
+    /// assert_eq!(df.loc(2021).unwrap()["births"], vec![10_127]);
+    /// ```
+    pub fn swaplevel<L, M>(&self, first: L, second: M) -> RaccoonResult<DataFrame>
+        where L: Into<Level>, M: Into<Level> {
+        let index = Index::from(MultiIndex::from(self.index.clone()).swaplevel(first, second)?);
+        Ok(DataFrame::from_parts(self.columns.clone(), index, self.column_index.clone()))
+    }
+
+    /// Returns a new dataframe whose rows are sorted by their labels at `level`, then by the labels of the other levels
+    /// from the outermost to the innermost. Missing labels are placed last. See [`MultiIndex::argsort`].
+    ///
+    /// [`MultiIndex::argsort`]: ../multiindex/struct.MultiIndex.html#method.argsort
+    ///
+    /// # Errors
+    /// `RaccoonError::LevelNotFound` if the level does not exist.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataFrame, Series};
+    /// let df = DataFrame::from_series(vec![
+    ///     Series::from_vector("canton".to_owned(), vec!["ZH", "BE", "ZH", "BE"]),
+    ///     Series::from_vector("year".to_owned(), vec![2021, 2021, 2020, 2020]),
+    ///     Series::from_vector("births".to_owned(), vec![16_070, 10_127, 16_324, 10_075]),
+    /// ]).unwrap().set_multi_index(&["canton", "year"]).unwrap();
+    ///
+    /// assert_eq!(df.sort_level("canton", true).unwrap()["births"], vec![10_075, 10_127, 16_324, 16_070]);
+    /// assert_eq!(df.sort_level(1, false).unwrap()["births"], vec![16_070, 10_127, 16_324, 10_075]);
+    /// ```
+    pub fn sort_level<L>(&self, level: L, ascending: bool) -> RaccoonResult<DataFrame> where L: Into<Level> {
+        self.take(&MultiIndex::from(self.index.clone()).argsort(level, ascending)?)
+    }
+
+    /// Returns a new dataframe in which `level` of the index is moved into the columns. Each column is split into one
+    /// column for every label of the level, in ascending order, holding the entries of the rows having this label, or
+    /// `DataEntry::NA`. Rows are labelled by the other levels, in the order of their first rows. The columns are
+    /// labelled by a multi index made of the levels of the columns and the level moved, see [`column_index`], and
+    /// named after these labels, see [`raccoon::multiindex`].
+    ///
+    /// [`column_index`]: #method.column_index
+    /// [`raccoon::multiindex`]: ../multiindex/index.html
+    ///
+    /// # Errors
+    /// - `RaccoonError::LevelNotFound` if the level does not exist.
+    /// - `RaccoonError::InvalidArgument` if the index has a single level, or if several rows have the same labels.
+    /// - `RaccoonError::DuplicateColumn` if the names of two columns built are the same.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataEntry, DataFrame, Series};
+    /// let df = DataFrame::from_series(vec![
+    ///     Series::from_vector("canton".to_owned(), vec!["ZH", "ZH", "BE"]),
+    ///     Series::from_vector("year".to_owned(), vec![2021, 2020, 2021]),
+    ///     Series::from_vector("births".to_owned(), vec![16_070, 16_324, 10_127]),
+    /// ]).unwrap().set_multi_index(&["canton", "year"]).unwrap();
+    ///
+    /// let wide = df.unstack("canton").unwrap();
+    /// assert_eq!(wide.columns(), vec!["births_BE", "births_ZH"]);
+    /// assert_eq!(wide.index().to_series(), vec![2021, 2020]);
+    /// assert_eq!(wide["births_BE"], vec![DataEntry::Integer(10_127), DataEntry::NA]);
+    /// assert_eq!(wide.column_index().unwrap().names(), vec![None, Some("canton")]);
+    /// ```
+    pub fn unstack<L>(&self, level: L) -> RaccoonResult<DataFrame> where L: Into<Level> {
+        let multi = MultiIndex::from(self.index.clone());
+        let position = multi.position(level)?;
+        if multi.nlevels() == 1 {
+            return Err(RaccoonError::InvalidArgument("cannot unstack the only level of an index".to_owned()));
+        }
+        let level = &multi.levels()[position];
+        let rest = multi.droplevel(position)?;
+        let rest_levels = MultiIndex::from(rest.clone());
+
+        // rows of the result, in the order of their first rows
+        let mut rows: HashMap<Vec<OrderedEntry>, usize> = HashMap::new();
+        let mut first_rows = Vec::new();
+        let row_of: Vec<usize> = (0..self.len())
+            .map(|idx| {
+                let count = rows.len();
+                *rows.entry(rest_levels.key(idx)).or_insert_with(|| {
+                    first_rows.push(idx);
+                    count
+                })
+            })
+            .collect();
+        let mut labels: Vec<OrderedEntry> = (0..self.len())
+            .map(|idx| OrderedEntry(level.get(idx).unwrap_or(DataEntry::NA)))
+            .collect();
+        labels.sort();
+        labels.dedup();
+        let label_of: HashMap<&OrderedEntry, usize> = labels.iter().enumerate()
+            .map(|(idx, label)| (label, idx))
+            .collect();
+
+        let mut cells: Vec<Option<usize>> = vec![None; first_rows.len() * labels.len()];
+        for (idx, &row) in row_of.iter().enumerate() {
+            let cell = row * labels.len() + label_of[&OrderedEntry(level.get(idx).unwrap_or(DataEntry::NA))];
+            if cells[cell].replace(idx).is_some() {
+                return Err(RaccoonError::InvalidArgument("cannot unstack rows having the same labels".to_owned()));
+            }
+        }
+
+        let column_levels = self.column_levels();
+        let mut columns = Vec::with_capacity(self.columns.len() * labels.len());
+        let mut keys: Vec<Vec<DataEntry>> = Vec::with_capacity(self.columns.len() * labels.len());
+        for (idx, series) in self.columns.iter().enumerate() {
+            for (label_idx, label) in labels.iter().enumerate() {
+                let positions: Vec<Option<usize>> = (0..first_rows.len())
+                    .map(|row| cells[row * labels.len() + label_idx])
+                    .collect();
+                let mut key = column_levels.get(idx).expect("every column has labels");
+                key.push(label.0.clone());
+                columns.push(series.take_or_na(&positions));
+                keys.push(key);
+            }
+        }
+        let mut df = DataFrame::with_capacity(columns.len());
+        for (mut column, name) in columns.into_iter().zip(multiindex::column_names(&keys)) {
+            column.set_name(name);
+            df.add_column(column)?;
+        }
+        let mut names = column_levels.names();
+        names.push(level.name());
+        let mut df = df.with_index(rest.take(&first_rows))?;
+        df.column_index = Some(labels_index(&keys, &names));
+        Ok(df)
+    }
+
+    /// Returns a new dataframe in which the last level of the columns is moved into the index. Columns having the
+    /// same labels at the other levels are merged into one column, holding for every row one row for each label of
+    /// the level moved, in the order of first appearance. Rows whose entries are all missing are dropped. The data
+    /// types of merged columns are promoted to a common type.
+    ///
+    /// Columns are labelled by their names if they are not labelled by a multi index, see [`column_index`], and are
+    /// then merged into a single column named `"0"`.
+    ///
+    /// [`column_index`]: #method.column_index
+    ///
+    /// # Errors
+    /// - `RaccoonError::TypeMismatch` if merged columns have no common data type.
+    /// - `RaccoonError::InvalidArgument` if several columns have the same labels.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataEntry, DataFrame, Series};
+    /// let df = DataFrame::from_series(vec![
+    ///     Series::from_vector("min".to_owned(), vec![DataEntry::Integer(-3), DataEntry::NA]),
+    ///     Series::from_vector("max".to_owned(), vec![4.5, 2.5]),
+    /// ]).unwrap();
+    ///
+    /// let long = df.stack().unwrap();
+    /// assert_eq!(long.columns(), vec!["0"]);
+    /// assert_eq!(long["0"], vec![-3.0, 4.5, 2.5]);
+    /// assert_eq!(long.index().multi().unwrap().get(2), Some(vec![DataEntry::Long(1), DataEntry::from("max")]));
+    /// ```
+    pub fn stack(&self) -> RaccoonResult<DataFrame> {
+        let column_levels = self.column_levels();
+        let last = column_levels.nlevels() - 1;
+        let level = &column_levels.levels()[last];
+        let rest = if last == 0 { None } else { Some(MultiIndex::from(column_levels.droplevel(last)?)) };
+
+        // groups of merged columns, and labels moved, in the order of first appearance
+        let mut groups: HashMap<Vec<OrderedEntry>, usize> = HashMap::new();
+        let mut labels: HashMap<OrderedEntry, usize> = HashMap::new();
+        let (mut first_columns, mut label_entries) = (Vec::new(), Vec::new());
+        let mut sources: HashMap<(usize, usize), usize> = HashMap::new();
+        for idx in 0..self.columns.len() {
+            let key = rest.as_ref().map_or_else(Vec::new, |rest| rest.key(idx));
+            let count = groups.len();
+            let group = *groups.entry(key).or_insert_with(|| {
+                first_columns.push(idx);
+                count
+            });
+            let label = level.get(idx).unwrap_or(DataEntry::NA);
+            let count = labels.len();
+            let label = *labels.entry(OrderedEntry(label.clone())).or_insert_with(|| {
+                label_entries.push(label);
+                count
+            });
+            if sources.insert((group, label), idx).is_some() {
+                return Err(RaccoonError::InvalidArgument("cannot stack columns having the same labels".to_owned()));
+            }
+        }
+
+        let mut data_types = Vec::with_capacity(first_columns.len());
+        for group in 0..first_columns.len() {
+            let mut data_type = DataType::NA;
+            for label in 0..label_entries.len() {
+                if let Some(&idx) = sources.get(&(group, label)) {
+                    let found = self.columns[idx].data_type();
+                    data_type = match data_type.common_type(found) {
+                        Some(data_type) => data_type,
+                        None            => {
+                            return Err(RaccoonError::TypeMismatch { expected: data_type, found: found.clone() })
+                        },
+                    };
+                }
+            }
+            data_types.push(data_type);
+        }
+
+        let (mut rows, mut row_labels) = (Vec::new(), Vec::new());
+        let mut entries: Vec<Vec<DataEntry>> = vec![Vec::new(); first_columns.len()];
+        for row in 0..self.len() {
+            for (label_idx, label) in label_entries.iter().enumerate() {
+                let row_entries: Vec<DataEntry> = (0..first_columns.len())
+                    .map(|group| {
+                        sources.get(&(group, label_idx))
+                            .and_then(|&idx| self.columns[idx].get(row))
+                            .unwrap_or(DataEntry::NA)
+                    })
+                    .collect();
+                if row_entries.iter().all(|entry| entry.is_missing()) {
+                    continue;
+                }
+                rows.push(row);
+                row_labels.push(label.clone());
+                for (column, entry) in entries.iter_mut().zip(row_entries) {
+                    column.push(entry);
+                }
+            }
+        }
+
+        let names = match rest {
+            Some(ref rest)  => {
+                let keys: Vec<Vec<DataEntry>> = first_columns.iter()
+                    .map(|&first| rest.get(first).expect("every column has labels"))
+                    .collect();
+                multiindex::column_names(&keys)
+            },
+            None            => vec!["0".to_owned()],
+        };
+        let mut df = DataFrame::with_capacity(first_columns.len());
+        for ((entries, data_type), name) in entries.into_iter().zip(data_types).zip(names) {
+            df.add_column(Series::from_entries(name, data_type, entries))?;
+        }
+        let mut levels: Vec<Index> = self.index.levels().iter().map(|level| level.take(&rows)).collect();
+        let stacked = Index::from(row_labels);
+        levels.push(match level.name() {
+            Some(name)  => stacked.with_name(name),
+            None        => stacked,
+        });
+        let mut df = df.with_index(Index::from(MultiIndex::new(levels)?))?;
+        df.column_index = rest.filter(|rest| rest.nlevels() > 1).map(|rest| rest.take(&first_columns));
+        Ok(df)
+    }
+
+    /// Returns the dataframe spread from long to wide format: its rows are labelled by the distinct entries of the
+    /// column `index`, and every distinct entry of the column `columns` becomes a column holding the entries of the
+    /// `values` columns, or of all other columns if `values` is empty. See [`raccoon::reshape`] for more details.
+    ///
+    /// [`raccoon::reshape`]: ../reshape/index.html
+    ///
+    /// # Errors
+    /// See [`PivotTable::new`] and [`PivotTable::pivot`], in particular `RaccoonError::InvalidArgument` if a pair of
+    /// an `index` entry and a `columns` entry appears several times.
+    ///
+    /// [`PivotTable::new`]: ../reshape/struct.PivotTable.html#method.new
+    /// [`PivotTable::pivot`]: ../reshape/struct.PivotTable.html#method.pivot
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataEntry, DataFrame, Series};
+    /// let df = DataFrame::from_series(vec![
+    ///     Series::from_vector("day".to_owned(), vec![1, 1, 2]),
+    ///     Series::from_vector("sensor".to_owned(), vec!['a', 'b', 'a']),
+    ///     Series::from_vector("temp".to_owned(), vec![20.5, 21.0, 19.5]),
+    /// ]).unwrap();
+    ///
+    /// let wide = df.pivot("day", "sensor", &["temp"]).unwrap();
+    /// assert_eq!(wide.columns(), vec!["a", "b"]);
+    /// assert_eq!(wide.index().to_series(), vec![1, 2]);
+    /// assert_eq!(wide["b"], vec![DataEntry::Double(21.0), DataEntry::NA]);
+    /// ```
+    pub fn pivot(&self, index: &str, columns: &str, values: &[&str]) -> RaccoonResult<DataFrame> {
+        PivotTable::new(self, index, columns)?.values(values).pivot()
+    }
+
+    /// Returns a [`PivotTable`] spreading the dataframe from long to wide format, whose rows are labelled by the
+    /// distinct entries of the column `index`, and whose columns are built from the distinct entries of the column
+    /// `columns`. Entries of the same pair are aggregated, see [`raccoon::reshape`].
+    ///
+    /// [`PivotTable`]: ../reshape/struct.PivotTable.html
+    /// [`raccoon::reshape`]: ../reshape/index.html
+    ///
+    /// # Errors
+    /// See [`PivotTable::new`].
+    ///
+    /// [`PivotTable::new`]: ../reshape/struct.PivotTable.html#method.new
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{Aggregation, DataFrame, Series};
+    /// let df = DataFrame::from_series(vec![
+    ///     Series::from_vector("day".to_owned(), vec![1, 1, 1, 2]),
+    ///     Series::from_vector("sensor".to_owned(), vec!['a', 'a', 'b', 'b']),
+    ///     Series::from_vector("temp".to_owned(), vec![20.0, 21.0, 19.5, 18.0]),
+    /// ]).unwrap();
+    ///
+    /// let wide = df.pivot_table("day", "sensor").unwrap().fill_value(0.0).aggregate(Aggregation::Mean).unwrap();
+    /// assert_eq!(wide["a"], vec![20.5, 0.0]);
+    /// assert_eq!(wide["b"], vec![19.5, 18.0]);
+    /// ```
+    pub fn pivot_table(&self, index: &str, columns: &str) -> RaccoonResult<PivotTable<'_>> {
+        PivotTable::new(self, index, columns)
+    }
+
+    /// Returns the dataframe gathered from wide to long format: every column of `value_vars`, or every column not in
+    /// `id_vars` if `value_vars` is empty, is turned into rows holding its name in a column `"variable"` and its
+    /// entries in a column `"value"`, next to the entries of the `id_vars` columns. Use a [`Melt`] to name these
+    /// columns differently, see [`raccoon::reshape`].
+    ///
+    /// [`Melt`]: ../reshape/struct.Melt.html
+    /// [`raccoon::reshape`]: ../reshape/index.html
+    ///
+    /// # Errors
+    /// See [`Melt::melt`].
+    ///
+    /// [`Melt::melt`]: ../reshape/struct.Melt.html#method.melt
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataFrame, Series};
+    /// let df = DataFrame::from_series(vec![
+    ///     Series::from_vector("day".to_owned(), vec![1, 2]),
+    ///     Series::from_vector("a".to_owned(), vec![20, 19]),
+    ///     Series::from_vector("b".to_owned(), vec![21, 18]),
+    /// ]).unwrap();
+    ///
+    /// let long = df.melt(&["day"], &[]).unwrap();
+    /// assert_eq!(long["day"], vec![1, 2, 1, 2]);
+    /// assert_eq!(long["variable"], vec!["a", "a", "b", "b"]);
+    /// assert_eq!(long["value"], vec![20, 19, 21, 18]);
+    /// ```
+    pub fn melt(&self, id_vars: &[&str], value_vars: &[&str]) -> RaccoonResult<DataFrame> {
+        let melt = Melt::new(self).id_vars(id_vars);
+        if value_vars.is_empty() { melt.melt() } else { melt.value_vars(value_vars).melt() }
+    }
+
+    /// Builds a dataframe from columns of the length of `index`, labelling the entries of every column by `index`.
+    pub(crate) fn from_parts(mut columns: Vec<Series>, index: Index, column_index: Option<MultiIndex>) -> DataFrame {
+        for series in columns.iter_mut() {
+            series.relabel(index.clone());
+        }
+        DataFrame { columns, index, column_index }
+    }
+
+    /// Returns the labels of the columns: the multi index labelling them, or their names.
+    fn column_levels(&self) -> MultiIndex {
+        match self.column_index {
+            Some(ref column_index)  => column_index.clone(),
+            None                    => {
+                let names: Vec<&str> = self.columns.iter().map(|series| series.name()).collect();
+                MultiIndex::from(Index::from(names))
+            },
+        }
+    }
+}
+
+/// Builds a multi index from tuples of labels having one label for each of the levels named `names`.
+fn labels_index(keys: &[Vec<DataEntry>], names: &[Option<&str>]) -> MultiIndex {
+    let levels = names.iter().enumerate()
+        .map(|(idx, name)| {
+            let level = Index::from(keys.iter().map(|key| key[idx].clone()).collect::<Vec<DataEntry>>());
+            match *name {
+                Some(name)  => level.with_name(name),
+                None        => level,
+            }
+        })
+        .collect();
+    MultiIndex::new(levels).expect("levels of the same tuples have the same length")
+}
+
+impl ops::Index<usize> for DataFrame {
+    type Output = Series;
+
+    fn index(&self, idx: usize) -> &Self::Output {
+        &self.columns[idx]
+    }
+}
+
+impl<'a> ops::Index<&'a str> for DataFrame {
+    type Output = Series;
+
+    fn index(&self, name: &'a str) -> &Self::Output {
+        match self.column(name) {
+            Some(series) => series,
+            None         => panic!("no column named '{}' in dataframe", name),
+        }
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create_dataframe() {
+        let df = DataFrame::from_series(vec![
+            Series::from_vector("city".to_owned(), vec!["Zürich", "Geneva", "Basel"]),
+            Series::from_vector("population".to_owned(), vec![415_367u32, 201_818, 171_017]),
+            Series::from_vector("canton".to_owned(), vec!["ZH", "GE", "BS"]),
+        ]).unwrap();
+        assert_eq!((3, 3), df.shape());
+        assert_eq!(vec!["city", "population", "canton"], df.columns());
+        assert_eq!(vec![&DataType::Text, &DataType::UInteger, &DataType::Text], df.dtypes());
+        assert!(!df.is_empty());
+
+        let df = DataFrame::from_series(Vec::new()).unwrap();
+        assert_eq!((0, 0), df.shape());
+        assert!(df.is_empty());
+    }
+
+    #[test]
+    fn invalid_construction() {
+        let result = DataFrame::from_series(vec![
+            Series::from_vector("a".to_owned(), vec![1, 2, 3]),
+            Series::from_vector("b".to_owned(), vec![1, 2]),
+        ]);
+        assert!(matches!(result, Err(RaccoonError::LengthMismatch { expected: 3, found: 2 })));
+
+        let result = DataFrame::from_series(vec![
+            Series::from_vector("a".to_owned(), vec![1, 2]),
+            Series::from_vector("a".to_owned(), vec![3, 4]),
+        ]);
+        assert!(matches!(result, Err(RaccoonError::DuplicateColumn(ref name)) if name == "a"));
+    }
+
+    #[test]
+    fn column_access() {
+        let df = DataFrame::from_series(vec![
+            Series::from_vector("city".to_owned(), vec!["Zürich", "Geneva", "Basel"]),
+            Series::from_vector("population".to_owned(), vec![415_367u32, 201_818, 171_017]),
+            Series::from_vector("canton".to_owned(), vec!["ZH", "GE", "BS"]),
+        ]).unwrap();
+        assert_eq!(DataEntry::Text("Geneva".to_owned()), df["city"].get(1).unwrap());
+        assert_eq!(DataEntry::UInteger(171_017), df[1usize].get(2).unwrap());
+        assert_eq!(Some(2), df.position("canton"));
+        assert_eq!("canton", df.column_at(2).unwrap().name());
+        assert!(df.column("country").is_none());
+        assert!(df.column_at(3).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected="no column named 'country' in dataframe")]
+    fn missing_column_indexing() {
+        let df = DataFrame::from_series(vec![
+            Series::from_vector("city".to_owned(), vec!["Zürich", "Geneva", "Basel"]),
+            Series::from_vector("population".to_owned(), vec![415_367u32, 201_818, 171_017]),
+            Series::from_vector("canton".to_owned(), vec!["ZH", "GE", "BS"]),
+        ]).unwrap();
+        let _ = &df["country"];
+    }
+
+    #[test]
+    fn add_and_drop_columns() {
+        let mut df = DataFrame::from_series(vec![
+            Series::from_vector("city".to_owned(), vec!["Zürich", "Geneva", "Basel"]),
+            Series::from_vector("population".to_owned(), vec![415_367u32, 201_818, 171_017]),
+            Series::from_vector("canton".to_owned(), vec!["ZH", "GE", "BS"]),
+        ]).unwrap();
+        let result = df.add_column(Series::from_vector("lake".to_owned(), vec![true, true, false]));
+        assert!(result.is_ok());
+        assert_eq!((3, 4), df.shape());
+
+        let result = df.add_column(Series::from_vector("river".to_owned(), vec![true]));
+        assert!(matches!(result, Err(RaccoonError::LengthMismatch { expected: 3, found: 1 })));
+        assert_eq!((3, 4), df.shape());
+
+        let dropped = df.drop_column("population").unwrap();
+        assert_eq!("population", dropped.name());
+        assert_eq!(vec!["city", "canton", "lake"], df.columns());
+
+        let result = df.drop_column("population");
+        assert!(matches!(result, Err(RaccoonError::ColumnNotFound(ref name)) if name == "population"));
+
+        // dropping all columns resets the number of rows
+        let _ = df.drop_column("city");
+        let _ = df.drop_column("canton");
+        let _ = df.drop_column("lake");
+        assert_eq!((0, 0), df.shape());
+        assert!(df.add_column(Series::from_vector("new".to_owned(), vec![1])).is_ok());
+        assert_eq!((1, 1), df.shape());
+    }
+
+    #[test]
+    fn filter_rows() {
+        let df = DataFrame::from_series(vec![
+            Series::from_vector("city".to_owned(), vec!["Zürich", "Geneva", "Basel"]),
+            Series::from_vector("population".to_owned(), vec![415_367u32, 201_818, 171_017]),
+            Series::from_vector("canton".to_owned(), vec!["ZH", "GE", "BS"]),
+        ]).unwrap();
+        let mask = df["canton"].ne("GE").unwrap();
+        let filtered = df.filter(&mask).unwrap();
+        assert_eq!((2, 3), filtered.shape());
+        assert_eq!(filtered["city"], vec!["Zürich", "Basel"]);
+        assert_eq!(filtered["population"], vec![415_367u32, 171_017]);
+
+        let mask = Series::from(vec![DataEntry::Boolean(false), DataEntry::NA, DataEntry::Boolean(true)]);
+        assert_eq!(df.filter(&mask).unwrap()["canton"], vec!["BS"]);
+
+        let result = df.filter(&Series::from(vec![true]));
+        assert!(matches!(result, Err(RaccoonError::LengthMismatch { expected: 3, found: 1 })));
+        let result = df.filter(&df["population"]);
+        assert!(matches!(result, Err(RaccoonError::TypeMismatch { found: DataType::UInteger, .. })));
+        assert!(matches!(df.take(&[0, 3]), Err(RaccoonError::IndexOutOfBounds { index: 3, length: 3 })));
+    }
+
+    #[test]
+    fn sort_rows() {
+        let df = DataFrame::from_series(vec![
+            Series::from_vector("city".to_owned(), vec!["Zürich", "Geneva", "Basel"]),
+            Series::from_vector("population".to_owned(), vec![415_367u32, 201_818, 171_017]),
+            Series::from_vector("canton".to_owned(), vec!["ZH", "GE", "BS"]),
+        ]).unwrap();
+        let sorted = df.sort_by(&[("canton", SortOrder::Descending)]).unwrap();
+        assert_eq!(sorted["canton"], vec!["ZH", "GE", "BS"]);
+        assert_eq!(sorted["population"], vec![415_367u32, 201_818, 171_017]);
+        assert_eq!(df, df.sort_by(&[]).unwrap());
+
+        let result = df.sort_by(&[("country", SortOrder::Ascending)]);
+        assert!(matches!(result, Err(RaccoonError::ColumnNotFound(ref name)) if name == "country"));
+    }
+
+    #[test]
+    fn row_labels() {
+        let df = DataFrame::from_series(vec![
+            Series::from_vector("city".to_owned(), vec!["Zürich", "Geneva", "Basel"]),
+            Series::from_vector("population".to_owned(), vec![415_367u32, 201_818, 171_017]),
+            Series::from_vector("canton".to_owned(), vec!["ZH", "GE", "BS"]),
+        ]).unwrap();
+        assert_eq!(df.index(), &Index::range(3));
+        let filtered = df.filter(&df["canton"].ne("GE").unwrap()).unwrap();
+        assert_eq!(filtered.index().to_series(), vec![0i64, 2]);
+        assert_eq!(filtered.loc(2).unwrap()["city"], vec!["Basel"]);
+        assert!(matches!(filtered.loc(1), Err(RaccoonError::LabelNotFound(_))));
+
+        let df = df.set_index("canton").unwrap();
+        assert_eq!(vec!["city", "population"], df.columns());
+        let sorted = df.sort_by(&[("population", SortOrder::Ascending)]).unwrap();
+        assert_eq!(sorted.index().to_series(), vec!["BS", "GE", "ZH"]);
+        assert_eq!(sorted.loc("GE"..).unwrap()["city"], vec!["Geneva", "Zürich"]);
+        assert_eq!(sorted.iloc(vec![2, 2]).unwrap().index().to_series(), vec!["ZH", "ZH"]);
+        assert!(matches!(df.set_index("canton"), Err(RaccoonError::ColumnNotFound(_))));
+        let result = df.clone().with_index(Index::range(2));
+        assert!(matches!(result, Err(RaccoonError::LengthMismatch { expected: 3, found: 2 })));
+
+        let reset = df.reset_index().unwrap();
+        assert_eq!(vec!["canton", "city", "population"], reset.columns());
+        assert_eq!(reset.index(), &Index::range(3));
+        assert_eq!(reset["canton"], vec!["ZH", "GE", "BS"]);
+
+        // columns carry the labels of the rows
+        assert_eq!(sorted["city"].index(), sorted.index());
+        let density = (&sorted["population"] / &df["population"]).unwrap();
+        assert_eq!(density.index().to_series(), vec!["BS", "GE", "ZH"]);
+        assert_eq!(density, vec![1i64, 1, 1]);
+
+        // the index remains when its only column is moved into it
+        let single = DataFrame::from_series(vec![Series::from_vector("key".to_owned(), vec![1, 2])]).unwrap();
+        let single = single.set_index("key").unwrap();
+        assert_eq!((2, 0), single.shape());
+        assert_eq!(single.loc(vec![2, 1]).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn reindex_and_align() {
+        let df = DataFrame::from_series(vec![
+            Series::from_vector("city".to_owned(), vec!["Zürich", "Geneva", "Basel"]),
+            Series::from_vector("population".to_owned(), vec![415_367u32, 201_818, 171_017]),
+            Series::from_vector("canton".to_owned(), vec!["ZH", "GE", "BS"]),
+        ]).unwrap().set_index("canton").unwrap();
+        let reindexed = df.reindex(vec!["GE", "TI", "GE"]).unwrap();
+        assert_eq!(reindexed.index().to_series().name(), "index");
+        assert_eq!(reindexed["city"], vec![DataEntry::from("Geneva"), DataEntry::NA, DataEntry::from("Geneva")]);
+        assert_eq!(reindexed["population"].data_type(), &DataType::UInteger);
+        assert!(matches!(reindexed.reindex(vec!["GE"]), Err(RaccoonError::InvalidArgument(_))));
+
+        let other = DataFrame::from_series(vec![
+            Series::from_vector("canton".to_owned(), vec!["TI", "ZH"]),
+            Series::from_vector("lakes".to_owned(), vec![2, 4]),
+        ]).unwrap().set_index("canton").unwrap();
+        let (left, right) = df.align(&other, JoinType::Inner).unwrap();
+        assert_eq!(left.index(), right.index());
+        assert_eq!(left["city"], vec!["Zürich"]);
+        assert_eq!(right["lakes"], vec![4]);
+        let (left, right) = df.align(&other, JoinType::Right).unwrap();
+        assert_eq!(left.index().to_series(), vec!["TI", "ZH"]);
+        assert_eq!(left["city"], vec![DataEntry::NA, DataEntry::from("Zürich")]);
+        assert_eq!(right, other);
+        assert!(matches!(df.align(&other, JoinType::Cross), Err(RaccoonError::InvalidArgument(_))));
+        let unlabelled = other.reset_index().unwrap();
+        assert!(matches!(df.align(&unlabelled, JoinType::Inner), Err(RaccoonError::TypeMismatch { .. })));
+    }
+
+    #[test]
+    fn multi_index() {
+        let df = DataFrame::from_series(vec![
+            Series::from_vector("canton".to_owned(), vec!["ZH", "ZH", "BE", "GE"]),
+            Series::from_vector("year".to_owned(), vec![2020, 2021, 2021, 2020]),
+            Series::from_vector("births".to_owned(), vec![16_324, 16_070, 10_127, 5_104]),
+            Series::from_vector("deaths".to_owned(), vec![13_412.0, 12_921.0, 10_630.0, 4_201.0]),
+        ]).unwrap().set_multi_index(&["canton", "year"]).unwrap();
+        assert!(matches!(df.set_multi_index(&["births", "none"]), Err(RaccoonError::ColumnNotFound(_))));
+        assert!(matches!(df.set_multi_index(&[]), Err(RaccoonError::InvalidArgument(_))));
+        assert!(matches!(df.xs(2019, 1), Err(RaccoonError::LabelNotFound(_))));
+        assert!(matches!(df.xs(2020, 2), Err(RaccoonError::LevelNotFound(_))));
+        assert_eq!(df.xs("ZH", "canton").unwrap().xs(2021, 0).unwrap().index(), &Index::range(1));
+
+        // series keep the labels of all levels
+        let rate = (&df["deaths"] / &df.sort_level("year", false).unwrap()["births"]).unwrap();
+        assert_eq!(rate.index().nlevels(), 2);
+        assert_eq!(rate.index().to_series(), vec!["ZH", "ZH", "BE", "GE"]);
+        assert_eq!(rate.get(3).unwrap(), DataEntry::Double(4_201.0 / 5_104.0));
+
+        let wide = df.unstack(1).unwrap();
+        assert_eq!(wide.columns(), vec!["births_2020", "births_2021", "deaths_2020", "deaths_2021"]);
+        assert_eq!(wide["deaths_2021"], vec![DataEntry::Double(12_921.0), DataEntry::Double(10_630.0), DataEntry::NA]);
+        let long = wide.stack().unwrap();
+        assert_eq!(long.columns(), vec!["births", "deaths"]);
+        assert_eq!(long.index(), df.index());
+        assert_eq!(long["deaths"], df["deaths"]);
+
+        let flat = long.stack().unwrap();
+        assert_eq!((flat.len(), flat.index().nlevels()), (8, 3));
+        assert_eq!(flat["0"].data_type(), &DataType::Double);
+        assert_eq!(flat.unstack(2).unwrap().columns(), vec!["0_births", "0_deaths"]);
+
+        let reset = wide.reset_index().unwrap();
+        assert_eq!(reset.columns()[0], "canton");
+        assert!(reset.column_index().is_none());
+        assert!(wide.column_index().is_some());
+        assert!(matches!(reset.stack(), Err(RaccoonError::TypeMismatch { .. })));
+        let repeated = df.reset_index().unwrap().set_multi_index(&["year", "year"]).unwrap();
+        assert!(matches!(repeated.unstack(0), Err(RaccoonError::InvalidArgument(_))));
+        assert!(matches!(df.xs("ZH", 0).unwrap().unstack(0), Err(RaccoonError::InvalidArgument(_))));
+        let unnamed = df.with_index(Index::from(MultiIndex::new(vec![Index::range(4), Index::range(4)]).unwrap()));
+        assert_eq!(unnamed.unwrap().reset_index().unwrap().columns(), vec!["level_0", "level_1", "births", "deaths"]);
+
+        // labels joined into the same name are told apart
+        let clashing = DataFrame::from_series(vec![
+            Series::from_vector("key".to_owned(), vec!["c", "b_c"]),
+            Series::from_vector("a".to_owned(), vec![1, 2]),
+            Series::from_vector("a_b".to_owned(), vec![3, 4]),
+        ]).unwrap().set_multi_index(&["key", "key"]).unwrap();
+        let wide = clashing.unstack(1).unwrap();
+        assert_eq!(wide.columns(), vec!["a_b_c", "a_c", "a_b_b_c", "a_b_c.1"]);
+        assert_eq!(wide["a_b_c.1"], vec![DataEntry::Integer(3), DataEntry::NA]);
+        assert_eq!(wide.stack().unwrap().columns(), vec!["a", "a_b"]);
+    }
+
+    #[test]
+    fn missing_values() {
+        let df = DataFrame::from_series(vec![
+            Series::from_vector("a".to_owned(), vec![
+                DataEntry::Double(1.0), DataEntry::Double(f64::NAN), DataEntry::NA, DataEntry::Double(4.0),
+            ]),
+            Series::from_vector("b".to_owned(), vec![
+                DataEntry::from('x'), DataEntry::from('y'), DataEntry::NA, DataEntry::NA,
+            ]),
+        ]).unwrap();
+        assert_eq!(df.dropna(DropHow::Any, &[]).unwrap()["a"], vec![1.0]);
+        assert_eq!(df.dropna(DropHow::All, &[]).unwrap()["a"].len(), 3);
+        assert_eq!(df.dropna(DropHow::Any, &["a"]).unwrap()["b"].get(1).unwrap(), DataEntry::NA);
+        assert_eq!(df.dropna(DropHow::All, &["b"]).unwrap().len(), 2);
+        let result = df.dropna(DropHow::Any, &["c"]);
+        assert!(matches!(result, Err(RaccoonError::ColumnNotFound(ref name)) if name == "c"));
+        let labels = DataFrame::from_series(vec![Series::from_vector("a".to_owned(), vec![1, 2])]).unwrap();
+        let labels = labels.set_index("a").unwrap();
+        assert_eq!(labels.dropna(DropHow::All, &[]).unwrap(), labels);
+
+        let mut fills = HashMap::new();
+        fills.insert("b", Fill::Value(DataEntry::from('z')));
+        let filled = df.fillna(&fills).unwrap();
+        assert_eq!(filled["b"], vec!['x', 'y', 'z', 'z']);
+        assert_eq!(filled["a"].get(2).unwrap(), DataEntry::NA);
+
+        fills.insert("a", Fill::Value(DataEntry::from("zero")));
+        assert!(matches!(df.fillna(&fills), Err(RaccoonError::TypeMismatch { expected: DataType::Double, .. })));
+        fills.clear();
+        fills.insert("c", Fill::Forward);
+        assert!(matches!(df.fillna(&fills), Err(RaccoonError::ColumnNotFound(_))));
+    }
+}
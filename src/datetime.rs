@@ -0,0 +1,799 @@
+//! Dates, date times and durations stored in a [`DataEntry`].
+//!
+//! [`DataEntry`]: ../entry/enum.DataEntry.html
+//!
+//! Three data types hold temporal values, built on the types of the [`chrono`] crate, which is re-exported as
+//! `raccoon::chrono`:
+//! - `DataEntry::Date` holds a calendar date as a `NaiveDate`.
+//! - `DataEntry::DateTime` holds a date and a time of day as a `NaiveDateTime`, and an optional offset from UTC.
+//!   Without an offset, the date time is naive and does not refer to a specific instant. With an offset, the date and
+//!   time are those of the local time at that offset. Date times with an offset are compared by the instant they
+//!   refer to, and cannot be compared with naive date times.
+//! - `DataEntry::Duration` holds a signed `Duration` with nanosecond precision.
+//!
+//! [`chrono`]: https://docs.rs/chrono
+//!
+//! # Conversions
+//! Temporal entries are converted to and from text using ISO 8601: `2021-03-14` for dates, `2021-03-14T15:09:26.5`
+//! for naive date times, `2021-03-14T15:09:26+01:00` for date times with an offset, and `P1DT2H30M` for durations.
+//! When parsing, a space may separate the date from the time, the seconds may be omitted, and a date alone is parsed
+//! as midnight. Other formats can be parsed using [`DataEntry::parse_with_format`], which takes a `chrono` format
+//! string.
+//!
+//! [`DataEntry::parse_with_format`]: ../entry/enum.DataEntry.html#method.parse_with_format
+//!
+//! Temporal entries are converted to and from a `DataEntry::Long` relative to the Unix epoch, 1970-01-01 00:00 UTC:
+//! dates as a number of days, date times as a number of seconds, and durations as a number of seconds. Naive date
+//! times are interpreted as UTC, and date times created from a number of seconds have an offset of zero.
+//!
+//! # Arithmetic
+//! - `DateTime - DateTime` and `Date - Date` give a `Duration`.
+//! - `DateTime ± Duration` gives a `DateTime` with the same offset, and `Date ± Duration` gives a naive `DateTime`.
+//! - `Duration ± Duration` gives a `Duration`, and durations can be multiplied and divided by integers.
+//! - `Duration / Duration` gives their ratio as a `Double`.
+//!
+//! # Accessor
+//! [`Series::dt`] returns a [`DatetimeAccessor`], which applies an operation to every date or date time of a series,
+//! such as extracting components, rounding to a frequency, formatting, or converting between offsets. Missing entries
+//! stay missing.
+//!
+//! [`Series::dt`]: ../series/struct.Series.html#method.dt
+//! [`DatetimeAccessor`]: ./struct.DatetimeAccessor.html
+//!
+//! # Examples
+//! ```
+//! use raccoon::{DataEntry, DataType};
+//! use raccoon::chrono::Duration;
+//!
+//! let start = DataEntry::from("2021-03-14T08:00:00+01:00").convert_to(&DataType::DateTime);
+//! let end = DataEntry::from("2021-03-14 10:30").convert_to(&DataType::DateTime);
+//! assert_eq!(end.data_type(), DataType::DateTime);
+//!
+//! // naive date times cannot be mixed with date times having an offset
+//! assert!(end.clone().checked_sub(start.clone()).is_err());
+//!
+//! let end = DataEntry::from("2021-03-14T10:30:00Z").convert_to(&DataType::DateTime);
+//! let elapsed = end.clone() - start.clone();
+//! assert_eq!(elapsed, DataEntry::Duration(Duration::minutes(210)));
+//! assert_eq!(elapsed.convert_to(&DataType::Text), DataEntry::from("PT3H30M"));
+//! assert_eq!(start + elapsed, end);
+//!
+//! let date = DataEntry::from("1970-01-11").convert_to(&DataType::Date);
+//! assert_eq!(date.convert_to(&DataType::Long), DataEntry::Long(10));
+//! ```
+
+use chrono::{DateTime, Datelike, Duration, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use chrono::format::{Item, StrftimeItems};
+
+use entry::{DataEntry, DataType};
+use error::{RaccoonResult, RaccoonError};
+use series::Series;
+
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt::Write;
+
+/// The number of nanoseconds in a second.
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+
+/// The formats tried when parsing a naive date time, after RFC 3339.
+const DATETIME_FORMATS: [&str; 4] = [
+    "%Y-%m-%dT%H:%M:%S%.f",
+    "%Y-%m-%d %H:%M:%S%.f",
+    "%Y-%m-%dT%H:%M",
+    "%Y-%m-%d %H:%M",
+];
+
+/// The formats tried when parsing a date time with an offset not accepted by RFC 3339.
+const OFFSET_FORMATS: [&str; 2] = ["%Y-%m-%dT%H:%M:%S%.f%z", "%Y-%m-%d %H:%M:%S%.f%z"];
+
+/// Vectorised operations on a series of dates or date times, returned by [`Series::dt`].
+///
+/// Dates are treated as date times at midnight without offset. Components are those of the local time, that is of the
+/// date and time as stored, for date times with and without offset alike. Every method returns a new series named
+/// after the original one, in which missing entries stay missing.
+///
+/// [`Series::dt`]: ../series/struct.Series.html#method.dt
+///
+/// # Example
+/// ```
+/// use raccoon::{DataEntry, DataType, Series};
+/// use raccoon::chrono::Duration;
+///
+/// let mut series = Series::from(vec!["2021-03-14 15:09:26", "2020-12-31 23:59:59", "n/a"]);
+/// series.convert_to(&DataType::DateTime);
+///
+/// let dt = series.dt().unwrap();
+/// assert_eq!(dt.year(), vec![DataEntry::Integer(2021), DataEntry::Integer(2020), DataEntry::NA]);
+/// assert_eq!(dt.quarter(), vec![DataEntry::Integer(1), DataEntry::Integer(4), DataEntry::NA]);
+///
+/// let floored = dt.floor(Duration::hours(1)).unwrap();
+/// assert_eq!(floored.get(0).unwrap().convert_to(&DataType::Text), DataEntry::from("2021-03-14T15:00:00"));
+///
+/// let formatted = dt.strftime("%d.%m.%Y").unwrap();
+/// assert_eq!(formatted, vec![DataEntry::from("14.03.2021"), DataEntry::from("31.12.2020"), DataEntry::NA]);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct DatetimeAccessor<'a> {
+    series: &'a Series,
+}
+
+impl<'a> DatetimeAccessor<'a> {
+    /// Constructs a new accessor on a series of dates or date times.
+    ///
+    /// # Errors
+    /// `RaccoonError::TypeMismatch` if the series is neither of `DataType::Date`, `DataType::DateTime`, nor
+    /// `DataType::NA`.
+    pub(crate) fn new(series: &'a Series) -> RaccoonResult<DatetimeAccessor<'a>> {
+        match *series.data_type() {
+            DataType::Date | DataType::DateTime | DataType::NA  => Ok(DatetimeAccessor { series }),
+            ref data_type                                       => Err(RaccoonError::TypeMismatch {
+                expected: DataType::DateTime,
+                found: data_type.clone(),
+            }),
+        }
+    }
+
+    /// Returns the years as `DataType::Integer`.
+    pub fn year(&self) -> Series {
+        self.component(|datetime| datetime.year())
+    }
+
+    /// Returns the months, from 1 for January to 12 for December, as `DataType::Integer`.
+    pub fn month(&self) -> Series {
+        self.component(|datetime| datetime.month() as i32)
+    }
+
+    /// Returns the days of the month, starting at 1, as `DataType::Integer`.
+    pub fn day(&self) -> Series {
+        self.component(|datetime| datetime.day() as i32)
+    }
+
+    /// Returns the hours, from 0 to 23, as `DataType::Integer`.
+    pub fn hour(&self) -> Series {
+        self.component(|datetime| datetime.hour() as i32)
+    }
+
+    /// Returns the minutes, from 0 to 59, as `DataType::Integer`.
+    pub fn minute(&self) -> Series {
+        self.component(|datetime| datetime.minute() as i32)
+    }
+
+    /// Returns the seconds, from 0 to 59, as `DataType::Integer`.
+    pub fn second(&self) -> Series {
+        self.component(|datetime| datetime.second() as i32)
+    }
+
+    /// Returns the days of the week, from 0 for Monday to 6 for Sunday, as `DataType::Integer`.
+    pub fn weekday(&self) -> Series {
+        self.component(|datetime| datetime.weekday().num_days_from_monday() as i32)
+    }
+
+    /// Returns the days of the year, starting at 1 on January 1st, as `DataType::Integer`.
+    pub fn dayofyear(&self) -> Series {
+        self.component(|datetime| datetime.ordinal() as i32)
+    }
+
+    /// Returns the quarters of the year, from 1 to 4, as `DataType::Integer`.
+    pub fn quarter(&self) -> Series {
+        self.component(|datetime| (datetime.month0() / 3 + 1) as i32)
+    }
+
+    /// Rounds the date times down to a multiple of `freq` since the Unix epoch, keeping their offset. The local time is
+    /// rounded, hence `Duration::days(1)` rounds down to midnight, whatever the offset. Returns a series of
+    /// `DataType::DateTime`.
+    ///
+    /// # Errors
+    /// `RaccoonError::InvalidArgument` if `freq` is not positive.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataEntry, DataType, Series};
+    /// # use raccoon::chrono::Duration;
+    /// let mut series = Series::from(vec!["2021-03-14T15:09:26+01:00"]);
+    /// series.convert_to(&DataType::DateTime);
+    ///
+    /// let floored = series.dt().unwrap().floor(Duration::minutes(15)).unwrap();
+    /// assert_eq!(floored.get(0).unwrap().convert_to(&DataType::Text), DataEntry::from("2021-03-14T15:00:00+01:00"));
+    /// let ceiled = series.dt().unwrap().ceil(Duration::minutes(15)).unwrap();
+    /// assert_eq!(ceiled.get(0).unwrap().convert_to(&DataType::Text), DataEntry::from("2021-03-14T15:15:00+01:00"));
+    /// ```
+    pub fn floor(&self, freq: Duration) -> RaccoonResult<Series> {
+        self.round(freq, false)
+    }
+
+    /// Rounds the date times up to a multiple of `freq` since the Unix epoch, keeping their offset. Date times that
+    /// are already a multiple of `freq` are kept. See [`floor`].
+    ///
+    /// [`floor`]: #method.floor
+    ///
+    /// # Errors
+    /// `RaccoonError::InvalidArgument` if `freq` is not positive.
+    pub fn ceil(&self, freq: Duration) -> RaccoonResult<Series> {
+        self.round(freq, true)
+    }
+
+    /// Formats the dates or date times into texts using a format string, see [`chrono::format::strftime`] for the
+    /// supported specifiers. Entries that cannot be formatted, such as naive date times with a format containing an
+    /// offset, become `DataEntry::NA`.
+    ///
+    /// [`chrono::format::strftime`]: https://docs.rs/chrono/latest/chrono/format/strftime/index.html
+    ///
+    /// # Errors
+    /// `RaccoonError::InvalidArgument` if the format string is invalid.
+    pub fn strftime(&self, format: &str) -> RaccoonResult<Series> {
+        let items: Vec<Item> = StrftimeItems::new(format).collect();
+        if items.contains(&Item::Error) {
+            return Err(RaccoonError::InvalidArgument(format!("invalid format string {:?}", format)));
+        }
+        let entries = self.series.iter().map(|entry| {
+            let mut text = String::new();
+            let written = match local(&entry) {
+                Some((datetime, None))          => write!(text, "{}", datetime.format_with_items(items.iter())),
+                Some((datetime, Some(offset)))  => match datetime.and_local_timezone(offset).single() {
+                    Some(datetime)  => write!(text, "{}", datetime.format_with_items(items.iter())),
+                    None            => return DataEntry::NA,
+                },
+                None                            => return DataEntry::NA,
+            };
+            match written {
+                Ok(())  => DataEntry::Text(text),
+                Err(_)  => DataEntry::NA,
+            }
+        });
+        Ok(self.series.with_entries(DataType::Text, entries))
+    }
+
+    /// Sets the offset of naive dates and date times, keeping their local time. Returns a series of
+    /// `DataType::DateTime`.
+    ///
+    /// # Errors
+    /// `RaccoonError::UnsupportedOperation` if the series contains date times that already have an offset.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataEntry, DataType, Series};
+    /// # use raccoon::chrono::FixedOffset;
+    /// let mut series = Series::from(vec!["2021-03-14T15:09:26"]);
+    /// series.convert_to(&DataType::DateTime);
+    ///
+    /// let localized = series.dt().unwrap().tz_localize(FixedOffset::east_opt(3600).unwrap()).unwrap();
+    /// assert_eq!(localized.get(0).unwrap().convert_to(&DataType::Text), DataEntry::from("2021-03-14T15:09:26+01:00"));
+    ///
+    /// let converted = localized.dt().unwrap().tz_convert(FixedOffset::west_opt(5 * 3600).unwrap()).unwrap();
+    /// assert_eq!(converted.get(0).unwrap().convert_to(&DataType::Text), DataEntry::from("2021-03-14T09:09:26-05:00"));
+    /// assert_eq!(converted, localized);
+    /// ```
+    pub fn tz_localize(&self, offset: FixedOffset) -> RaccoonResult<Series> {
+        let mut entries = Vec::with_capacity(self.series.len());
+        for entry in self.series.iter() {
+            entries.push(match local(&entry) {
+                Some((datetime, None))  => DataEntry::DateTime(datetime, Some(offset)),
+                Some((_, Some(_)))      => {
+                    let message = "tz_localize of a DateTime with an offset, use tz_convert instead";
+                    return Err(RaccoonError::UnsupportedOperation(message.to_owned()));
+                },
+                None                    => DataEntry::NA,
+            });
+        }
+        Ok(self.series.with_entries(DataType::DateTime, entries))
+    }
+
+    /// Converts date times with an offset to another offset, keeping the instant they refer to. Returns a series of
+    /// `DataType::DateTime`.
+    ///
+    /// # Errors
+    /// `RaccoonError::UnsupportedOperation` if the series contains naive dates or date times, whose instant is
+    /// unknown. Use [`tz_localize`] to set their offset first.
+    ///
+    /// [`tz_localize`]: #method.tz_localize
+    pub fn tz_convert(&self, offset: FixedOffset) -> RaccoonResult<Series> {
+        let mut entries = Vec::with_capacity(self.series.len());
+        for entry in self.series.iter() {
+            entries.push(match local(&entry) {
+                Some((datetime, Some(current))) => match datetime.and_local_timezone(current).single() {
+                    Some(datetime)  => DataEntry::from(datetime.with_timezone(&offset)),
+                    None            => DataEntry::NA,
+                },
+                Some((_, None))                 => {
+                    let message = "tz_convert of a naive DateTime, use tz_localize instead";
+                    return Err(RaccoonError::UnsupportedOperation(message.to_owned()));
+                },
+                None                            => DataEntry::NA,
+            });
+        }
+        Ok(self.series.with_entries(DataType::DateTime, entries))
+    }
+
+    /// Applies `component` to every local date time, returning a series of `DataType::Integer`.
+    fn component<F>(&self, component: F) -> Series where F: Fn(&NaiveDateTime) -> i32 {
+        let entries = self.series.iter().map(|entry| match local(&entry) {
+            Some((datetime, _)) => DataEntry::Integer(component(&datetime)),
+            None                => DataEntry::NA,
+        });
+        self.series.with_entries(DataType::Integer, entries)
+    }
+
+    /// Rounds every local date time down, or up if `up` is set, to a multiple of `freq` since the Unix epoch.
+    fn round(&self, freq: Duration, up: bool) -> RaccoonResult<Series> {
+        let freq = to_nanos(&freq);
+        if freq <= 0 {
+            return Err(RaccoonError::InvalidArgument(format!("frequency of {} nanoseconds is not positive", freq)));
+        }
+        let entries = self.series.iter().map(|entry| {
+            let (datetime, offset) = match local(&entry) {
+                Some(local) => local,
+                None        => return DataEntry::NA,
+            };
+            let nanos = to_nanos(&(datetime - epoch()));
+            let remainder = nanos.rem_euclid(freq);
+            let rounded = if up && remainder > 0 { nanos - remainder + freq } else { nanos - remainder };
+            from_nanos(rounded)
+                .and_then(|duration| epoch().checked_add_signed(duration))
+                .map_or(DataEntry::NA, |datetime| DataEntry::DateTime(datetime, offset))
+        });
+        Ok(self.series.with_entries(DataType::DateTime, entries))
+    }
+}
+
+/// Returns the instant referred to by a date time with an offset, as a number of seconds since the Unix epoch and a
+/// number of nanoseconds. Naive date times are interpreted as UTC.
+pub(crate) fn instant(datetime: &NaiveDateTime, offset: &Option<FixedOffset>) -> (i64, u32) {
+    let utc = datetime.and_utc();
+    let offset = offset.map_or(0, |offset| offset.local_minus_utc() as i64);
+    (utc.timestamp() - offset, utc.timestamp_subsec_nanos())
+}
+
+/// Compares two date times. Date times with an offset are compared by instant, and cannot be compared with naive date
+/// times.
+pub(crate) fn compare(datetime1: &NaiveDateTime, offset1: &Option<FixedOffset>, datetime2: &NaiveDateTime,
+                      offset2: &Option<FixedOffset>) -> Option<Ordering> {
+    match (offset1, offset2) {
+        (None, None)        => Some(datetime1.cmp(datetime2)),
+        (Some(_), Some(_))  => Some(instant(datetime1, offset1).cmp(&instant(datetime2, offset2))),
+        _                   => None,
+    }
+}
+
+/// Converts a temporal entry into another data type.
+pub(crate) fn convert(entry: &DataEntry, data_type: &DataType) -> DataEntry {
+    match (entry, data_type) {
+        (&DataEntry::Date(date), &DataType::Date)                       => DataEntry::Date(date),
+        (&DataEntry::Date(date), &DataType::DateTime)                   => {
+            DataEntry::DateTime(date.and_time(NaiveTime::MIN), None)
+        },
+        (&DataEntry::Date(date), &DataType::Long)                       => {
+            DataEntry::Long(date.signed_duration_since(epoch().date()).num_days())
+        },
+        (&DataEntry::Date(date), &DataType::Text)                       => {
+            DataEntry::Text(date.format("%Y-%m-%d").to_string())
+        },
+        (&DataEntry::DateTime(datetime, _), &DataType::Date)            => DataEntry::Date(datetime.date()),
+        (&DataEntry::DateTime(datetime, offset), &DataType::DateTime)   => DataEntry::DateTime(datetime, offset),
+        (&DataEntry::DateTime(datetime, offset), &DataType::Long)       => {
+            DataEntry::Long(instant(&datetime, &offset).0)
+        },
+        (&DataEntry::DateTime(datetime, offset), &DataType::Text)       => {
+            DataEntry::Text(format_datetime(&datetime, &offset))
+        },
+        (&DataEntry::Duration(duration), &DataType::Duration)           => DataEntry::Duration(duration),
+        (&DataEntry::Duration(duration), &DataType::Long)               => DataEntry::Long(duration.num_seconds()),
+        (&DataEntry::Duration(duration), &DataType::Text)               => {
+            DataEntry::Text(format_duration(&duration))
+        },
+        _                                                               => DataEntry::NA,
+    }
+}
+
+/// Converts a number relative to the Unix epoch into an entry of a temporal data type: a number of days for dates, and
+/// a number of seconds for date times and durations. Date times are created with an offset of zero.
+pub(crate) fn from_epoch(value: i64, data_type: &DataType) -> DataEntry {
+    let entry = match *data_type {
+        DataType::Date      => {
+            Duration::try_days(value).and_then(|days| epoch().date().checked_add_signed(days)).map(DataEntry::Date)
+        },
+        DataType::DateTime  => {
+            DateTime::from_timestamp(value, 0).map(|datetime| DataEntry::DateTime(datetime.naive_utc(), utc()))
+        },
+        DataType::Duration  => Duration::try_seconds(value).map(DataEntry::Duration),
+        _                   => None,
+    };
+    entry.unwrap_or(DataEntry::NA)
+}
+
+/// Returns the Unix epoch.
+pub(crate) fn epoch() -> NaiveDateTime {
+    NaiveDateTime::default()
+}
+
+/// Returns the offset of UTC.
+fn utc() -> Option<FixedOffset> {
+    FixedOffset::east_opt(0)
+}
+
+/// Returns the local date time and the offset of a date or date time entry. Dates are at midnight, without offset.
+fn local(entry: &DataEntry) -> Option<(NaiveDateTime, Option<FixedOffset>)> {
+    match *entry {
+        DataEntry::Date(date)                   => Some((date.and_time(NaiveTime::MIN), None)),
+        DataEntry::DateTime(datetime, offset)   => Some((datetime, offset)),
+        _                                       => None,
+    }
+}
+
+/// Parses an ISO 8601 date.
+pub(crate) fn parse_date(text: &str) -> DataEntry {
+    match NaiveDate::parse_from_str(text, "%Y-%m-%d") {
+        Ok(date)    => DataEntry::Date(date),
+        Err(_)      => DataEntry::NA,
+    }
+}
+
+/// Parses an ISO 8601 date time, with or without offset. A date alone is parsed as midnight.
+pub(crate) fn parse_datetime(text: &str) -> DataEntry {
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(text) {
+        return DataEntry::DateTime(datetime.naive_local(), Some(*datetime.offset()));
+    }
+    if let Some(datetime) = OFFSET_FORMATS.iter().find_map(|format| DateTime::parse_from_str(text, format).ok()) {
+        return DataEntry::DateTime(datetime.naive_local(), Some(*datetime.offset()));
+    }
+    let naive = DATETIME_FORMATS.iter().find_map(|format| NaiveDateTime::parse_from_str(text, format).ok());
+    if let Some(datetime) = naive {
+        return DataEntry::DateTime(datetime, None);
+    }
+    match NaiveDate::parse_from_str(text, "%Y-%m-%d") {
+        Ok(date)    => DataEntry::DateTime(date.and_time(NaiveTime::MIN), None),
+        Err(_)      => DataEntry::NA,
+    }
+}
+
+/// Parses text into an entry of a temporal `data_type` using a `chrono` format string.
+pub(crate) fn parse_with_format(text: &str, data_type: &DataType, format: &str) -> DataEntry {
+    match *data_type {
+        DataType::Date      => match NaiveDate::parse_from_str(text, format) {
+            Ok(date)    => DataEntry::Date(date),
+            Err(_)      => DataEntry::NA,
+        },
+        DataType::DateTime  => {
+            if let Ok(datetime) = DateTime::parse_from_str(text, format) {
+                return DataEntry::DateTime(datetime.naive_local(), Some(*datetime.offset()));
+            }
+            if let Ok(datetime) = NaiveDateTime::parse_from_str(text, format) {
+                return DataEntry::DateTime(datetime, None);
+            }
+            match NaiveDate::parse_from_str(text, format) {
+                Ok(date)    => DataEntry::DateTime(date.and_time(NaiveTime::MIN), None),
+                Err(_)      => DataEntry::NA,
+            }
+        },
+        _                   => DataEntry::NA,
+    }
+}
+
+/// Formats a date time in ISO 8601, including its offset if it has one.
+pub(crate) fn format_datetime(datetime: &NaiveDateTime, offset: &Option<FixedOffset>) -> String {
+    let text = datetime.format("%Y-%m-%dT%H:%M:%S%.f").to_string();
+    match *offset {
+        Some(offset)    => text + &offset.to_string(),
+        None            => text,
+    }
+}
+
+/// Parses an ISO 8601 duration made of weeks, days, hours, minutes and seconds, such as `P1DT2H30M` or `-PT0.5S`.
+/// Years and months are not supported, as their length varies.
+pub(crate) fn parse_duration(text: &str) -> DataEntry {
+    let (negative, text) = match text.strip_prefix('-') {
+        Some(text)  => (true, text),
+        None        => (false, text.strip_prefix('+').unwrap_or(text)),
+    };
+    let text = match text.strip_prefix('P') {
+        Some(text) if !text.is_empty() && !text.ends_with('T') => text,
+        _                                                       => return DataEntry::NA,
+    };
+
+    let mut nanos: i128 = 0;
+    let mut number = String::new();
+    let mut in_time = false;
+    for ch in text.chars() {
+        let unit: i128 = match (ch, in_time) {
+            ('T', false)                                    => {
+                if !number.is_empty() {
+                    return DataEntry::NA;
+                }
+                in_time = true;
+                continue;
+            },
+            (ch, _) if ch.is_ascii_digit() || ch == '.'     => {
+                number.push(ch);
+                continue;
+            },
+            ('W', false)                                    => 7 * 24 * 3600,
+            ('D', false)                                    => 24 * 3600,
+            ('H', true)                                     => 3600,
+            ('M', true)                                     => 60,
+            ('S', true)                                     => 1,
+            _                                               => return DataEntry::NA,
+        };
+        let value = match parse_seconds(&number, ch == 'S') {
+            Some(value) => value,
+            None        => return DataEntry::NA,
+        };
+        nanos += value * unit;
+        number.clear();
+    }
+    if !number.is_empty() {
+        return DataEntry::NA;
+    }
+    match from_nanos(if negative { -nanos } else { nanos }) {
+        Some(duration)  => DataEntry::Duration(duration),
+        None            => DataEntry::NA,
+    }
+}
+
+/// Parses a number of a duration component into nanoseconds of that unit. Only seconds may have a fraction.
+fn parse_seconds(number: &str, fraction: bool) -> Option<i128> {
+    let (whole, decimals) = match number.find('.') {
+        Some(idx) if fraction   => (&number[..idx], &number[idx + 1..]),
+        Some(_)                 => return None,
+        None                    => (number, ""),
+    };
+    if whole.is_empty() || decimals.len() > 9 || (number.contains('.') && decimals.is_empty()) {
+        return None;
+    }
+    let whole: i128 = whole.parse().ok()?;
+    let decimals: i128 = if decimals.is_empty() { 0 } else { format!("{:0<9}", decimals).parse().ok()? };
+    Some(whole.checked_mul(NANOS_PER_SECOND)? + decimals)
+}
+
+/// Formats a duration in ISO 8601, using days, hours, minutes and seconds.
+pub(crate) fn format_duration(duration: &Duration) -> String {
+    let nanos = to_nanos(duration);
+    let mut text = String::from(if nanos < 0 { "-P" } else { "P" });
+    let nanos = nanos.abs();
+    let (seconds, fraction) = (nanos / NANOS_PER_SECOND, nanos % NANOS_PER_SECOND);
+    let (days, hours) = (seconds / 86_400, seconds % 86_400 / 3600);
+    let (minutes, seconds) = (seconds % 3600 / 60, seconds % 60);
+    if days > 0 {
+        let _ = write!(text, "{}D", days);
+    }
+    if hours > 0 || minutes > 0 || seconds > 0 || fraction > 0 || days == 0 {
+        text.push('T');
+    }
+    if hours > 0 {
+        let _ = write!(text, "{}H", hours);
+    }
+    if minutes > 0 {
+        let _ = write!(text, "{}M", minutes);
+    }
+    if fraction > 0 {
+        let fraction = format!("{:09}", fraction);
+        let _ = write!(text, "{}.{}S", seconds, fraction.trim_end_matches('0'));
+    } else if seconds > 0 || text.ends_with('T') {
+        let _ = write!(text, "{}S", seconds);
+    }
+    text
+}
+
+/// Returns the number of nanoseconds of a duration.
+pub(crate) fn to_nanos(duration: &Duration) -> i128 {
+    duration.num_seconds() as i128 * NANOS_PER_SECOND + duration.subsec_nanos() as i128
+}
+
+/// Builds a duration from a number of nanoseconds, or `None` if it is out of the range of durations.
+pub(crate) fn from_nanos(nanos: i128) -> Option<Duration> {
+    let seconds = i64::try_from(nanos.div_euclid(NANOS_PER_SECOND)).ok()?;
+    let subsec = nanos.rem_euclid(NANOS_PER_SECOND) as i64;
+    Duration::try_seconds(seconds)?.checked_add(&Duration::nanoseconds(subsec))
+}
+
+/// Adds two entries, one of which is temporal.
+pub(crate) fn add(lhs: DataEntry, rhs: DataEntry) -> RaccoonResult<DataEntry> {
+    let result = match (lhs, rhs) {
+        (DataEntry::NA, _) | (_, DataEntry::NA)                             => DataEntry::NA,
+        (DataEntry::DateTime(datetime, offset), DataEntry::Duration(duration)) |
+        (DataEntry::Duration(duration), DataEntry::DateTime(datetime, offset))  => {
+            DataEntry::DateTime(overflow(datetime.checked_add_signed(duration))?, offset)
+        },
+        (DataEntry::Date(date), DataEntry::Duration(duration)) |
+        (DataEntry::Duration(duration), DataEntry::Date(date))              => {
+            DataEntry::DateTime(overflow(date.and_time(NaiveTime::MIN).checked_add_signed(duration))?, None)
+        },
+        (DataEntry::Duration(duration1), DataEntry::Duration(duration2))    => {
+            DataEntry::Duration(overflow(duration1.checked_add(&duration2))?)
+        },
+        (lhs, rhs)                                                          => return Err(unsupported(&lhs, "+", &rhs)),
+    };
+    Ok(result)
+}
+
+/// Subtracts two entries, one of which is temporal.
+pub(crate) fn sub(lhs: DataEntry, rhs: DataEntry) -> RaccoonResult<DataEntry> {
+    let result = match (lhs, rhs) {
+        (DataEntry::NA, _) | (_, DataEntry::NA)                                     => DataEntry::NA,
+        (DataEntry::DateTime(datetime, offset), DataEntry::Duration(duration))      => {
+            DataEntry::DateTime(overflow(datetime.checked_sub_signed(duration))?, offset)
+        },
+        (DataEntry::Date(date), DataEntry::Duration(duration))                      => {
+            DataEntry::DateTime(overflow(date.and_time(NaiveTime::MIN).checked_sub_signed(duration))?, None)
+        },
+        (DataEntry::DateTime(datetime1, offset1), DataEntry::DateTime(datetime2, offset2))
+            if offset1.is_some() == offset2.is_some()                               => {
+            let (seconds1, nanos1) = instant(&datetime1, &offset1);
+            let (seconds2, nanos2) = instant(&datetime2, &offset2);
+            let nanos = Duration::nanoseconds(nanos1 as i64 - nanos2 as i64);
+            DataEntry::Duration(Duration::seconds(seconds1 - seconds2) + nanos)
+        },
+        (DataEntry::Date(date1), DataEntry::Date(date2))                            => {
+            DataEntry::Duration(date1 - date2)
+        },
+        (DataEntry::Duration(duration1), DataEntry::Duration(duration2))            => {
+            DataEntry::Duration(overflow(duration1.checked_sub(&duration2))?)
+        },
+        (lhs, rhs)                                                                  => {
+            return Err(unsupported(&lhs, "-", &rhs))
+        },
+    };
+    Ok(result)
+}
+
+/// Multiplies two entries, one of which is temporal. Only durations can be multiplied, by integers.
+pub(crate) fn mul(lhs: DataEntry, rhs: DataEntry) -> RaccoonResult<DataEntry> {
+    let result = match (lhs, rhs) {
+        (DataEntry::NA, _) | (_, DataEntry::NA)         => DataEntry::NA,
+        (DataEntry::Duration(duration), factor) |
+        (factor, DataEntry::Duration(duration)) if integer(&factor).is_some()  => {
+            let factor = integer(&factor).unwrap_or(0);
+            let nanos = overflow(to_nanos(&duration).checked_mul(factor))?;
+            DataEntry::Duration(overflow(from_nanos(nanos))?)
+        },
+        (lhs, rhs)                                      => return Err(unsupported(&lhs, "*", &rhs)),
+    };
+    Ok(result)
+}
+
+/// Divides two entries, one of which is temporal. Durations can be divided by integers, giving a duration rounded
+/// towards zero to the nanosecond, or by durations, giving their ratio.
+pub(crate) fn div(lhs: DataEntry, rhs: DataEntry) -> RaccoonResult<DataEntry> {
+    let result = match (lhs, rhs) {
+        (DataEntry::NA, _) | (_, DataEntry::NA)                         => DataEntry::NA,
+        (DataEntry::Duration(duration1), DataEntry::Duration(duration2)) => {
+            let divisor = to_nanos(&duration2);
+            if divisor == 0 {
+                return Err(RaccoonError::DivisionByZero);
+            }
+            DataEntry::Double(to_nanos(&duration1) as f64 / divisor as f64)
+        },
+        (DataEntry::Duration(duration), divisor) if integer(&divisor).is_some() => {
+            match integer(&divisor) {
+                Some(0)         => return Err(RaccoonError::DivisionByZero),
+                Some(divisor)   => DataEntry::Duration(overflow(from_nanos(to_nanos(&duration) / divisor))?),
+                None            => DataEntry::NA,
+            }
+        },
+        (lhs, rhs)                                                      => return Err(unsupported(&lhs, "/", &rhs)),
+    };
+    Ok(result)
+}
+
+/// Returns the value of an integer entry.
+fn integer(entry: &DataEntry) -> Option<i128> {
+    match *entry {
+        DataEntry::Integer(int)     => Some(int as i128),
+        DataEntry::UInteger(int)    => Some(int as i128),
+        DataEntry::Long(int)        => Some(int as i128),
+        DataEntry::ULong(int)       => Some(int as i128),
+        _                           => None,
+    }
+}
+
+/// Reports an out of range temporal value as an overflow.
+fn overflow<T>(value: Option<T>) -> RaccoonResult<T> {
+    value.ok_or(RaccoonError::ArithmeticOverflow)
+}
+
+/// Builds the error reporting an unsupported operation between two entries.
+fn unsupported(lhs: &DataEntry, operator: &str, rhs: &DataEntry) -> RaccoonError {
+    RaccoonError::UnsupportedOperation(format!("{:?} {} {:?}", lhs.data_type(), operator, rhs.data_type()))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parsing() {
+        let date = NaiveDate::from_ymd_opt(2021, 3, 14).unwrap();
+        assert_eq!(DataEntry::Date(date), parse_date("2021-03-14"));
+        assert_eq!(DataEntry::NA, parse_date("2021-02-30"));
+
+        let datetime = date.and_hms_milli_opt(15, 9, 26, 500).unwrap();
+        assert_eq!(DataEntry::DateTime(datetime, None), parse_datetime("2021-03-14T15:09:26.5"));
+        assert_eq!(DataEntry::DateTime(datetime, None), parse_datetime("2021-03-14 15:09:26.500"));
+        let midnight = date.and_hms_opt(0, 0, 0).unwrap();
+        assert_eq!(DataEntry::DateTime(midnight, None), parse_datetime("2021-03-14"));
+        let minutes = date.and_hms_opt(15, 9, 0).unwrap();
+        assert_eq!(DataEntry::DateTime(minutes, None), parse_datetime("2021-03-14 15:09"));
+
+        let offset = FixedOffset::east_opt(3600).unwrap();
+        assert_eq!(DataEntry::DateTime(datetime, Some(offset)), parse_datetime("2021-03-14T15:09:26.5+01:00"));
+        assert_eq!(DataEntry::DateTime(datetime, Some(offset)), parse_datetime("2021-03-14 15:09:26.5+0100"));
+        match parse_datetime("2021-03-14T14:09:26.5Z") {
+            DataEntry::DateTime(_, Some(offset))    => assert_eq!(0, offset.local_minus_utc()),
+            entry                                   => panic!("unexpected entry {:?}", entry),
+        }
+        assert_eq!(DataEntry::NA, parse_datetime("14.03.2021"));
+    }
+
+    #[test]
+    fn durations() {
+        let duration = Duration::days(1) + Duration::hours(2) + Duration::minutes(30) + Duration::milliseconds(250);
+        assert_eq!(DataEntry::Duration(duration), parse_duration("P1DT2H30M0.25S"));
+        assert_eq!("P1DT2H30M0.25S", format_duration(&duration));
+        assert_eq!(DataEntry::Duration(-Duration::weeks(2)), parse_duration("-P2W"));
+        assert_eq!("-P14D", format_duration(&-Duration::weeks(2)));
+        assert_eq!("PT0S", format_duration(&Duration::zero()));
+        assert_eq!("PT1M", format_duration(&Duration::seconds(60)));
+        assert_eq!(DataEntry::Duration(Duration::seconds(90)), parse_duration("PT90S"));
+
+        for text in &["P", "PT", "P1Y", "P1M", "PT1.5M", "P1DT", "PT5", "1D", "PT.5S", "PT5.S"] {
+            assert_eq!(DataEntry::NA, parse_duration(text), "{}", text);
+        }
+        assert_eq!(Some(Duration::nanoseconds(-1)), from_nanos(-1));
+        assert_eq!(-1, to_nanos(&Duration::nanoseconds(-1)));
+    }
+
+    #[test]
+    fn custom_formats() {
+        let date = NaiveDate::from_ymd_opt(2021, 3, 14).unwrap();
+        assert_eq!(DataEntry::Date(date), parse_with_format("14.03.2021", &DataType::Date, "%d.%m.%Y"));
+        let datetime = date.and_hms_opt(15, 9, 0).unwrap();
+        let entry = parse_with_format("14/03/2021 15:09", &DataType::DateTime, "%d/%m/%Y %H:%M");
+        assert_eq!(DataEntry::DateTime(datetime, None), entry);
+        let entry = parse_with_format("14/03/2021", &DataType::DateTime, "%d/%m/%Y");
+        assert_eq!(DataEntry::DateTime(date.and_hms_opt(0, 0, 0).unwrap(), None), entry);
+        assert_eq!(DataEntry::NA, parse_with_format("14/03/2021", &DataType::Date, "%d.%m.%Y"));
+    }
+
+    #[test]
+    fn accessor() {
+        let offset = FixedOffset::east_opt(3600).unwrap();
+        let datetime = NaiveDate::from_ymd_opt(2020, 12, 31).unwrap().and_hms_opt(23, 30, 15).unwrap();
+        let series = Series::from(vec![
+            DataEntry::DateTime(datetime, Some(offset)), DataEntry::NA, DataEntry::DateTime(datetime, None),
+        ]);
+        let dt = series.dt().unwrap();
+        assert_eq!(dt.year(), vec![DataEntry::Integer(2020), DataEntry::NA, DataEntry::Integer(2020)]);
+        assert_eq!(dt.hour(), vec![DataEntry::Integer(23), DataEntry::NA, DataEntry::Integer(23)]);
+        assert_eq!(dt.dayofyear().get(0).unwrap(), DataEntry::Integer(366));
+        assert_eq!(dt.weekday().get(0).unwrap(), DataEntry::Integer(3));
+        assert_eq!(dt.quarter().get(2).unwrap(), DataEntry::Integer(4));
+        assert_eq!(dt.second().get(2).unwrap(), DataEntry::Integer(15));
+        assert_eq!(series.name(), dt.month().name());
+
+        let ceiled = dt.ceil(Duration::days(1)).unwrap();
+        let midnight = NaiveDate::from_ymd_opt(2021, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+        assert_eq!(ceiled, vec![
+            DataEntry::DateTime(midnight, Some(offset)), DataEntry::NA, DataEntry::DateTime(midnight, None),
+        ]);
+        assert_eq!(ceiled, ceiled.dt().unwrap().ceil(Duration::hours(6)).unwrap());
+        assert_eq!(ceiled, ceiled.dt().unwrap().floor(Duration::hours(6)).unwrap());
+        assert!(matches!(dt.floor(Duration::zero()), Err(RaccoonError::InvalidArgument(_))));
+
+        let formatted = dt.strftime("%H:%M %z").unwrap();
+        assert_eq!(formatted, vec![DataEntry::from("23:30 +0100"), DataEntry::NA, DataEntry::NA]);
+        assert!(matches!(dt.strftime("%Q"), Err(RaccoonError::InvalidArgument(_))));
+        assert!(matches!(dt.tz_localize(offset), Err(RaccoonError::UnsupportedOperation(_))));
+        assert!(matches!(dt.tz_convert(offset), Err(RaccoonError::UnsupportedOperation(_))));
+
+        let dates = Series::from(vec![DataEntry::Date(datetime.date()), DataEntry::NA]);
+        let localized = dates.dt().unwrap().tz_localize(offset).unwrap();
+        assert_eq!(localized.data_type(), &DataType::DateTime);
+        let converted = localized.dt().unwrap().tz_convert(FixedOffset::east_opt(0).unwrap()).unwrap();
+        assert_eq!(converted.dt().unwrap().hour(), vec![DataEntry::Integer(23), DataEntry::NA]);
+        assert_eq!(converted.dt().unwrap().day(), vec![DataEntry::Integer(30), DataEntry::NA]);
+
+        assert!(Series::from(vec![DataEntry::NA]).dt().is_ok());
+        let integers = Series::from(vec![1, 2]);
+        assert!(matches!(integers.dt(), Err(RaccoonError::TypeMismatch { expected: DataType::DateTime, .. })));
+    }
+}
@@ -18,6 +18,10 @@
 //! also why numerical types smaller than 32 bits are not supported. It is therefore encouraged to use 64 bit numerals
 //! as they provide more precision or larger max/min values.
 //!
+//! This only applies to standalone entries: a [`Series`] does not store `DataEntry`s, but keeps its values in a buffer
+//! typed after its data type. A series of `DataType::Integer` hence uses 4 bytes per entry, plus a bit marking missing
+//! entries.
+//!
 //!
 //! # Examples
 //! Creating a `DataEntry` like any other Rust `enum`:
@@ -38,6 +42,35 @@
 //! assert_eq!(new_entry, DataEntry::Text("true".to_owned()));
 //! ```
 //!
+//! # Equality and Ordering
+//! Entries are compared by value: numerical entries are equal if they hold the same number, whatever their data type,
+//! and are ordered accordingly. Other entries are only equal to, and ordered with, entries of the same data type.
+//! Date times with an offset are equal if they refer to the same instant, see [`raccoon::datetime`].
+//! Entries of different kinds, such as a text and a number, are never equal and cannot be ordered.
+//!
+//! Missing values follow two rules:
+//! - `DataEntry::NA` is equal to itself, so that checking whether an entry is missing with `entry == DataEntry::NA`
+//!   works. It cannot be ordered with any other entry.
+//! - A NaN floating point number follows IEEE 754: it is neither equal to, nor ordered with, any entry, including
+//!   itself.
+//!
+//! [`OrderedEntry`] wraps an entry to provide a total order and a hash, for instance to sort entries or to use them as
+//! keys of a `BTreeMap` or a `HashMap`. In contrast to `DataEntry`, all NaNs are equal when wrapped.
+//!
+//! [`OrderedEntry`]: ./struct.OrderedEntry.html
+//! [`raccoon::datetime`]: ../datetime/index.html
+//! ```
+//! use raccoon::DataEntry;
+//!
+//! assert_eq!(DataEntry::Integer(3), DataEntry::Double(3.0));
+//! assert!(DataEntry::Long(-1) < DataEntry::ULong(1));
+//! assert_ne!(DataEntry::Integer(1), DataEntry::Boolean(true));
+//!
+//! assert_eq!(DataEntry::NA, DataEntry::NA);
+//! assert_ne!(DataEntry::Double(f64::NAN), DataEntry::Double(f64::NAN));
+//! assert_eq!(DataEntry::NA.partial_cmp(&DataEntry::Integer(1)), None);
+//! ```
+//!
 //! Recovering the data type of a series:
 //! ```
 //! use raccoon::{Series, DataType};
@@ -47,14 +80,20 @@
 //! assert_eq!(data_type, &DataType::Character);
 //! ```
 
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, NaiveDateTime, Utc};
+use datetime;
+use error::{RaccoonResult, RaccoonError};
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::hash::{Hash, Hasher};
 use std::ops::{Add, Sub, Div, Mul};
 
 /// A cell-like data entry. Note that `DataEntry::Integer`, `DataEntry::UInteger`, and `DataEntry::Float`, are only
 /// added for convenience. Due to how Rust `enum`s are stored, using a `DataEntry::Integer` does not actually reduce
 /// the memory footprint versus `DataEntry::Long`.
 ///
-/// See `raccoon::entry` for more details on memory efficiency.
-#[derive(Debug, Clone, PartialEq, PartialOrd)]
+/// See `raccoon::entry` for more details on memory efficiency, and on how entries are compared.
+#[derive(Debug, Clone)]
 pub enum DataEntry {
     /// A text entry.
     Text(String),
@@ -74,6 +113,12 @@ pub enum DataEntry {
     Boolean(bool),
     /// A character entry.
     Character(char),
+    /// A calendar date entry.
+    Date(NaiveDate),
+    /// A date and time entry, with an optional offset from UTC. The date and time are the local time at the offset.
+    DateTime(NaiveDateTime, Option<FixedOffset>),
+    /// A signed duration entry.
+    Duration(Duration),
     /// A missing or invalid entry.
     NA
 }
@@ -90,6 +135,9 @@ impl DataEntry {
     /// - `f64`,
     /// - `bool`,
     /// - `char`,
+    /// - `NaiveDate`,
+    /// - `NaiveDateTime`,
+    /// - `Duration`,
     /// - `na`.
     ///
     /// # Examples
@@ -122,6 +170,9 @@ impl DataEntry {
             DataEntry::Double(_)    => "f64",
             DataEntry::Boolean(_)   => "bool",
             DataEntry::Character(_) => "char",
+            DataEntry::Date(_)      => "NaiveDate",
+            DataEntry::DateTime(..) => "NaiveDateTime",
+            DataEntry::Duration(_)  => "Duration",
             DataEntry::NA           => "na"
         }
     }
@@ -148,10 +199,31 @@ impl DataEntry {
             DataEntry::Double(_)    => DataType::Double,
             DataEntry::Boolean(_)   => DataType::Boolean,
             DataEntry::Character(_) => DataType::Character,
+            DataEntry::Date(_)      => DataType::Date,
+            DataEntry::DateTime(..) => DataType::DateTime,
+            DataEntry::Duration(_)  => DataType::Duration,
             DataEntry::NA           => DataType::NA
         }
     }
 
+    /// Checks whether the entry is a missing value, that is `DataEntry::NA` or a NaN floating point number.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::DataEntry;
+    /// assert!(DataEntry::NA.is_missing());
+    /// assert!(DataEntry::Float(f32::NAN).is_missing());
+    /// assert!(!DataEntry::Double(0.0).is_missing());
+    /// ```
+    pub fn is_missing(&self) -> bool {
+        match *self {
+            DataEntry::NA           => true,
+            DataEntry::Float(f)     => f.is_nan(),
+            DataEntry::Double(f)    => f.is_nan(),
+            _                       => false,
+        }
+    }
+
     /// Convert this entry into another data type. Note this does not modify the initial entry itself but returns a new
     /// entry with the desired type.
     ///
@@ -241,6 +313,9 @@ impl DataEntry {
                     DataType::Boolean   => DataEntry::from(int != 0i64),
                     DataType::Character => DataEntry::NA,
                     DataType::Text      => DataEntry::from(int.to_string()),
+                    DataType::Date      => datetime::from_epoch(int, data_type),
+                    DataType::DateTime  => datetime::from_epoch(int, data_type),
+                    DataType::Duration  => datetime::from_epoch(int, data_type),
                     _                   => DataEntry::NA,
 
                 }
@@ -320,126 +395,238 @@ impl DataEntry {
 
                 }
             },
-            DataEntry::Text(ref txt)            => {
-                match data_type {
-                    DataType::Integer   => {
-                        match txt.parse::<i32>() {
-                            Ok(int) => DataEntry::from(int),
-                            Err(_)  => DataEntry::NA
-                        }
-                    },
-                    DataType::UInteger  => {
-                        match txt.parse::<u32>() {
-                            Ok(int) => DataEntry::from(int),
-                            Err(_)  => DataEntry::NA
-                        }
-                    },
-                    DataType::Long      => {
-                        match txt.parse::<i64>() {
-                            Ok(int) => DataEntry::from(int),
-                            Err(_)  => DataEntry::NA
-                        }
-                    },
-                    DataType::ULong     => {
-                        match txt.parse::<u64>() {
-                            Ok(int) => DataEntry::from(int),
-                            Err(_)  => DataEntry::NA
-                        }
-                    },
-                    DataType::Float     => {
-                        match txt.parse::<f32>() {
-                            Ok(f)   => DataEntry::from(f),
-                            Err(_)  => DataEntry::NA
-                        }
-                    },
-                    DataType::Double    => {
-                        match txt.parse::<f64>() {
-                            Ok(f)   => DataEntry::from(f),
-                            Err(_)  => DataEntry::NA
-                        }
-                    },
-                    DataType::Boolean   => {
-                        match txt.parse::<bool>() {
-                            Ok(b)   => DataEntry::from(b),
-                            Err(_)  => DataEntry::NA
-                        }
-                    },
-                    DataType::Character => {
-                        match txt.parse::<char>() {
-                            Ok(ch)  => DataEntry::from(ch),
-                            Err(_)  => DataEntry::NA
-                        }
-                    },
-                    DataType::Text      => DataEntry::from(txt),
-                    _                   => DataEntry::NA,
+            DataEntry::Text(ref txt)        => DataEntry::parse(txt, data_type),
+            DataEntry::Date(_) | DataEntry::DateTime(..) | DataEntry::Duration(_)   => {
+                datetime::convert(self, data_type)
+            },
+            _                               => DataEntry::NA
+
+        }
+    }
 
+    /// Parses text into an entry of the given data type. This applies the same rules as converting a
+    /// `DataEntry::Text` using [`convert_to`]: the text is parsed using Rust's `str::parse` for the corresponding
+    /// primitive, and results in `DataEntry::NA` if parsing fails.
+    ///
+    /// [`convert_to`]: ./enum.DataEntry.html#method.convert_to
+    ///
+    /// # Examples
+    /// ```
+    /// # use raccoon::{DataEntry, DataType};
+    /// assert_eq!(DataEntry::parse("-12", &DataType::Long), DataEntry::Long(-12));
+    /// assert_eq!(DataEntry::parse("true", &DataType::Boolean), DataEntry::Boolean(true));
+    ///
+    /// // unsigned types cannot hold negative numbers
+    /// assert_eq!(DataEntry::parse("-12", &DataType::ULong), DataEntry::NA);
+    /// ```
+    pub fn parse(text: &str, data_type: &DataType) -> DataEntry {
+        match data_type {
+            DataType::Integer   => {
+                match text.parse::<i32>() {
+                    Ok(int) => DataEntry::from(int),
+                    Err(_)  => DataEntry::NA
                 }
             },
-            _                               => DataEntry::NA
+            DataType::UInteger  => {
+                match text.parse::<u32>() {
+                    Ok(int) => DataEntry::from(int),
+                    Err(_)  => DataEntry::NA
+                }
+            },
+            DataType::Long      => {
+                match text.parse::<i64>() {
+                    Ok(int) => DataEntry::from(int),
+                    Err(_)  => DataEntry::NA
+                }
+            },
+            DataType::ULong     => {
+                match text.parse::<u64>() {
+                    Ok(int) => DataEntry::from(int),
+                    Err(_)  => DataEntry::NA
+                }
+            },
+            DataType::Float     => {
+                match text.parse::<f32>() {
+                    Ok(f)   => DataEntry::from(f),
+                    Err(_)  => DataEntry::NA
+                }
+            },
+            DataType::Double    => {
+                match text.parse::<f64>() {
+                    Ok(f)   => DataEntry::from(f),
+                    Err(_)  => DataEntry::NA
+                }
+            },
+            DataType::Boolean   => {
+                match text.parse::<bool>() {
+                    Ok(b)   => DataEntry::from(b),
+                    Err(_)  => DataEntry::NA
+                }
+            },
+            DataType::Character => {
+                match text.parse::<char>() {
+                    Ok(ch)  => DataEntry::from(ch),
+                    Err(_)  => DataEntry::NA
+                }
+            },
+            DataType::Text      => DataEntry::from(text),
+            DataType::Date      => datetime::parse_date(text),
+            DataType::DateTime  => datetime::parse_datetime(text),
+            DataType::Duration  => datetime::parse_duration(text),
+            _                   => DataEntry::NA,
 
         }
     }
-}
 
-impl Add for DataEntry {
-    type Output = DataEntry;
+    /// Parses text into an entry of a temporal data type using a format string, see [`chrono::format::strftime`] for
+    /// the supported specifiers. A date time can be parsed from a format with or without offset, and from a format
+    /// containing only a date, in which case the time is midnight. Any other data type results in `DataEntry::NA`,
+    /// as does text not matching the format.
+    ///
+    /// [`chrono::format::strftime`]: https://docs.rs/chrono/latest/chrono/format/strftime/index.html
+    ///
+    /// # Examples
+    /// ```
+    /// # use raccoon::{DataEntry, DataType};
+    /// let entry = DataEntry::parse_with_format("14.03.2021", &DataType::Date, "%d.%m.%Y");
+    /// assert_eq!(entry.convert_to(&DataType::Text), DataEntry::from("2021-03-14"));
+    ///
+    /// let entry = DataEntry::parse_with_format("03/14/21 3:09 PM +0100", &DataType::DateTime, "%D %I:%M %p %z");
+    /// assert_eq!(entry.convert_to(&DataType::Text), DataEntry::from("2021-03-14T15:09:00+01:00"));
+    ///
+    /// assert_eq!(DataEntry::parse_with_format("2021-03-14", &DataType::Date, "%d.%m.%Y"), DataEntry::NA);
+    /// ```
+    pub fn parse_with_format(text: &str, data_type: &DataType, format: &str) -> DataEntry {
+        datetime::parse_with_format(text, data_type, format)
+    }
 
-    fn add(self, other: DataEntry) -> Self::Output {
-        match self {
+    /// Compares two entries by value.
+    ///
+    /// Numerical entries are compared by their value, regardless of their data type, and without loss of precision:
+    /// `DataEntry::Integer(3)` is equal to `DataEntry::Double(3.0)` and smaller than `DataEntry::ULong(4)`. Other
+    /// entries can only be compared to entries of the same data type. Date times with an offset are compared by the
+    /// instant they refer to, and cannot be compared to naive date times. `None` is returned if the entries cannot be
+    /// compared, if either of them is `DataEntry::NA`, or if a floating point number is NaN.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::DataEntry;
+    /// # use std::cmp::Ordering;
+    /// assert_eq!(DataEntry::Integer(3).compare(&DataEntry::Double(3.0)), Some(Ordering::Equal));
+    /// assert_eq!(DataEntry::Long(-1).compare(&DataEntry::ULong(u64::MAX)), Some(Ordering::Less));
+    /// assert_eq!(DataEntry::from("b").compare(&DataEntry::from("a")), Some(Ordering::Greater));
+    ///
+    /// assert_eq!(DataEntry::from("1").compare(&DataEntry::Integer(1)), None);
+    /// assert_eq!(DataEntry::NA.compare(&DataEntry::NA), None);
+    /// ```
+    pub fn compare(&self, other: &DataEntry) -> Option<Ordering> {
+        match (Number::from_entry(self), Number::from_entry(other)) {
+            (Some(number1), Some(number2))  => number1.compare(&number2),
+            (None, None)                    => {
+                match (self, other) {
+                    (DataEntry::Text(txt1), DataEntry::Text(txt2))              => Some(txt1.cmp(txt2)),
+                    (DataEntry::Boolean(b1), DataEntry::Boolean(b2))            => Some(b1.cmp(b2)),
+                    (DataEntry::Character(ch1), DataEntry::Character(ch2))      => Some(ch1.cmp(ch2)),
+                    (DataEntry::Date(date1), DataEntry::Date(date2))            => Some(date1.cmp(date2)),
+                    (DataEntry::DateTime(datetime1, offset1), DataEntry::DateTime(datetime2, offset2))  => {
+                        datetime::compare(datetime1, offset1, datetime2, offset2)
+                    },
+                    (DataEntry::Duration(duration1), DataEntry::Duration(duration2))  => {
+                        Some(duration1.cmp(duration2))
+                    },
+                    _                                                           => None,
+                }
+            },
+            _                               => None,
+        }
+    }
+
+    /// Adds two entries, returning an error instead of panicking or yielding `DataEntry::NA` when the addition fails.
+    ///
+    /// The data type of the result follows the same promotion rules as the `+` operator. If either entry is
+    /// `DataEntry::NA`, the result is `DataEntry::NA`. Dates, date times and durations are added as described in
+    /// `raccoon::datetime`.
+    ///
+    /// # Errors
+    /// - `RaccoonError::ArithmeticOverflow` if the result does not fit into the resulting integer or temporal type.
+    /// - `RaccoonError::UnsupportedOperation` if addition is not defined for the data types of the entries.
+    ///
+    /// # Examples
+    /// ```
+    /// # use raccoon::{DataEntry, RaccoonError};
+    /// let result = DataEntry::Integer(1).checked_add(DataEntry::UInteger(2));
+    /// assert_eq!(result.unwrap(), DataEntry::Long(3));
+    ///
+    /// let result = DataEntry::Integer(i32::MAX).checked_add(DataEntry::Integer(1));
+    /// assert!(matches!(result, Err(RaccoonError::ArithmeticOverflow)));
+    ///
+    /// // the `+` operator yields `DataEntry::NA` instead
+    /// assert_eq!(DataEntry::Integer(i32::MAX) + DataEntry::Integer(1), DataEntry::NA);
+    /// ```
+    pub fn checked_add(self, other: DataEntry) -> RaccoonResult<DataEntry> {
+        if self.data_type().is_temporal() || other.data_type().is_temporal() {
+            return datetime::add(self, other);
+        }
+        let (lhs_type, rhs_type) = (self.data_type(), other.data_type());
+        let result = match self {
             DataEntry::Integer(int1)    => {
                 match other {
-                    DataEntry::Integer(int2)    => DataEntry::Integer(int1 + int2),
+                    DataEntry::Integer(int2)    => DataEntry::Integer(checked(int1.checked_add(int2))?),
                     DataEntry::UInteger(int2)   => DataEntry::Long(int1 as i64 + int2 as i64),
-                    DataEntry::Long(int2)       => DataEntry::Long(int1 as i64 + int2),
+                    DataEntry::Long(int2)       => DataEntry::Long(checked((int1 as i64).checked_add(int2))?),
                     DataEntry::ULong(int2)      => DataEntry::Double(int1 as f64 + int2 as f64),
                     DataEntry::Float(f2)        => DataEntry::Float(int1 as f32 + f2),
                     DataEntry::Double(f2)       => DataEntry::Double(int1 as f64 + f2),
-                    DataEntry::Boolean(b2)      => DataEntry::Integer(int1 + b2 as i32),
-                    DataEntry::Character(ch1)   => DataEntry::Text(int1.to_string() + &ch1.to_string()),
+                    DataEntry::Boolean(b2)      => DataEntry::Integer(checked(int1.checked_add(b2 as i32))?),
+                    DataEntry::Character(ch2)   => DataEntry::Text(int1.to_string() + &ch2.to_string()),
                     DataEntry::Text(txt2)       => DataEntry::Text(int1.to_string() + &txt2),
-                    _                           => DataEntry::NA
+                    DataEntry::NA               => DataEntry::NA,
+                    _                           => return Err(unsupported(&lhs_type, "+", &rhs_type)),
                 }
             },
             DataEntry::UInteger(int1)   => {
                 match other {
                     DataEntry::Integer(int2)    => DataEntry::Long(int1 as i64 + int2 as i64),
-                    DataEntry::UInteger(int2)   => DataEntry::UInteger(int1 + int2),
-                    DataEntry::Long(int2)       => DataEntry::Long(int1 as i64 + int2),
+                    DataEntry::UInteger(int2)   => DataEntry::UInteger(checked(int1.checked_add(int2))?),
+                    DataEntry::Long(int2)       => DataEntry::Long(checked((int1 as i64).checked_add(int2))?),
                     DataEntry::ULong(int2)      => DataEntry::Double(int1 as f64 + int2 as f64),
                     DataEntry::Float(f2)        => DataEntry::Float(int1 as f32 + f2),
                     DataEntry::Double(f2)       => DataEntry::Double(int1 as f64 + f2),
-                    DataEntry::Boolean(b2)      => DataEntry::UInteger(int1 + b2 as u32),
-                    DataEntry::Character(ch1)   => DataEntry::Text(int1.to_string() + &ch1.to_string()),
+                    DataEntry::Boolean(b2)      => DataEntry::UInteger(checked(int1.checked_add(b2 as u32))?),
+                    DataEntry::Character(ch2)   => DataEntry::Text(int1.to_string() + &ch2.to_string()),
                     DataEntry::Text(txt2)       => DataEntry::Text(int1.to_string() + &txt2),
-                    _                           => DataEntry::NA
+                    DataEntry::NA               => DataEntry::NA,
+                    _                           => return Err(unsupported(&lhs_type, "+", &rhs_type)),
                 }
             },
             DataEntry::Long(int1)       => {
                 match other {
-                    DataEntry::Integer(int2)    => DataEntry::Long(int1 as i64 + int2 as i64),
-                    DataEntry::UInteger(int2)   => DataEntry::Long(int1 as i64 + int2 as i64),
-                    DataEntry::Long(int2)       => DataEntry::Long(int1 + int2),
+                    DataEntry::Integer(int2)    => DataEntry::Long(checked(int1.checked_add(int2 as i64))?),
+                    DataEntry::UInteger(int2)   => DataEntry::Long(checked(int1.checked_add(int2 as i64))?),
+                    DataEntry::Long(int2)       => DataEntry::Long(checked(int1.checked_add(int2))?),
                     DataEntry::ULong(int2)      => DataEntry::Double(int1 as f64 + int2 as f64),
                     DataEntry::Float(f2)        => DataEntry::Float(int1 as f32 + f2),
                     DataEntry::Double(f2)       => DataEntry::Double(int1 as f64 + f2),
-                    DataEntry::Boolean(b2)      => DataEntry::Long(int1 + b2 as i64),
-                    DataEntry::Character(ch1)   => DataEntry::Text(int1.to_string() + &ch1.to_string()),
+                    DataEntry::Boolean(b2)      => DataEntry::Long(checked(int1.checked_add(b2 as i64))?),
+                    DataEntry::Character(ch2)   => DataEntry::Text(int1.to_string() + &ch2.to_string()),
                     DataEntry::Text(txt2)       => DataEntry::Text(int1.to_string() + &txt2),
-                    _                           => DataEntry::NA
+                    DataEntry::NA               => DataEntry::NA,
+                    _                           => return Err(unsupported(&lhs_type, "+", &rhs_type)),
                 }
             },
             DataEntry::ULong(int1)      => {
                 match other {
                     DataEntry::Integer(int2)    => DataEntry::Double(int1 as f64 + int2 as f64),
-                    DataEntry::UInteger(int2)   => DataEntry::ULong(int1 + int2 as u64),
+                    DataEntry::UInteger(int2)   => DataEntry::ULong(checked(int1.checked_add(int2 as u64))?),
                     DataEntry::Long(int2)       => DataEntry::Double(int1 as f64 + int2 as f64),
-                    DataEntry::ULong(int2)      => DataEntry::ULong(int1 + int2),
+                    DataEntry::ULong(int2)      => DataEntry::ULong(checked(int1.checked_add(int2))?),
                     DataEntry::Float(f2)        => DataEntry::Double(int1 as f64 + f2 as f64),
                     DataEntry::Double(f2)       => DataEntry::Double(int1 as f64 + f2),
-                    DataEntry::Boolean(b2)      => DataEntry::ULong(int1 + b2 as u64),
-                    DataEntry::Character(ch1)   => DataEntry::Text(int1.to_string() + &ch1.to_string()),
+                    DataEntry::Boolean(b2)      => DataEntry::ULong(checked(int1.checked_add(b2 as u64))?),
+                    DataEntry::Character(ch2)   => DataEntry::Text(int1.to_string() + &ch2.to_string()),
                     DataEntry::Text(txt2)       => DataEntry::Text(int1.to_string() + &txt2),
-                    _                           => DataEntry::NA
+                    DataEntry::NA               => DataEntry::NA,
+                    _                           => return Err(unsupported(&lhs_type, "+", &rhs_type)),
                 }
             },
             DataEntry::Float(f1)        => {
@@ -451,9 +638,10 @@ impl Add for DataEntry {
                     DataEntry::Float(f2)        => DataEntry::Float(f1 + f2),
                     DataEntry::Double(f2)       => DataEntry::Double(f1 as f64 + f2),
                     DataEntry::Boolean(b2)      => DataEntry::Float(f1 + b2 as u8 as f32),
-                    DataEntry::Character(ch1)   => DataEntry::Text(f1.to_string() + &ch1.to_string()),
+                    DataEntry::Character(ch2)   => DataEntry::Text(f1.to_string() + &ch2.to_string()),
                     DataEntry::Text(txt2)       => DataEntry::Text(f1.to_string() + &txt2),
-                    _                           => DataEntry::NA
+                    DataEntry::NA               => DataEntry::NA,
+                    _                           => return Err(unsupported(&lhs_type, "+", &rhs_type)),
                 }
             },
             DataEntry::Double(f1)       => {
@@ -465,23 +653,25 @@ impl Add for DataEntry {
                     DataEntry::Float(f2)        => DataEntry::Double(f1 + f2 as f64),
                     DataEntry::Double(f2)       => DataEntry::Double(f1 + f2),
                     DataEntry::Boolean(b2)      => DataEntry::Double(f1 + b2 as u8 as f64),
-                    DataEntry::Character(ch1)   => DataEntry::Text(f1.to_string() + &ch1.to_string()),
+                    DataEntry::Character(ch2)   => DataEntry::Text(f1.to_string() + &ch2.to_string()),
                     DataEntry::Text(txt2)       => DataEntry::Text(f1.to_string() + &txt2),
-                    _                           => DataEntry::NA
+                    DataEntry::NA               => DataEntry::NA,
+                    _                           => return Err(unsupported(&lhs_type, "+", &rhs_type)),
                 }
             },
             DataEntry::Boolean(b1)      => {
                 match other {
-                    DataEntry::Integer(int2)    => DataEntry::Integer(b1 as i32 + int2),
-                    DataEntry::UInteger(int2)   => DataEntry::UInteger(b1 as u32 + int2),
-                    DataEntry::Long(int2)       => DataEntry::Long(b1 as i64 + int2),
-                    DataEntry::ULong(int2)      => DataEntry::ULong(b1 as u64 + int2),
+                    DataEntry::Integer(int2)    => DataEntry::Integer(checked((b1 as i32).checked_add(int2))?),
+                    DataEntry::UInteger(int2)   => DataEntry::UInteger(checked((b1 as u32).checked_add(int2))?),
+                    DataEntry::Long(int2)       => DataEntry::Long(checked((b1 as i64).checked_add(int2))?),
+                    DataEntry::ULong(int2)      => DataEntry::ULong(checked((b1 as u64).checked_add(int2))?),
                     DataEntry::Float(f2)        => DataEntry::Float(b1 as u8 as f32 + f2),
                     DataEntry::Double(f2)       => DataEntry::Double(b1 as u8 as f64 + f2),
                     DataEntry::Boolean(b2)      => DataEntry::Integer(b1 as i32 + b2 as i32),
-                    DataEntry::Character(ch1)   => DataEntry::Text(b1.to_string() + &ch1.to_string()),
+                    DataEntry::Character(ch2)   => DataEntry::Text(b1.to_string() + &ch2.to_string()),
                     DataEntry::Text(txt2)       => DataEntry::Text(b1.to_string() + &txt2),
-                    _                           => DataEntry::NA
+                    DataEntry::NA               => DataEntry::NA,
+                    _                           => return Err(unsupported(&lhs_type, "+", &rhs_type)),
                 }
             },
             DataEntry::Character(ch1)   => {
@@ -493,9 +683,10 @@ impl Add for DataEntry {
                     DataEntry::Float(f2)        => DataEntry::Text(ch1.to_string() + &f2.to_string()),
                     DataEntry::Double(f2)       => DataEntry::Text(ch1.to_string() + &f2.to_string()),
                     DataEntry::Boolean(b2)      => DataEntry::Text(ch1.to_string() + &b2.to_string()),
-                    DataEntry::Character(ch1)   => DataEntry::Text(ch1.to_string() + &ch1.to_string()),
+                    DataEntry::Character(ch2)   => DataEntry::Text(ch1.to_string() + &ch2.to_string()),
                     DataEntry::Text(txt2)       => DataEntry::Text(ch1.to_string() + &txt2),
-                    _                           => DataEntry::NA
+                    DataEntry::NA               => DataEntry::NA,
+                    _                           => return Err(unsupported(&lhs_type, "+", &rhs_type)),
                 }
             },
             DataEntry::Text(txt1)       => {
@@ -507,55 +698,87 @@ impl Add for DataEntry {
                     DataEntry::Float(f2)        => DataEntry::Text(txt1 + &f2.to_string()),
                     DataEntry::Double(f2)       => DataEntry::Text(txt1 + &f2.to_string()),
                     DataEntry::Boolean(b2)      => DataEntry::Text(txt1 + &b2.to_string()),
-                    DataEntry::Character(ch1)   => DataEntry::Text(txt1 + &ch1.to_string()),
+                    DataEntry::Character(ch2)   => DataEntry::Text(txt1 + &ch2.to_string()),
                     DataEntry::Text(txt2)       => DataEntry::Text(txt1 + &txt2),
-                    _                           => DataEntry::NA
+                    DataEntry::NA               => DataEntry::NA,
+                    _                           => return Err(unsupported(&lhs_type, "+", &rhs_type)),
                 }
             },
-            _                           => DataEntry::NA
-        }
+            DataEntry::NA               => DataEntry::NA,
+            _                           => return Err(unsupported(&lhs_type, "+", &rhs_type)),
+        };
+        Ok(result)
     }
-}
-
-impl Sub for DataEntry {
-    type Output = DataEntry;
 
-    fn sub(self, other: DataEntry) -> Self::Output {
-        match self {
+    /// Subtracts an entry from another, returning an error instead of panicking or yielding `DataEntry::NA` when the
+    /// subtraction fails.
+    ///
+    /// The data type of the result follows the same promotion rules as the `-` operator. If either entry is
+    /// `DataEntry::NA`, the result is `DataEntry::NA`. Dates, date times and durations are subtracted as described in
+    /// `raccoon::datetime`.
+    ///
+    /// # Errors
+    /// - `RaccoonError::ArithmeticOverflow` if the result does not fit into the resulting integer or temporal type.
+    /// - `RaccoonError::UnsupportedOperation` if subtraction is not defined for the data types of the entries.
+    ///
+    /// # Examples
+    /// ```
+    /// # use raccoon::{DataEntry, RaccoonError};
+    /// let result = DataEntry::Long(10).checked_sub(DataEntry::Integer(4));
+    /// assert_eq!(result.unwrap(), DataEntry::Long(6));
+    ///
+    /// let result = DataEntry::Boolean(false).checked_sub(DataEntry::ULong(1));
+    /// assert!(matches!(result, Err(RaccoonError::ArithmeticOverflow)));
+    ///
+    /// let result = DataEntry::Text("text".to_owned()).checked_sub(DataEntry::Integer(1));
+    /// assert!(matches!(result, Err(RaccoonError::UnsupportedOperation(_))));
+    /// ```
+    pub fn checked_sub(self, other: DataEntry) -> RaccoonResult<DataEntry> {
+        if self.data_type().is_temporal() || other.data_type().is_temporal() {
+            return datetime::sub(self, other);
+        }
+        let (lhs_type, rhs_type) = (self.data_type(), other.data_type());
+        let result = match self {
             DataEntry::Integer(int1)    => {
                 match other {
-                    DataEntry::Integer(int2)    => DataEntry::Integer(int1 - int2),
+                    DataEntry::Integer(int2)    => DataEntry::Integer(checked(int1.checked_sub(int2))?),
                     DataEntry::UInteger(int2)   => DataEntry::Long(int1 as i64 - int2 as i64),
-                    DataEntry::Long(int2)       => DataEntry::Long(int1 as i64 - int2),
+                    DataEntry::Long(int2)       => DataEntry::Long(checked((int1 as i64).checked_sub(int2))?),
                     DataEntry::ULong(int2)      => DataEntry::Double(int1 as f64 - int2 as f64),
                     DataEntry::Float(f2)        => DataEntry::Float(int1 as f32 - f2),
                     DataEntry::Double(f2)       => DataEntry::Double(int1 as f64 - f2),
-                    DataEntry::Boolean(b2)      => DataEntry::Integer(int1 - b2 as i32),
-                    _                           => DataEntry::NA
+                    DataEntry::Boolean(b2)      => DataEntry::Integer(checked(int1.checked_sub(b2 as i32))?),
+                    DataEntry::NA               => DataEntry::NA,
+                    _                           => return Err(unsupported(&lhs_type, "-", &rhs_type)),
                 }
             },
             DataEntry::UInteger(int1)   => {
                 match other {
                     DataEntry::Integer(int2)    => DataEntry::Long(int1 as i64 - int2 as i64),
                     DataEntry::UInteger(int2)   => DataEntry::Long(int1 as i64 - int2 as i64),
-                    DataEntry::Long(int2)       => DataEntry::Long(int1 as i64 + int2),
+                    DataEntry::Long(int2)       => DataEntry::Long(checked((int1 as i64).checked_sub(int2))?),
                     DataEntry::ULong(int2)      => DataEntry::Double(int1 as f64 - int2 as f64),
                     DataEntry::Float(f2)        => DataEntry::Float(int1 as f32 - f2),
                     DataEntry::Double(f2)       => DataEntry::Double(int1 as f64 - f2),
-                    DataEntry::Boolean(b2)      => DataEntry::Integer(int1 as i32 - b2 as i32),
-                    _                           => DataEntry::NA
+                    DataEntry::Boolean(b2)      => {
+                        let int1 = checked(i32::try_from(int1).ok())?;
+                        DataEntry::Integer(int1 - b2 as i32)
+                    },
+                    DataEntry::NA               => DataEntry::NA,
+                    _                           => return Err(unsupported(&lhs_type, "-", &rhs_type)),
                 }
             },
             DataEntry::Long(int1)       => {
                 match other {
-                    DataEntry::Integer(int2)    => DataEntry::Long(int1 as i64 - int2 as i64),
-                    DataEntry::UInteger(int2)   => DataEntry::Long(int1 as i64 - int2 as i64),
-                    DataEntry::Long(int2)       => DataEntry::Long(int1 - int2),
+                    DataEntry::Integer(int2)    => DataEntry::Long(checked(int1.checked_sub(int2 as i64))?),
+                    DataEntry::UInteger(int2)   => DataEntry::Long(checked(int1.checked_sub(int2 as i64))?),
+                    DataEntry::Long(int2)       => DataEntry::Long(checked(int1.checked_sub(int2))?),
                     DataEntry::ULong(int2)      => DataEntry::Double(int1 as f64 - int2 as f64),
                     DataEntry::Float(f2)        => DataEntry::Float(int1 as f32 - f2),
                     DataEntry::Double(f2)       => DataEntry::Double(int1 as f64 - f2),
-                    DataEntry::Boolean(b2)      => DataEntry::Long(int1 - b2 as i64),
-                    _                           => DataEntry::NA
+                    DataEntry::Boolean(b2)      => DataEntry::Long(checked(int1.checked_sub(b2 as i64))?),
+                    DataEntry::NA               => DataEntry::NA,
+                    _                           => return Err(unsupported(&lhs_type, "-", &rhs_type)),
                 }
             },
             DataEntry::ULong(int1)      => {
@@ -567,7 +790,8 @@ impl Sub for DataEntry {
                     DataEntry::Float(f2)        => DataEntry::Double(int1 as f64 - f2 as f64),
                     DataEntry::Double(f2)       => DataEntry::Double(int1 as f64 - f2),
                     DataEntry::Boolean(b2)      => DataEntry::Double(int1 as f64 - b2 as u8 as f64),
-                    _                           => DataEntry::NA
+                    DataEntry::NA               => DataEntry::NA,
+                    _                           => return Err(unsupported(&lhs_type, "-", &rhs_type)),
                 }
             },
             DataEntry::Float(f1)        => {
@@ -579,7 +803,8 @@ impl Sub for DataEntry {
                     DataEntry::Float(f2)        => DataEntry::Float(f1 - f2),
                     DataEntry::Double(f2)       => DataEntry::Double(f1 as f64 - f2),
                     DataEntry::Boolean(b2)      => DataEntry::Float(f1 - b2 as u8 as f32),
-                    _                           => DataEntry::NA
+                    DataEntry::NA               => DataEntry::NA,
+                    _                           => return Err(unsupported(&lhs_type, "-", &rhs_type)),
                 }
             },
             DataEntry::Double(f1)       => {
@@ -591,109 +816,122 @@ impl Sub for DataEntry {
                     DataEntry::Float(f2)        => DataEntry::Double(f1 - f2 as f64),
                     DataEntry::Double(f2)       => DataEntry::Double(f1 - f2),
                     DataEntry::Boolean(b2)      => DataEntry::Double(f1 - b2 as u8 as f64),
-                    _                           => DataEntry::NA
+                    DataEntry::NA               => DataEntry::NA,
+                    _                           => return Err(unsupported(&lhs_type, "-", &rhs_type)),
                 }
             },
             DataEntry::Boolean(b1)      => {
                 match other {
-                    DataEntry::Integer(int2)    => DataEntry::Integer(b1 as i32 - int2),
-                    DataEntry::UInteger(int2)   => DataEntry::UInteger(b1 as u32 - int2),
-                    DataEntry::Long(int2)       => DataEntry::Long(b1 as i64 - int2),
-                    DataEntry::ULong(int2)      => DataEntry::ULong(b1 as u64 - int2),
+                    DataEntry::Integer(int2)    => DataEntry::Integer(checked((b1 as i32).checked_sub(int2))?),
+                    DataEntry::UInteger(int2)   => DataEntry::UInteger(checked((b1 as u32).checked_sub(int2))?),
+                    DataEntry::Long(int2)       => DataEntry::Long(checked((b1 as i64).checked_sub(int2))?),
+                    DataEntry::ULong(int2)      => DataEntry::ULong(checked((b1 as u64).checked_sub(int2))?),
                     DataEntry::Float(f2)        => DataEntry::Float(b1 as u8 as f32 - f2),
                     DataEntry::Double(f2)       => DataEntry::Double(b1 as u8 as f64 - f2),
                     DataEntry::Boolean(b2)      => DataEntry::Integer(b1 as i32 - b2 as i32),
-                    _                           => DataEntry::NA
+                    DataEntry::NA               => DataEntry::NA,
+                    _                           => return Err(unsupported(&lhs_type, "-", &rhs_type)),
                 }
             },
-            _                           => DataEntry::NA
-        }
+            DataEntry::NA               => DataEntry::NA,
+            _                           => {
+                match other {
+                    DataEntry::NA               => DataEntry::NA,
+                    _                           => return Err(unsupported(&lhs_type, "-", &rhs_type)),
+                }
+            },
+        };
+        Ok(result)
     }
-}
-
-impl Mul for DataEntry {
-    type Output = DataEntry;
 
-    fn mul(self, rhs: DataEntry) -> Self::Output {
-        match self {
+    /// Multiplies two entries, returning an error instead of panicking or yielding `DataEntry::NA` when the
+    /// multiplication fails.
+    ///
+    /// The data type of the result follows the same promotion rules as the `*` operator. Multiplying a text or a
+    /// character by a number repeats it. Durations can be multiplied by integers. If either entry is `DataEntry::NA`,
+    /// the result is `DataEntry::NA`.
+    ///
+    /// # Errors
+    /// - `RaccoonError::ArithmeticOverflow` if the result does not fit into the resulting integer type, or if a
+    ///   repeated text would be too large to be stored.
+    /// - `RaccoonError::UnsupportedOperation` if multiplication is not defined for the data types of the entries.
+    ///
+    /// # Examples
+    /// ```
+    /// # use raccoon::{DataEntry, RaccoonError};
+    /// let result = DataEntry::Integer(3).checked_mul(DataEntry::Text("ab".to_owned()));
+    /// assert_eq!(result.unwrap(), DataEntry::Text("ababab".to_owned()));
+    ///
+    /// let result = DataEntry::ULong(u64::MAX).checked_mul(DataEntry::UInteger(2));
+    /// assert!(matches!(result, Err(RaccoonError::ArithmeticOverflow)));
+    /// ```
+    pub fn checked_mul(self, rhs: DataEntry) -> RaccoonResult<DataEntry> {
+        if self.data_type().is_temporal() || rhs.data_type().is_temporal() {
+            return datetime::mul(self, rhs);
+        }
+        let (lhs_type, rhs_type) = (self.data_type(), rhs.data_type());
+        let result = match self {
             DataEntry::Integer(int1)    => {
                 match rhs {
-                    DataEntry::Integer(int2)    => DataEntry::Integer(int1 * int2),
-                    DataEntry::UInteger(int2)   => DataEntry::Long(int1 as i64 * int2 as i64),
-                    DataEntry::Long(int2)       => DataEntry::Long(int1 as i64 * int2),
+                    DataEntry::Integer(int2)    => DataEntry::Integer(checked(int1.checked_mul(int2))?),
+                    DataEntry::UInteger(int2)   => DataEntry::Long(checked((int1 as i64).checked_mul(int2 as i64))?),
+                    DataEntry::Long(int2)       => DataEntry::Long(checked((int1 as i64).checked_mul(int2))?),
                     DataEntry::ULong(int2)      => DataEntry::Double(int1 as f64 * int2 as f64),
                     DataEntry::Float(f2)        => DataEntry::Float(int1 as f32 * f2),
                     DataEntry::Double(f2)       => DataEntry::Double(int1 as f64 * f2),
                     DataEntry::Boolean(b2)      => DataEntry::Integer(int1 * b2 as i32),
-                    DataEntry::Character(ch1)   => {
-                        if int1 >= 0i32 {
-                            DataEntry::Text(ch1.to_string().repeat(int1 as usize))
-                        } else {
-                            DataEntry::Text("".to_owned())
-                        }
-                    },
-                    DataEntry::Text(txt2)       => {
-                        if int1 >= 0i32 {
-                            DataEntry::Text(txt2.repeat(int1 as usize))
-                        } else {
-                            DataEntry::Text("".to_owned())
-                        }
-                    },
-                    _                           => DataEntry::NA
+                    DataEntry::Character(ch2)   => repeat(&ch2.to_string(), int1 as f64)?,
+                    DataEntry::Text(txt2)       => repeat(&txt2, int1 as f64)?,
+                    DataEntry::NA               => DataEntry::NA,
+                    _                           => return Err(unsupported(&lhs_type, "*", &rhs_type)),
                 }
             },
             DataEntry::UInteger(int1)   => {
                 match rhs {
-                    DataEntry::Integer(int2)    => DataEntry::Long(int1 as i64 * int2 as i64),
-                    DataEntry::UInteger(int2)   => DataEntry::UInteger(int1 * int2),
-                    DataEntry::Long(int2)       => DataEntry::Long(int1 as i64 * int2),
-                    DataEntry::ULong(int2)      => DataEntry::ULong(int1 as u64 * int2),
+                    DataEntry::Integer(int2)    => DataEntry::Long(checked((int1 as i64).checked_mul(int2 as i64))?),
+                    DataEntry::UInteger(int2)   => DataEntry::UInteger(checked(int1.checked_mul(int2))?),
+                    DataEntry::Long(int2)       => DataEntry::Long(checked((int1 as i64).checked_mul(int2))?),
+                    DataEntry::ULong(int2)      => DataEntry::ULong(checked((int1 as u64).checked_mul(int2))?),
                     DataEntry::Float(f2)        => DataEntry::Float(int1 as f32 * f2),
                     DataEntry::Double(f2)       => DataEntry::Double(int1 as f64 * f2),
-                    DataEntry::Boolean(b2)      => DataEntry::Integer(int1 as i32 * b2 as i32),
-                    DataEntry::Character(ch1)   => DataEntry::Text(ch1.to_string().repeat(int1 as usize)),
-                    DataEntry::Text(txt2)       => DataEntry::Text(txt2.repeat(int1 as usize)),
-                    _                           => DataEntry::NA
+                    DataEntry::Boolean(b2)      => {
+                        let int1 = if b2 { checked(i32::try_from(int1).ok())? } else { 0 };
+                        DataEntry::Integer(int1)
+                    },
+                    DataEntry::Character(ch2)   => repeat(&ch2.to_string(), int1 as f64)?,
+                    DataEntry::Text(txt2)       => repeat(&txt2, int1 as f64)?,
+                    DataEntry::NA               => DataEntry::NA,
+                    _                           => return Err(unsupported(&lhs_type, "*", &rhs_type)),
                 }
             },
             DataEntry::Long(int1)       => {
                 match rhs {
-                    DataEntry::Integer(int2)    => DataEntry::Long(int1 as i64 * int2 as i64),
-                    DataEntry::UInteger(int2)   => DataEntry::Long(int1 as i64 * int2 as i64),
-                    DataEntry::Long(int2)       => DataEntry::Long(int1 * int2),
+                    DataEntry::Integer(int2)    => DataEntry::Long(checked(int1.checked_mul(int2 as i64))?),
+                    DataEntry::UInteger(int2)   => DataEntry::Long(checked(int1.checked_mul(int2 as i64))?),
+                    DataEntry::Long(int2)       => DataEntry::Long(checked(int1.checked_mul(int2))?),
                     DataEntry::ULong(int2)      => DataEntry::Double(int1 as f64 * int2 as f64),
                     DataEntry::Float(f2)        => DataEntry::Float(int1 as f32 * f2),
                     DataEntry::Double(f2)       => DataEntry::Double(int1 as f64 * f2),
                     DataEntry::Boolean(b2)      => DataEntry::Long(int1 * b2 as i64),
-                    DataEntry::Character(ch1)   => {
-                        if int1 >= 0i64 {
-                            DataEntry::Text(ch1.to_string().repeat(int1 as usize))
-                        } else {
-                            DataEntry::Text("".to_owned())
-                        }
-                    },
-                    DataEntry::Text(txt2)       => {
-                        if int1 >= 0i64 {
-                            DataEntry::Text(txt2.repeat(int1 as usize))
-                        } else {
-                            DataEntry::Text("".to_owned())
-                        }
-                    },
-                    _                           => DataEntry::NA
+                    DataEntry::Character(ch2)   => repeat(&ch2.to_string(), int1 as f64)?,
+                    DataEntry::Text(txt2)       => repeat(&txt2, int1 as f64)?,
+                    DataEntry::NA               => DataEntry::NA,
+                    _                           => return Err(unsupported(&lhs_type, "*", &rhs_type)),
                 }
             },
             DataEntry::ULong(int1)      => {
                 match rhs {
                     DataEntry::Integer(int2)    => DataEntry::Double(int1 as f64 * int2 as f64),
-                    DataEntry::UInteger(int2)   => DataEntry::ULong(int1 * int2 as u64),
+                    DataEntry::UInteger(int2)   => DataEntry::ULong(checked(int1.checked_mul(int2 as u64))?),
                     DataEntry::Long(int2)       => DataEntry::Double(int1 as f64 * int2 as f64),
-                    DataEntry::ULong(int2)      => DataEntry::ULong(int1 * int2),
+                    DataEntry::ULong(int2)      => DataEntry::ULong(checked(int1.checked_mul(int2))?),
                     DataEntry::Float(f2)        => DataEntry::Double(int1 as f64 * f2 as f64),
                     DataEntry::Double(f2)       => DataEntry::Double(int1 as f64 * f2),
                     DataEntry::Boolean(b2)      => DataEntry::Double(int1 as f64 * b2 as u8 as f64),
-                    DataEntry::Character(ch1)   => DataEntry::Text(ch1.to_string().repeat(int1 as usize)),
-                    DataEntry::Text(txt2)       => DataEntry::Text(txt2.repeat(int1 as usize)),
-                    _                           => DataEntry::NA
+                    DataEntry::Character(ch2)   => repeat(&ch2.to_string(), int1 as f64)?,
+                    DataEntry::Text(txt2)       => repeat(&txt2, int1 as f64)?,
+                    DataEntry::NA               => DataEntry::NA,
+                    _                           => return Err(unsupported(&lhs_type, "*", &rhs_type)),
                 }
             },
             DataEntry::Float(f1)        => {
@@ -705,21 +943,10 @@ impl Mul for DataEntry {
                     DataEntry::Float(f2)        => DataEntry::Float(f1 * f2),
                     DataEntry::Double(f2)       => DataEntry::Double(f1 as f64 * f2),
                     DataEntry::Boolean(b2)      => DataEntry::Float(f1 * b2 as u8 as f32),
-                    DataEntry::Character(ch1)   => {
-                        if f1 >= 0f32 {
-                            DataEntry::Text(ch1.to_string().repeat(f1 as usize))
-                        } else {
-                            DataEntry::Text("".to_owned())
-                        }
-                    },
-                    DataEntry::Text(txt2)       => {
-                        if f1 >= 0f32 {
-                            DataEntry::Text(txt2.repeat(f1 as usize))
-                        } else {
-                            DataEntry::Text("".to_owned())
-                        }
-                    },
-                    _                           => DataEntry::NA
+                    DataEntry::Character(ch2)   => repeat(&ch2.to_string(), f1 as f64)?,
+                    DataEntry::Text(txt2)       => repeat(&txt2, f1 as f64)?,
+                    DataEntry::NA               => DataEntry::NA,
+                    _                           => return Err(unsupported(&lhs_type, "*", &rhs_type)),
                 }
             },
             DataEntry::Double(f1)       => {
@@ -731,21 +958,10 @@ impl Mul for DataEntry {
                     DataEntry::Float(f2)        => DataEntry::Double(f1 * f2 as f64),
                     DataEntry::Double(f2)       => DataEntry::Double(f1 * f2),
                     DataEntry::Boolean(b2)      => DataEntry::Double(f1 * b2 as u8 as f64),
-                    DataEntry::Character(ch1)   => {
-                        if f1 >= 0f64 {
-                            DataEntry::Text(ch1.to_string().repeat(f1 as usize))
-                        } else {
-                            DataEntry::Text("".to_owned())
-                        }
-                    },
-                    DataEntry::Text(txt2)       => {
-                        if f1 >= 0f64 {
-                            DataEntry::Text(txt2.repeat(f1 as usize))
-                        } else {
-                            DataEntry::Text("".to_owned())
-                        }
-                    },
-                    _                           => DataEntry::NA
+                    DataEntry::Character(ch2)   => repeat(&ch2.to_string(), f1)?,
+                    DataEntry::Text(txt2)       => repeat(&txt2, f1)?,
+                    DataEntry::NA               => DataEntry::NA,
+                    _                           => return Err(unsupported(&lhs_type, "*", &rhs_type)),
                 }
             },
             DataEntry::Boolean(b1)      => {
@@ -757,135 +973,133 @@ impl Mul for DataEntry {
                     DataEntry::Float(f2)        => DataEntry::Float(b1 as u8 as f32 * f2),
                     DataEntry::Double(f2)       => DataEntry::Double(b1 as u8 as f64 * f2),
                     DataEntry::Boolean(b2)      => DataEntry::Integer(b1 as i32 * b2 as i32),
-                    DataEntry::Character(ch1)   => DataEntry::Text(ch1.to_string().repeat(b1 as usize)),
-                    DataEntry::Text(txt2)       => DataEntry::Text(txt2.repeat(b1 as usize)),
-                    _                           => DataEntry::NA
+                    DataEntry::Character(ch2)   => repeat(&ch2.to_string(), b1 as u8 as f64)?,
+                    DataEntry::Text(txt2)       => repeat(&txt2, b1 as u8 as f64)?,
+                    DataEntry::NA               => DataEntry::NA,
+                    _                           => return Err(unsupported(&lhs_type, "*", &rhs_type)),
                 }
             },
             DataEntry::Character(ch1)   => {
                 match rhs {
-                    DataEntry::Integer(int2)   => {
-                        if int2 >= 0i32 {
-                            DataEntry::Text(ch1.to_string().repeat(int2 as usize))
-                        } else {
-                            DataEntry::Text("".to_owned())
-                        }
-                    },
-                    DataEntry::UInteger(int2)   => DataEntry::Text(ch1.to_string().repeat(int2 as usize)),
-                    DataEntry::Long(int2)       => {
-                        if int2 >= 0i64 {
-                            DataEntry::Text(ch1.to_string().repeat(int2 as usize))
-                        } else {
-                            DataEntry::Text("".to_owned())
-                        }
-                    },
-                    DataEntry::ULong(int2)      => DataEntry::Text(ch1.to_string().repeat(int2 as usize)),
-                    DataEntry::Float(f2)        => {
-                        if f2 >= 0f32 {
-                            DataEntry::Text(ch1.to_string().repeat(f2 as usize))
-                        } else {
-                            DataEntry::Text("".to_owned())
-                        }
-                    },
-                    DataEntry::Double(f2)       => {
-                        if f2 >= 0f64 {
-                            DataEntry::Text(ch1.to_string().repeat(f2 as usize))
-                        } else {
-                            DataEntry::Text("".to_owned())
-                        }
-                    },
-                    DataEntry::Boolean(b2)      => DataEntry::Text(ch1.to_string().repeat(b2 as usize)),
-                    _                           => DataEntry::NA
+                    DataEntry::Integer(int2)    => repeat(&ch1.to_string(), int2 as f64)?,
+                    DataEntry::UInteger(int2)   => repeat(&ch1.to_string(), int2 as f64)?,
+                    DataEntry::Long(int2)       => repeat(&ch1.to_string(), int2 as f64)?,
+                    DataEntry::ULong(int2)      => repeat(&ch1.to_string(), int2 as f64)?,
+                    DataEntry::Float(f2)        => repeat(&ch1.to_string(), f2 as f64)?,
+                    DataEntry::Double(f2)       => repeat(&ch1.to_string(), f2)?,
+                    DataEntry::Boolean(b2)      => repeat(&ch1.to_string(), b2 as u8 as f64)?,
+                    DataEntry::NA               => DataEntry::NA,
+                    _                           => return Err(unsupported(&lhs_type, "*", &rhs_type)),
                 }
             },
             DataEntry::Text(txt1)       => {
                 match rhs {
-                    DataEntry::Integer(int2)    => {
-                        if int2 >= 0i32 {
-                            DataEntry::Text(txt1.repeat(int2 as usize))
-                        } else {
-                            DataEntry::Text("".to_owned())
-                        }
-                    },
-                    DataEntry::UInteger(int2)   => DataEntry::Text(txt1.repeat(int2 as usize)),
-                    DataEntry::Long(int2)       => {
-                        if int2 >= 0i64 {
-                            DataEntry::Text(txt1.repeat(int2 as usize))
-                        } else {
-                            DataEntry::Text("".to_owned())
-                        }
-                    },
-                    DataEntry::ULong(int2)      => DataEntry::Text(txt1.repeat(int2 as usize)),
-                    DataEntry::Float(f2)        => {
-                        if f2 >= 0f32 {
-                            DataEntry::Text(txt1.repeat(f2 as usize))
-                        } else {
-                            DataEntry::Text("".to_owned())
-                        }
-                    },
-                    DataEntry::Double(f2)       => {
-                        if f2 >= 0f64 {
-                            DataEntry::Text(txt1.repeat(f2 as usize))
-                        } else {
-                            DataEntry::Text("".to_owned())
-                        }
-                    },
-                    DataEntry::Boolean(b2)      => DataEntry::Text(txt1.repeat(b2 as usize)),
-                    _                           => DataEntry::NA
+                    DataEntry::Integer(int2)    => repeat(&txt1, int2 as f64)?,
+                    DataEntry::UInteger(int2)   => repeat(&txt1, int2 as f64)?,
+                    DataEntry::Long(int2)       => repeat(&txt1, int2 as f64)?,
+                    DataEntry::ULong(int2)      => repeat(&txt1, int2 as f64)?,
+                    DataEntry::Float(f2)        => repeat(&txt1, f2 as f64)?,
+                    DataEntry::Double(f2)       => repeat(&txt1, f2)?,
+                    DataEntry::Boolean(b2)      => repeat(&txt1, b2 as u8 as f64)?,
+                    DataEntry::NA               => DataEntry::NA,
+                    _                           => return Err(unsupported(&lhs_type, "*", &rhs_type)),
                 }
             },
-            _                           => DataEntry::NA
-        }
+            DataEntry::NA               => DataEntry::NA,
+            _                           => return Err(unsupported(&lhs_type, "*", &rhs_type)),
+        };
+        Ok(result)
     }
-}
-
-impl Div for DataEntry {
-    type Output = DataEntry;
 
-    fn div(self, rhs: DataEntry) -> Self::Output {
-        match self {
+    /// Divides an entry by another, returning an error instead of panicking or yielding `DataEntry::NA` when the
+    /// division fails.
+    ///
+    /// The data type of the result follows the same promotion rules as the `/` operator. Dividing floating point
+    /// numbers by zero follows IEEE 754 and yields an infinite value or NaN. Durations can be divided by integers and
+    /// by durations. If either entry is `DataEntry::NA`, the result is `DataEntry::NA`.
+    ///
+    /// # Errors
+    /// - `RaccoonError::DivisionByZero` if an integer division, or a division of a duration, by zero is attempted.
+    /// - `RaccoonError::ArithmeticOverflow` if the result does not fit into the resulting integer type.
+    /// - `RaccoonError::UnsupportedOperation` if division is not defined for the data types of the entries.
+    ///
+    /// # Examples
+    /// ```
+    /// # use raccoon::{DataEntry, RaccoonError};
+    /// let result = DataEntry::Integer(7).checked_div(DataEntry::Integer(2));
+    /// assert_eq!(result.unwrap(), DataEntry::Integer(3));
+    ///
+    /// let result = DataEntry::Integer(1).checked_div(DataEntry::Integer(0));
+    /// assert!(matches!(result, Err(RaccoonError::DivisionByZero)));
+    ///
+    /// // floating point division follows IEEE 754
+    /// let result = DataEntry::Double(1.0).checked_div(DataEntry::Integer(0));
+    /// assert_eq!(result.unwrap(), DataEntry::Double(f64::INFINITY));
+    /// ```
+    pub fn checked_div(self, rhs: DataEntry) -> RaccoonResult<DataEntry> {
+        if self.data_type().is_temporal() || rhs.data_type().is_temporal() {
+            return datetime::div(self, rhs);
+        }
+        let (lhs_type, rhs_type) = (self.data_type(), rhs.data_type());
+        let result = match self {
             DataEntry::Integer(int1)    => {
                 match rhs {
-                    DataEntry::Integer(int2)    => DataEntry::Integer(int1 / int2),
-                    DataEntry::UInteger(int2)   => DataEntry::Long(int1 as i64 / int2 as i64),
-                    DataEntry::Long(int2)       => DataEntry::Long(int1 as i64 / int2),
+                    DataEntry::Integer(int2)    => DataEntry::Integer(quotient(int1.checked_div(int2), int2 == 0)?),
+                    DataEntry::UInteger(int2)   => {
+                        DataEntry::Long(quotient((int1 as i64).checked_div(int2 as i64), int2 == 0)?)
+                    },
+                    DataEntry::Long(int2)       => {
+                        DataEntry::Long(quotient((int1 as i64).checked_div(int2), int2 == 0)?)
+                    },
                     DataEntry::ULong(int2)      => DataEntry::Double(int1 as f64 / int2 as f64),
                     DataEntry::Float(f2)        => DataEntry::Float(int1 as f32 / f2),
                     DataEntry::Double(f2)       => DataEntry::Double(int1 as f64 / f2),
-                    _                           => DataEntry::NA
+                    DataEntry::NA               => DataEntry::NA,
+                    _                           => return Err(unsupported(&lhs_type, "/", &rhs_type)),
                 }
             },
             DataEntry::UInteger(int1)   => {
                 match rhs {
-                    DataEntry::Integer(int2)    => DataEntry::Long(int1 as i64 / int2 as i64),
-                    DataEntry::UInteger(int2)   => DataEntry::UInteger(int1 / int2),
-                    DataEntry::Long(int2)       => DataEntry::Long(int1 as i64 / int2),
-                    DataEntry::ULong(int2)      => DataEntry::ULong(int1 as u64 / int2),
+                    DataEntry::Integer(int2)    => {
+                        DataEntry::Long(quotient((int1 as i64).checked_div(int2 as i64), int2 == 0)?)
+                    },
+                    DataEntry::UInteger(int2)   => DataEntry::UInteger(quotient(int1.checked_div(int2), int2 == 0)?),
+                    DataEntry::Long(int2)       => {
+                        DataEntry::Long(quotient((int1 as i64).checked_div(int2), int2 == 0)?)
+                    },
+                    DataEntry::ULong(int2)      => {
+                        DataEntry::ULong(quotient((int1 as u64).checked_div(int2), int2 == 0)?)
+                    },
                     DataEntry::Float(f2)        => DataEntry::Float(int1 as f32 / f2),
                     DataEntry::Double(f2)       => DataEntry::Double(int1 as f64 / f2),
-                    _                           => DataEntry::NA
+                    DataEntry::NA               => DataEntry::NA,
+                    _                           => return Err(unsupported(&lhs_type, "/", &rhs_type)),
                 }
             },
             DataEntry::Long(int1)       => {
                 match rhs {
-                    DataEntry::Integer(int2)    => DataEntry::Long(int1 as i64 / int2 as i64),
-                    DataEntry::UInteger(int2)   => DataEntry::Long(int1 as i64 / int2 as i64),
-                    DataEntry::Long(int2)       => DataEntry::Long(int1 / int2),
+                    DataEntry::Integer(int2)    => DataEntry::Long(quotient(int1.checked_div(int2 as i64), int2 == 0)?),
+                    DataEntry::UInteger(int2)   => DataEntry::Long(quotient(int1.checked_div(int2 as i64), int2 == 0)?),
+                    DataEntry::Long(int2)       => DataEntry::Long(quotient(int1.checked_div(int2), int2 == 0)?),
                     DataEntry::ULong(int2)      => DataEntry::Double(int1 as f64 / int2 as f64),
                     DataEntry::Float(f2)        => DataEntry::Float(int1 as f32 / f2),
                     DataEntry::Double(f2)       => DataEntry::Double(int1 as f64 / f2),
-                    _                           => DataEntry::NA
+                    DataEntry::NA               => DataEntry::NA,
+                    _                           => return Err(unsupported(&lhs_type, "/", &rhs_type)),
                 }
             },
             DataEntry::ULong(int1)      => {
                 match rhs {
                     DataEntry::Integer(int2)    => DataEntry::Double(int1 as f64 / int2 as f64),
-                    DataEntry::UInteger(int2)   => DataEntry::ULong(int1 / int2 as u64),
+                    DataEntry::UInteger(int2)   => {
+                        DataEntry::ULong(quotient(int1.checked_div(int2 as u64), int2 == 0)?)
+                    },
                     DataEntry::Long(int2)       => DataEntry::Double(int1 as f64 / int2 as f64),
-                    DataEntry::ULong(int2)      => DataEntry::ULong(int1 / int2),
+                    DataEntry::ULong(int2)      => DataEntry::ULong(quotient(int1.checked_div(int2), int2 == 0)?),
                     DataEntry::Float(f2)        => DataEntry::Double(int1 as f64 / f2 as f64),
                     DataEntry::Double(f2)       => DataEntry::Double(int1 as f64 / f2),
-                    _                           => DataEntry::NA
+                    DataEntry::NA               => DataEntry::NA,
+                    _                           => return Err(unsupported(&lhs_type, "/", &rhs_type)),
                 }
             },
             DataEntry::Float(f1)        => {
@@ -896,7 +1110,8 @@ impl Div for DataEntry {
                     DataEntry::ULong(int2)      => DataEntry::Double(f1 as f64 / int2 as f64),
                     DataEntry::Float(f2)        => DataEntry::Float(f1 / f2),
                     DataEntry::Double(f2)       => DataEntry::Double(f1 as f64 / f2),
-                    _                           => DataEntry::NA
+                    DataEntry::NA               => DataEntry::NA,
+                    _                           => return Err(unsupported(&lhs_type, "/", &rhs_type)),
                 }
             },
             DataEntry::Double(f1)       => {
@@ -907,25 +1122,302 @@ impl Div for DataEntry {
                     DataEntry::ULong(int2)      => DataEntry::Double(f1 / int2 as f64),
                     DataEntry::Float(f2)        => DataEntry::Double(f1 / f2 as f64),
                     DataEntry::Double(f2)       => DataEntry::Double(f1 / f2),
-                    _                           => DataEntry::NA
+                    DataEntry::NA               => DataEntry::NA,
+                    _                           => return Err(unsupported(&lhs_type, "/", &rhs_type)),
                 }
             },
             DataEntry::Boolean(b1)      => {
                 match rhs {
-                    DataEntry::Integer(int2)    => DataEntry::Integer(b1 as i32 / int2),
-                    DataEntry::UInteger(int2)   => DataEntry::UInteger(b1 as u32 / int2),
-                    DataEntry::Long(int2)       => DataEntry::Long(b1 as i64 / int2),
-                    DataEntry::ULong(int2)      => DataEntry::ULong(b1 as u64 / int2),
+                    DataEntry::Integer(int2)    => {
+                        DataEntry::Integer(quotient((b1 as i32).checked_div(int2), int2 == 0)?)
+                    },
+                    DataEntry::UInteger(int2)   => {
+                        DataEntry::UInteger(quotient((b1 as u32).checked_div(int2), int2 == 0)?)
+                    },
+                    DataEntry::Long(int2)       => DataEntry::Long(quotient((b1 as i64).checked_div(int2), int2 == 0)?),
+                    DataEntry::ULong(int2)      => {
+                        DataEntry::ULong(quotient((b1 as u64).checked_div(int2), int2 == 0)?)
+                    },
                     DataEntry::Float(f2)        => DataEntry::Float(b1 as u8 as f32 / f2),
                     DataEntry::Double(f2)       => DataEntry::Double(b1 as u8 as f64 / f2),
-                    _                           => DataEntry::NA
+                    DataEntry::NA               => DataEntry::NA,
+                    _                           => return Err(unsupported(&lhs_type, "/", &rhs_type)),
                 }
             },
-            _                           => DataEntry::NA
+            DataEntry::NA               => DataEntry::NA,
+            _                           => {
+                match rhs {
+                    DataEntry::NA               => DataEntry::NA,
+                    _                           => return Err(unsupported(&lhs_type, "/", &rhs_type)),
+                }
+            },
+        };
+        Ok(result)
+    }
+}
+
+impl Add for DataEntry {
+    type Output = DataEntry;
+
+    fn add(self, other: DataEntry) -> Self::Output {
+        self.checked_add(other).unwrap_or(DataEntry::NA)
+    }
+}
+
+impl Sub for DataEntry {
+    type Output = DataEntry;
+
+    fn sub(self, other: DataEntry) -> Self::Output {
+        self.checked_sub(other).unwrap_or(DataEntry::NA)
+    }
+}
+
+impl Mul for DataEntry {
+    type Output = DataEntry;
+
+    fn mul(self, rhs: DataEntry) -> Self::Output {
+        self.checked_mul(rhs).unwrap_or(DataEntry::NA)
+    }
+}
+
+impl Div for DataEntry {
+    type Output = DataEntry;
+
+    fn div(self, rhs: DataEntry) -> Self::Output {
+        self.checked_div(rhs).unwrap_or(DataEntry::NA)
+    }
+}
+
+impl PartialEq for DataEntry {
+    fn eq(&self, other: &DataEntry) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for DataEntry {
+    fn partial_cmp(&self, other: &DataEntry) -> Option<Ordering> {
+        match (self, other) {
+            (DataEntry::NA, DataEntry::NA)  => Some(Ordering::Equal),
+            _                               => self.compare(other),
+        }
+    }
+}
+
+/// A wrapper around a `DataEntry` providing a total order and a hash, such that entries can be sorted or used as keys
+/// of a `BTreeMap` or a `HashMap`.
+///
+/// Entries are ordered by kind first: booleans, numbers, characters, texts, dates, naive date times, date times with an
+/// offset, durations, and finally `DataEntry::NA`. Within a kind, entries are ordered by value, numbers being compared
+/// across data types as for `DataEntry`. To obtain a total order, all NaN floating point numbers are equal to one
+/// another and greater than any other number, and `DataEntry::NA` is equal to itself. Equality is consistent with the
+/// order, hence `OrderedEntry(Integer(1))` equals `OrderedEntry(Long(1))`.
+///
+/// The hash is consistent with equality: numbers are hashed after their value rather than their data type, NaNs all
+/// hash the same, and `-0.0` hashes like `0.0`. Date times with an offset are hashed after the instant they refer to.
+///
+/// # Example
+/// ```
+/// use raccoon::{DataEntry, OrderedEntry};
+/// use std::collections::BTreeMap;
+///
+/// let mut entries = vec![
+///     OrderedEntry(DataEntry::NA),
+///     OrderedEntry(DataEntry::Double(f64::NAN)),
+///     OrderedEntry(DataEntry::Long(7)),
+///     OrderedEntry(DataEntry::from("text")),
+///     OrderedEntry(DataEntry::Float(-0.5)),
+/// ];
+/// entries.sort();
+/// assert_eq!(entries[0], OrderedEntry(DataEntry::Float(-0.5)));
+/// assert_eq!(entries[1], OrderedEntry(DataEntry::Integer(7)));
+/// assert_eq!(entries[4], OrderedEntry(DataEntry::NA));
+///
+/// let mut counts = BTreeMap::new();
+/// for entry in vec![DataEntry::Integer(1), DataEntry::Long(1), DataEntry::Double(2.0)] {
+///     *counts.entry(OrderedEntry(entry)).or_insert(0) += 1;
+/// }
+/// assert_eq!(counts[&OrderedEntry(DataEntry::ULong(1))], 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct OrderedEntry(pub DataEntry);
+
+impl OrderedEntry {
+    /// Returns the rank of the kind of the entry, used to order entries of different kinds.
+    fn rank(&self) -> u8 {
+        match self.0 {
+            DataEntry::Boolean(_)   => 0,
+            DataEntry::Character(_) => 2,
+            DataEntry::Text(_)      => 3,
+            DataEntry::Date(_)      => 4,
+            DataEntry::DateTime(_, None)    => 5,
+            DataEntry::DateTime(_, Some(_)) => 6,
+            DataEntry::Duration(_)  => 7,
+            DataEntry::NA           => 8,
+            _                       => 1,
+        }
+    }
+}
+
+impl Ord for OrderedEntry {
+    fn cmp(&self, other: &OrderedEntry) -> Ordering {
+        match self.rank().cmp(&other.rank()) {
+            Ordering::Equal => {},
+            ordering        => return ordering,
+        }
+        match self.0.compare(&other.0) {
+            Some(ordering)  => ordering,
+            // only NaN and NA are not comparable within their kind
+            None            => is_nan(&self.0).cmp(&is_nan(&other.0)),
+        }
+    }
+}
+
+impl PartialOrd for OrderedEntry {
+    fn partial_cmp(&self, other: &OrderedEntry) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for OrderedEntry {
+    fn eq(&self, other: &OrderedEntry) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for OrderedEntry {}
+
+impl Hash for OrderedEntry {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.rank().hash(state);
+        match self.0 {
+            DataEntry::Text(ref text)   => text.hash(state),
+            DataEntry::Boolean(b)       => b.hash(state),
+            DataEntry::Character(ch)    => ch.hash(state),
+            DataEntry::Date(date)       => date.hash(state),
+            DataEntry::DateTime(datetime, offset)   => datetime::instant(&datetime, &offset).hash(state),
+            DataEntry::Duration(duration)   => duration.hash(state),
+            DataEntry::NA               => {},
+            ref number                  => match Number::from_entry(number) {
+                Some(Number::Integer(int))  => int.hash(state),
+                Some(Number::Float(f))      => hash_float(f, state),
+                None                        => {},
+            },
+        }
+    }
+}
+
+impl From<DataEntry> for OrderedEntry {
+    fn from(entry: DataEntry) -> Self {
+        OrderedEntry(entry)
+    }
+}
+
+/// Hashes a floating point number such that it hashes like an integer of equal value, all NaNs hash the same, and
+/// `-0.0` hashes like `0.0`.
+fn hash_float<H: Hasher>(f: f64, state: &mut H) {
+    if f.is_nan() {
+        u64::MAX.hash(state);
+    } else if f.fract() == 0.0 && f.abs() < 2f64.powi(127) {
+        // integral floats, including -0.0, are exactly representable as i128
+        (f as i128).hash(state);
+    } else {
+        f.to_bits().hash(state);
+    }
+}
+
+/// Checks whether an entry is a NaN floating point number.
+fn is_nan(entry: &DataEntry) -> bool {
+    match *entry {
+        DataEntry::Float(f)     => f.is_nan(),
+        DataEntry::Double(f)    => f.is_nan(),
+        _                       => false,
+    }
+}
+
+/// The value of a numerical entry, used to compare numbers of different data types.
+enum Number {
+    Integer(i128),
+    Float(f64),
+}
+
+impl Number {
+    /// Returns the value of `entry`, or `None` if it is not numerical.
+    fn from_entry(entry: &DataEntry) -> Option<Number> {
+        match *entry {
+            DataEntry::Integer(int)     => Some(Number::Integer(int as i128)),
+            DataEntry::UInteger(int)    => Some(Number::Integer(int as i128)),
+            DataEntry::Long(int)        => Some(Number::Integer(int as i128)),
+            DataEntry::ULong(int)       => Some(Number::Integer(int as i128)),
+            DataEntry::Float(f)         => Some(Number::Float(f as f64)),
+            DataEntry::Double(f)        => Some(Number::Float(f)),
+            _                           => None,
+        }
+    }
+
+    /// Compares two numbers exactly. Casting integers to floating point numbers could round them, hence integers are
+    /// compared to the integral part of floating point numbers first, and to their fractional part second.
+    fn compare(&self, other: &Number) -> Option<Ordering> {
+        match (self, other) {
+            (&Number::Integer(int1), &Number::Integer(int2))    => Some(int1.cmp(&int2)),
+            (&Number::Float(f1), &Number::Float(f2))            => f1.partial_cmp(&f2),
+            (&Number::Integer(int1), &Number::Float(f2))        => compare_mixed(int1, f2),
+            (&Number::Float(f1), &Number::Integer(int2))        => compare_mixed(int2, f1).map(Ordering::reverse),
         }
     }
 }
 
+/// Compares an integer with a floating point number without loss of precision.
+fn compare_mixed(int: i128, float: f64) -> Option<Ordering> {
+    if float.is_nan() {
+        return None;
+    }
+    // every `i128` lies in [-2^127, 2^127)
+    let bound = 2f64.powi(127);
+    if float >= bound {
+        return Some(Ordering::Less);
+    }
+    if float < -bound {
+        return Some(Ordering::Greater);
+    }
+    let integral = float.trunc();
+    match int.cmp(&(integral as i128)) {
+        Ordering::Equal => 0f64.partial_cmp(&(float - integral)),
+        ordering        => Some(ordering),
+    }
+}
+
+/// Unwraps the result of a checked integer operation, `None` meaning that the operation overflowed.
+fn checked<T>(value: Option<T>) -> RaccoonResult<T> {
+    value.ok_or(RaccoonError::ArithmeticOverflow)
+}
+
+/// Unwraps the result of a checked integer division, telling a division by zero apart from an overflow.
+fn quotient<T>(value: Option<T>, divisor_is_zero: bool) -> RaccoonResult<T> {
+    match value {
+        Some(value)             => Ok(value),
+        None if divisor_is_zero => Err(RaccoonError::DivisionByZero),
+        None                    => Err(RaccoonError::ArithmeticOverflow),
+    }
+}
+
+/// Repeats a text `count` times, the fractional part of `count` being truncated. A negative or NaN count yields an
+/// empty text.
+fn repeat(text: &str, count: f64) -> RaccoonResult<DataEntry> {
+    if count.is_nan() || count < 1f64 || text.is_empty() {
+        return Ok(DataEntry::Text(String::new()));
+    }
+    if count > (isize::MAX as usize / text.len()) as f64 {
+        return Err(RaccoonError::ArithmeticOverflow);
+    }
+    Ok(DataEntry::Text(text.repeat(count as usize)))
+}
+
+/// Builds the error returned when an operator is not defined for the data types of its operands.
+fn unsupported(lhs: &DataType, operator: &str, rhs: &DataType) -> RaccoonError {
+    RaccoonError::UnsupportedOperation(format!("{:?} {} {:?}", lhs, operator, rhs))
+}
+
+
 impl From<i8> for DataEntry {
     fn from(integer: i8) -> Self {
         DataEntry::Integer(integer as i32)
@@ -1016,6 +1508,36 @@ impl<'a> From<&'a str> for DataEntry {
     }
 }
 
+impl From<NaiveDate> for DataEntry {
+    fn from(date: NaiveDate) -> Self {
+        DataEntry::Date(date)
+    }
+}
+
+impl From<NaiveDateTime> for DataEntry {
+    fn from(datetime: NaiveDateTime) -> Self {
+        DataEntry::DateTime(datetime, None)
+    }
+}
+
+impl From<DateTime<FixedOffset>> for DataEntry {
+    fn from(datetime: DateTime<FixedOffset>) -> Self {
+        DataEntry::DateTime(datetime.naive_local(), Some(*datetime.offset()))
+    }
+}
+
+impl From<DateTime<Utc>> for DataEntry {
+    fn from(datetime: DateTime<Utc>) -> Self {
+        DataEntry::from(datetime.fixed_offset())
+    }
+}
+
+impl From<Duration> for DataEntry {
+    fn from(duration: Duration) -> Self {
+        DataEntry::Duration(duration)
+    }
+}
+
 
 /// The data type any entry can take.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -1038,10 +1560,77 @@ pub enum DataType {
     Boolean,
     /// Character
     Character,
+    /// Date
+    Date,
+    /// Date and time, with or without offset
+    DateTime,
+    /// Duration
+    Duration,
     /// Missing
     NA
 }
 
+impl DataType {
+    /// Checks whether the data type is numerical, that is an integer or a floating point number.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::DataType;
+    /// assert!(DataType::UInteger.is_numeric());
+    /// assert!(DataType::Double.is_numeric());
+    /// assert!(!DataType::Boolean.is_numeric());
+    /// ```
+    pub fn is_numeric(&self) -> bool {
+        matches!(*self, DataType::Integer | DataType::UInteger | DataType::Long | DataType::ULong | DataType::Float
+            | DataType::Double)
+    }
+
+    /// Checks whether the data type is temporal, that is a date, a date time or a duration.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::DataType;
+    /// assert!(DataType::DateTime.is_temporal());
+    /// assert!(!DataType::Long.is_temporal());
+    /// ```
+    pub fn is_temporal(&self) -> bool {
+        matches!(*self, DataType::Date | DataType::DateTime | DataType::Duration)
+    }
+
+    /// Returns the smallest data type into which entries of both `self` and `other` can be converted, or `None` if
+    /// there is no such type. `DataType::NA` is compatible with every data type. Integers of different sizes or
+    /// signedness are widened to `DataType::Long` without losing their value, except for `DataType::ULong` which is
+    /// combined with signed integers into a `DataType::Double`, like floating point numbers combined with other
+    /// numbers. A `DataType::Double` only holds integers up to 2^53 exactly, so larger `DataType::Long` or
+    /// `DataType::ULong` values are rounded. Dates are combined with date times into a `DataType::DateTime`.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::DataType;
+    /// assert_eq!(DataType::Integer.common_type(&DataType::UInteger), Some(DataType::Long));
+    /// assert_eq!(DataType::Integer.common_type(&DataType::Double), Some(DataType::Double));
+    /// assert_eq!(DataType::ULong.common_type(&DataType::Integer), Some(DataType::Double));
+    /// assert_eq!(DataType::NA.common_type(&DataType::Text), Some(DataType::Text));
+    /// assert_eq!(DataType::Text.common_type(&DataType::Character), None);
+    /// ```
+    pub fn common_type(&self, other: &DataType) -> Option<DataType> {
+        match (self, other) {
+            (a, b) if a == b                                => Some(a.clone()),
+            (DataType::NA, b)                               => Some(b.clone()),
+            (a, DataType::NA)                               => Some(a.clone()),
+            (DataType::Date, DataType::DateTime) |
+            (DataType::DateTime, DataType::Date)            => Some(DataType::DateTime),
+            (a, b) if !a.is_numeric() || !b.is_numeric()    => None,
+            (DataType::Float, _) | (DataType::Double, _) |
+            (_, DataType::Float) | (_, DataType::Double)    => Some(DataType::Double),
+            (DataType::UInteger, DataType::ULong) |
+            (DataType::ULong, DataType::UInteger)           => Some(DataType::ULong),
+            (DataType::ULong, _) | (_, DataType::ULong)     => Some(DataType::Double),
+            _                                               => Some(DataType::Long),
+        }
+    }
+}
+
 
 
 #[cfg(test)]
@@ -1109,14 +1698,16 @@ mod tests {
         assert_eq!(DataEntry::Character('z'), entry.convert_to(&DataType::Character));
 
         let entry = DataEntry::Double(-42.4353f64);
-        assert_eq!(DataEntry::Integer(-42), entry.convert_to(&DataType::Integer));
+        assert!(matches!(entry.convert_to(&DataType::Integer), DataEntry::Integer(-42)));
     }
 
     #[test]
     fn addition() {
         let a = DataEntry::Integer(-34);
         let b = DataEntry::Integer(12);
-        assert_eq!(DataEntry::Integer(-22), a + b);
+        let c = a + b;
+        assert_eq!(DataEntry::Integer(-22), c);
+        assert_eq!(DataType::Integer, c.data_type());
 
         let a = DataEntry::Integer(-34);
         let b = DataEntry::Text("hello world".to_owned());
@@ -1124,7 +1715,9 @@ mod tests {
 
         let a = DataEntry::Double(-100_000.000_1);
         let b = DataEntry::Long(-12);
-        assert_eq!(DataEntry::Double(-100_012.000_1), a + b);
+        let c = a + b;
+        assert_eq!(DataEntry::Double(-100_012.000_1), c);
+        assert_eq!(DataType::Double, c.data_type());
 
         let a = DataEntry::Character('x');
         let b = DataEntry::Boolean(false);
@@ -1132,46 +1725,66 @@ mod tests {
 
         let a = DataEntry::ULong(123_456_789);
         let b = DataEntry::Boolean(true);
-        assert_eq!(DataEntry::ULong(123_456_790), a + b);
+        let c = a + b;
+        assert_eq!(DataEntry::ULong(123_456_790), c);
+        assert_eq!(DataType::ULong, c.data_type());
 
         let a = DataEntry::Boolean(false);
         let b = DataEntry::Double(234_567.120_345);
-        assert_eq!(DataEntry::Double(234_567.120_345), a + b);
+        let c = a + b;
+        assert_eq!(DataEntry::Double(234_567.120_345), c);
+        assert_eq!(DataType::Double, c.data_type());
 
         let a = DataEntry::Integer(-23);
         let b = DataEntry::ULong(234_567);
-        assert_eq!(DataEntry::Double(234_544f64), a + b);
+        let c = a + b;
+        assert_eq!(DataEntry::Double(234_544f64), c);
+        assert_eq!(DataType::Double, c.data_type());
 
         let a = DataEntry::Boolean(false);
         let b = DataEntry::Boolean(true);
-        assert_eq!(DataEntry::Integer(1), a + b);
+        let c = a + b;
+        assert_eq!(DataEntry::Integer(1), c);
+        assert_eq!(DataType::Integer, c.data_type());
     }
 
     #[test]
     fn substraction() {
         let a = DataEntry::Integer(-34);
         let b = DataEntry::Integer(12);
-        assert_eq!(DataEntry::Integer(-46), a - b);
+        let c = a - b;
+        assert_eq!(DataEntry::Integer(-46), c);
+        assert_eq!(DataType::Integer, c.data_type());
 
         let a = DataEntry::Double(-100_000.000_1);
         let b = DataEntry::Long(-12);
-        assert_eq!(DataEntry::Double(-99_988.000_1), a - b);
+        let c = a - b;
+        assert_eq!(DataEntry::Double(-99_988.000_1), c);
+        assert_eq!(DataType::Double, c.data_type());
 
         let a = DataEntry::ULong(123_456_789);
         let b = DataEntry::Boolean(true);
-        assert_eq!(DataEntry::Double(123_456_788f64), a - b);
+        let c = a - b;
+        assert_eq!(DataEntry::Double(123_456_788f64), c);
+        assert_eq!(DataType::Double, c.data_type());
 
         let a = DataEntry::Boolean(false);
         let b = DataEntry::Double(234_567.120_345);
-        assert_eq!(DataEntry::Double(-234_567.120_345), a - b);
+        let c = a - b;
+        assert_eq!(DataEntry::Double(-234_567.120_345), c);
+        assert_eq!(DataType::Double, c.data_type());
 
         let a = DataEntry::Integer(-23);
         let b = DataEntry::ULong(234_567);
-        assert_eq!(DataEntry::Double(-234_590f64), a - b);
+        let c = a - b;
+        assert_eq!(DataEntry::Double(-234_590f64), c);
+        assert_eq!(DataType::Double, c.data_type());
 
         let a = DataEntry::Boolean(false);
         let b = DataEntry::Boolean(true);
-        assert_eq!(DataEntry::Integer(-1), a - b);
+        let c = a - b;
+        assert_eq!(DataEntry::Integer(-1), c);
+        assert_eq!(DataType::Integer, c.data_type());
 
         let a = DataEntry::Character('x');
         let b = DataEntry::Boolean(false);
@@ -1188,7 +1801,9 @@ mod tests {
     fn multiplication() {
         let a = DataEntry::Integer(-34);
         let b = DataEntry::Integer(12);
-        assert_eq!(DataEntry::Integer(-408), a * b);
+        let c = a * b;
+        assert_eq!(DataEntry::Integer(-408), c);
+        assert_eq!(DataType::Integer, c.data_type());
 
         let a = DataEntry::Integer(-34);
         let b = DataEntry::Text("hello world".to_owned());
@@ -1196,7 +1811,9 @@ mod tests {
 
         let a = DataEntry::Double(-100_000.000_1);
         let b = DataEntry::Long(-12);
-        assert_eq!(DataEntry::Double(1_200_000.001_2), a * b);
+        let c = a * b;
+        assert_eq!(DataEntry::Double(1_200_000.001_2), c);
+        assert_eq!(DataType::Double, c.data_type());
 
         let a = DataEntry::Character('x');
         let b = DataEntry::Boolean(false);
@@ -1204,19 +1821,27 @@ mod tests {
 
         let a = DataEntry::ULong(123_456_789);
         let b = DataEntry::Boolean(true);
-        assert_eq!(DataEntry::Double(123_456_789f64), a * b);
+        let c = a * b;
+        assert_eq!(DataEntry::Double(123_456_789f64), c);
+        assert_eq!(DataType::Double, c.data_type());
 
         let a = DataEntry::Boolean(false);
         let b = DataEntry::Double(234_567.120_345);
-        assert_eq!(DataEntry::Double(0f64), a * b);
+        let c = a * b;
+        assert_eq!(DataEntry::Double(0f64), c);
+        assert_eq!(DataType::Double, c.data_type());
 
         let a = DataEntry::Integer(-23);
         let b = DataEntry::ULong(234_567);
-        assert_eq!(DataEntry::Double(-5_395_041f64), a * b);
+        let c = a * b;
+        assert_eq!(DataEntry::Double(-5_395_041f64), c);
+        assert_eq!(DataType::Double, c.data_type());
 
         let a = DataEntry::Boolean(false);
         let b = DataEntry::Boolean(true);
-        assert_eq!(DataEntry::Integer(0), a * b);
+        let c = a * b;
+        assert_eq!(DataEntry::Integer(0), c);
+        assert_eq!(DataType::Integer, c.data_type());
 
         let a = DataEntry::Text("hello ".to_owned());
         let b = DataEntry::Float(3.4);
@@ -1246,23 +1871,29 @@ mod tests {
     fn division() {
         let a = DataEntry::Integer(-34);
         let b = DataEntry::Integer(12);
-        assert_eq!(DataEntry::Integer(-2), a / b);
+        let c = a / b;
+        assert_eq!(DataEntry::Integer(-2), c);
+        assert_eq!(DataType::Integer, c.data_type());
 
         let a = DataEntry::Double(-100_000.000_1);
         let b = DataEntry::Long(-12);
         let c = a / b;
         assert!(DataEntry::Double(8_333.33334166) < c);
         assert!(DataEntry::Double(8_333.33334167) > c);
+        assert_eq!(DataType::Double, c.data_type());
 
         let a = DataEntry::Boolean(false);
         let b = DataEntry::Double(234_567.120_345);
-        assert_eq!(DataEntry::Double(0f64), a / b);
+        let c = a / b;
+        assert_eq!(DataEntry::Double(0f64), c);
+        assert_eq!(DataType::Double, c.data_type());
 
         let a = DataEntry::Integer(-23);
         let b = DataEntry::ULong(234_567);
         let c = a / b;
         assert!(DataEntry::Double(-9.8053008e-5f64) > c);
         assert!(DataEntry::Double(-9.8053009e-5f64) < c);
+        assert_eq!(DataType::Double, c.data_type());
 
         let a = DataEntry::Integer(-34);
         let b = DataEntry::Text("hello world".to_owned());
@@ -1296,6 +1927,64 @@ mod tests {
     }
 
     #[test]
+    fn checked_arithmetic() {
+        let result = DataEntry::Integer(3).checked_add(DataEntry::Double(0.5));
+        assert!(matches!(result, Ok(DataEntry::Double(_))));
+        assert_eq!(DataEntry::Double(3.5), result.unwrap());
+        let result = DataEntry::UInteger(2).checked_sub(DataEntry::Long(5));
+        assert!(matches!(result, Ok(DataEntry::Long(_))));
+        assert_eq!(DataEntry::Long(-3), result.unwrap());
+        let result = DataEntry::Character('a').checked_mul(DataEntry::Integer(-2));
+        assert_eq!(DataEntry::Text("".to_owned()), result.unwrap());
+        let result = DataEntry::NA.checked_div(DataEntry::Text("text".to_owned()));
+        assert_eq!(DataEntry::NA, result.unwrap());
+
+        let result = DataEntry::Long(i64::MIN).checked_sub(DataEntry::Boolean(true));
+        assert!(matches!(result, Err(RaccoonError::ArithmeticOverflow)));
+        let result = DataEntry::UInteger(u32::MAX).checked_mul(DataEntry::Boolean(true));
+        assert!(matches!(result, Err(RaccoonError::ArithmeticOverflow)));
+        let result = DataEntry::Integer(i32::MIN).checked_div(DataEntry::Integer(-1));
+        assert!(matches!(result, Err(RaccoonError::ArithmeticOverflow)));
+        let result = DataEntry::Text("text".to_owned()).checked_mul(f64::MAX.into());
+        assert!(matches!(result, Err(RaccoonError::ArithmeticOverflow)));
+
+        let result = DataEntry::ULong(1).checked_div(DataEntry::UInteger(0));
+        assert!(matches!(result, Err(RaccoonError::DivisionByZero)));
+        let result = DataEntry::Float(1.0).checked_div(DataEntry::Integer(0));
+        assert!(matches!(result, Ok(DataEntry::Float(_))));
+        assert_eq!(DataEntry::Float(f32::INFINITY), result.unwrap());
+
+        let result = DataEntry::Character('a').checked_mul(DataEntry::Character('b'));
+        assert!(matches!(result, Err(RaccoonError::UnsupportedOperation(ref op)) if op == "Character * Character"));
+        let result = DataEntry::Boolean(true).checked_div(DataEntry::Boolean(true));
+        assert!(matches!(result, Err(RaccoonError::UnsupportedOperation(_))));
+    }
+
+    #[test]
+    fn operators_do_not_panic() {
+        assert_eq!(DataEntry::NA, DataEntry::Integer(i32::MAX) + DataEntry::Integer(1));
+        assert_eq!(DataEntry::NA, DataEntry::Boolean(false) - DataEntry::ULong(1));
+        assert_eq!(DataEntry::NA, DataEntry::ULong(u64::MAX) * DataEntry::ULong(2));
+        assert_eq!(DataEntry::NA, DataEntry::Integer(1) / DataEntry::Integer(0));
+        assert_eq!(DataEntry::NA, DataEntry::Boolean(true) / DataEntry::Long(0));
+        assert!(matches!(DataEntry::UInteger(1) - DataEntry::Long(2), DataEntry::Long(-1)));
+    }
+
+    #[test]
+    fn comparing_values() {
+        assert_eq!(Some(Ordering::Less), DataEntry::Long(i64::MAX).compare(&DataEntry::Double(i64::MAX as f64)));
+        assert_eq!(Some(Ordering::Greater), DataEntry::ULong(u64::MAX).compare(&DataEntry::Float(1e19)));
+        assert_eq!(Some(Ordering::Less), DataEntry::Integer(-3).compare(&DataEntry::Double(-2.5)));
+        assert_eq!(Some(Ordering::Greater), DataEntry::Double(-2.5).compare(&DataEntry::Integer(-3)));
+        assert_eq!(Some(Ordering::Equal), DataEntry::Float(-0.0).compare(&DataEntry::UInteger(0)));
+        assert_eq!(Some(Ordering::Greater), DataEntry::Double(f64::INFINITY).compare(&DataEntry::ULong(u64::MAX)));
+        assert_eq!(None, DataEntry::Double(f64::NAN).compare(&DataEntry::Integer(0)));
+        assert_eq!(None, DataEntry::Boolean(true).compare(&DataEntry::Integer(1)));
+        assert_eq!(Some(Ordering::Less), DataEntry::Boolean(false).compare(&DataEntry::Boolean(true)));
+    }
+
+    #[test]
+    #[allow(clippy::neg_cmp_op_on_partial_ord)]
     fn ordering() {
         let a = DataEntry::Long(-234_567);
         let b = DataEntry::Long(-234_567);
@@ -1333,4 +2022,181 @@ mod tests {
         assert!(a < c);
         assert!(!(b >= c));
     }
+
+    #[test]
+    fn cross_type_comparison() {
+        assert_eq!(DataEntry::Integer(1), DataEntry::Long(1));
+        assert_eq!(DataEntry::ULong(3), DataEntry::Float(3.0));
+        assert_ne!(DataEntry::Integer(1), DataEntry::Double(1.5));
+        assert!(DataEntry::UInteger(2) < DataEntry::Double(2.5));
+        assert!(DataEntry::Long(-1) < DataEntry::ULong(0));
+        assert_ne!(DataEntry::Integer(1), DataEntry::Boolean(true));
+        assert_eq!(None, DataEntry::Character('a').partial_cmp(&DataEntry::Text("a".to_owned())));
+
+        assert_eq!(DataEntry::NA, DataEntry::NA);
+        assert_ne!(DataEntry::NA, DataEntry::Integer(0));
+        assert_eq!(None, DataEntry::NA.partial_cmp(&DataEntry::Integer(0)));
+        assert_ne!(DataEntry::Double(f64::NAN), DataEntry::Double(f64::NAN));
+        assert_eq!(None, DataEntry::Double(f64::NAN).partial_cmp(&DataEntry::Integer(0)));
+    }
+
+    #[test]
+    fn total_order() {
+        let mut entries: Vec<OrderedEntry> = vec![
+            DataEntry::NA,
+            DataEntry::Text("b".to_owned()),
+            DataEntry::Double(f64::NAN),
+            DataEntry::Character('z'),
+            DataEntry::Long(2),
+            DataEntry::Float(-0.0),
+            DataEntry::Boolean(true),
+            DataEntry::Text("a".to_owned()),
+            DataEntry::Boolean(false),
+            DataEntry::Integer(-3),
+        ].into_iter().map(OrderedEntry::from).collect();
+        entries.sort();
+        let sorted: Vec<DataEntry> = entries.into_iter().map(|entry| entry.0).collect();
+        assert_eq!(DataEntry::Boolean(false), sorted[0]);
+        assert_eq!(DataEntry::Boolean(true), sorted[1]);
+        assert_eq!(DataEntry::Integer(-3), sorted[2]);
+        assert_eq!(DataEntry::Double(0.0), sorted[3]);
+        assert_eq!(DataEntry::Long(2), sorted[4]);
+        assert!(is_nan(&sorted[5]));
+        assert_eq!(DataEntry::Character('z'), sorted[6]);
+        assert_eq!(DataEntry::Text("a".to_owned()), sorted[7]);
+        assert_eq!(DataEntry::Text("b".to_owned()), sorted[8]);
+        assert_eq!(DataEntry::NA, sorted[9]);
+
+        assert_eq!(OrderedEntry(DataEntry::Float(f32::NAN)), OrderedEntry(DataEntry::Double(f64::NAN)));
+        assert_eq!(OrderedEntry(DataEntry::Double(-0.0)), OrderedEntry(DataEntry::Integer(0)));
+        assert_eq!(OrderedEntry(DataEntry::NA), OrderedEntry(DataEntry::NA));
+    }
+
+    #[test]
+    fn hashing() {
+        use std::collections::HashMap;
+        use std::collections::hash_map::DefaultHasher;
+
+        fn hash(entry: DataEntry) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            OrderedEntry(entry).hash(&mut hasher);
+            hasher.finish()
+        }
+
+        assert_eq!(hash(DataEntry::Integer(1)), hash(DataEntry::Long(1)));
+        assert_eq!(hash(DataEntry::ULong(1)), hash(DataEntry::Double(1.0)));
+        assert_eq!(hash(DataEntry::Float(2.5)), hash(DataEntry::Double(2.5)));
+        assert_eq!(hash(DataEntry::Double(-0.0)), hash(DataEntry::Double(0.0)));
+        assert_eq!(hash(DataEntry::Double(f64::NAN)), hash(DataEntry::Float(-f32::NAN)));
+        assert_eq!(hash(DataEntry::Double(1e30)), hash(DataEntry::Double(1e30)));
+        assert_ne!(hash(DataEntry::Integer(1)), hash(DataEntry::Boolean(true)));
+
+        let mut counts = HashMap::new();
+        let entries = vec![
+            DataEntry::Integer(1), DataEntry::Long(1), DataEntry::Double(f64::NAN), DataEntry::Float(f32::NAN),
+            DataEntry::NA, DataEntry::NA, DataEntry::Text("a".to_owned()), DataEntry::Character('a'),
+        ];
+        for entry in entries {
+            *counts.entry(OrderedEntry(entry)).or_insert(0) += 1;
+        }
+        assert_eq!(5, counts.len());
+        assert_eq!(2, counts[&OrderedEntry(DataEntry::UInteger(1))]);
+        assert_eq!(2, counts[&OrderedEntry(DataEntry::Double(f64::NAN))]);
+        assert_eq!(2, counts[&OrderedEntry(DataEntry::NA)]);
+    }
+
+    #[test]
+    fn temporal_conversion() {
+        let date = NaiveDate::from_ymd_opt(2021, 3, 14).unwrap();
+        let entry = DataEntry::from("2021-03-14").convert_to(&DataType::Date);
+        assert_eq!(DataEntry::Date(date), entry);
+        assert_eq!(DataEntry::Long(18_700), entry.convert_to(&DataType::Long));
+        assert_eq!(entry, DataEntry::Long(18_700).convert_to(&DataType::Date));
+        assert_eq!(DataEntry::from("2021-03-14"), entry.convert_to(&DataType::Text));
+        assert_eq!(DataEntry::NA, entry.convert_to(&DataType::Integer));
+
+        let entry = DataEntry::from("2021-03-14T01:00:00+01:00").convert_to(&DataType::DateTime);
+        let seconds = 18_700 * 86_400;
+        assert_eq!(DataEntry::Long(seconds), entry.convert_to(&DataType::Long));
+        let utc = DataEntry::Long(seconds).convert_to(&DataType::DateTime);
+        assert_eq!(DataEntry::from("2021-03-14T00:00:00+00:00"), utc.convert_to(&DataType::Text));
+        assert_eq!(entry, utc);
+        assert_eq!(DataEntry::Date(date), entry.convert_to(&DataType::Date));
+        let naive = DataEntry::Date(date).convert_to(&DataType::DateTime);
+        assert_eq!(DataEntry::DateTime(date.and_hms_opt(0, 0, 0).unwrap(), None), naive);
+        assert_eq!(DataEntry::Long(seconds), naive.convert_to(&DataType::Long));
+        assert_eq!(DataEntry::from("2021-03-14T00:00:00"), naive.convert_to(&DataType::Text));
+
+        let entry = DataEntry::from("PT1M30S").convert_to(&DataType::Duration);
+        assert_eq!(DataEntry::Duration(Duration::seconds(90)), entry);
+        assert_eq!(DataEntry::Long(90), entry.convert_to(&DataType::Long));
+        assert_eq!(entry, DataEntry::Long(90).convert_to(&DataType::Duration));
+        assert_eq!(DataEntry::NA, DataEntry::Long(i64::MAX).convert_to(&DataType::Date));
+        assert_eq!(DataEntry::NA, DataEntry::Long(i64::MAX).convert_to(&DataType::DateTime));
+    }
+
+    #[test]
+    fn temporal_arithmetic() {
+        let date = NaiveDate::from_ymd_opt(2021, 3, 14).unwrap();
+        let offset = FixedOffset::east_opt(3600);
+        let start = DataEntry::DateTime(date.and_hms_opt(8, 0, 0).unwrap(), offset);
+        let end = DataEntry::DateTime(date.and_hms_opt(9, 30, 0).unwrap(), FixedOffset::east_opt(0));
+        let duration = DataEntry::Duration(Duration::minutes(150));
+        assert_eq!(duration, end.clone() - start.clone());
+        assert_eq!(DataEntry::DateTime(date.and_hms_opt(10, 30, 0).unwrap(), offset), start.clone() + duration.clone());
+        assert_eq!(start.clone() + duration.clone(), duration.clone() + start.clone());
+        assert_eq!(start.clone(), (start.clone() + duration.clone()) - duration.clone());
+
+        let naive = DataEntry::DateTime(date.and_hms_opt(8, 0, 0).unwrap(), None);
+        assert!(matches!(naive.clone().checked_sub(start.clone()), Err(RaccoonError::UnsupportedOperation(_))));
+        assert!(matches!(start.clone().checked_add(end.clone()), Err(RaccoonError::UnsupportedOperation(_))));
+        assert!(matches!(start.clone().checked_mul(DataEntry::Integer(2)), Err(RaccoonError::UnsupportedOperation(_))));
+        assert_eq!(DataEntry::NA, start.clone() + DataEntry::NA);
+
+        let day = DataEntry::Date(date.succ_opt().unwrap());
+        assert_eq!(DataEntry::Duration(Duration::days(1)), day.clone() - DataEntry::Date(date));
+        assert_eq!(naive, day - DataEntry::Duration(Duration::hours(16)));
+
+        assert_eq!(DataEntry::Duration(Duration::minutes(300)), duration.clone() * DataEntry::Long(2));
+        assert_eq!(DataEntry::Duration(Duration::minutes(300)), DataEntry::UInteger(2) * duration.clone());
+        assert_eq!(DataEntry::Duration(Duration::minutes(50)), duration.clone() / DataEntry::Integer(3));
+        assert_eq!(DataEntry::Double(2.5), duration.clone() / DataEntry::Duration(Duration::hours(1)));
+        assert_eq!(DataEntry::Duration(Duration::zero()), duration.clone() - duration.clone());
+        assert!(matches!(duration.clone().checked_div(DataEntry::Long(0)), Err(RaccoonError::DivisionByZero)));
+        assert!(matches!(duration.checked_mul(DataEntry::Double(1.5)), Err(RaccoonError::UnsupportedOperation(_))));
+        let max = DataEntry::Duration(Duration::MAX);
+        assert!(matches!(max.clone().checked_add(max), Err(RaccoonError::ArithmeticOverflow)));
+    }
+
+    #[test]
+    fn temporal_order() {
+        use std::collections::HashMap;
+
+        let date = NaiveDate::from_ymd_opt(2021, 3, 14).unwrap().and_hms_opt(12, 0, 0).unwrap();
+        let utc = DataEntry::DateTime(date, FixedOffset::east_opt(0));
+        let cet = DataEntry::DateTime(date + Duration::hours(1), FixedOffset::east_opt(3600));
+        let naive = DataEntry::DateTime(date, None);
+        assert_eq!(utc, cet);
+        assert_eq!(None, utc.compare(&naive));
+        assert!(naive < DataEntry::DateTime(date + Duration::seconds(1), None));
+
+        let mut entries = [
+            OrderedEntry(DataEntry::Duration(Duration::seconds(1))),
+            OrderedEntry(cet.clone()),
+            OrderedEntry(naive.clone()),
+            OrderedEntry(DataEntry::Date(date.date())),
+            OrderedEntry(DataEntry::Integer(1)),
+        ];
+        entries.sort();
+        assert_eq!(OrderedEntry(DataEntry::Integer(1)), entries[0]);
+        assert_eq!(OrderedEntry(DataEntry::Date(date.date())), entries[1]);
+        assert_eq!(OrderedEntry(naive), entries[2]);
+        assert_eq!(OrderedEntry(utc.clone()), entries[3]);
+
+        let mut counts = HashMap::new();
+        for entry in [utc.clone(), cet, DataEntry::DateTime(date, None)] {
+            *counts.entry(OrderedEntry(entry)).or_insert(0) += 1;
+        }
+        assert_eq!(2, counts[&OrderedEntry(utc)]);
+    }
 }
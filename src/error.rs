@@ -1,16 +1,112 @@
 //! Error module.
 
-/// The result type used by the `raccoon` library.
-pub type RaccoonResult = ::std::result::Result<(), RaccoonError>;
+use entry::DataType;
+
+/// The result type used by the `raccoon` library. Operations that only succeed or fail use the default `()` value.
+pub type RaccoonResult<T = ()> = ::std::result::Result<T, RaccoonError>;
 
 quick_error! {
     /// Contains all errors used by the `raccoon` library.
-    #[derive(Debug, Clone, PartialEq, Eq)]
+    // `quick_error` does not support documenting the fields of struct variants, their meaning is given in the variant
+    // documentation instead.
+    #[allow(missing_docs)]
+    #[derive(Debug)]
     pub enum RaccoonError {
-        /// Invalid type. Thrown when an argument has an invalid type.
-        InvalidType {
-            description("Invalid data type")
-            display("Invalid data type")
+        /// Type mismatch. Thrown when an entry or a series does not have the data type required by an operation.
+        /// `expected` is the required data type and `found` the actual one.
+        TypeMismatch { expected: DataType, found: DataType } {
+            description("Type mismatch")
+            display("Type mismatch: expected {:?}, found {:?}", expected, found)
+        }
+        /// Length mismatch. Thrown when a series does not have the length required by an operation. `expected` is the
+        /// required length and `found` the actual one.
+        LengthMismatch { expected: usize, found: usize } {
+            description("Length mismatch")
+            display("Length mismatch: expected {} entries, found {}", expected, found)
+        }
+        /// Column not found. Thrown when no column with the requested name exists.
+        ColumnNotFound(name: String) {
+            description("Column not found")
+            display("Column not found: {}", name)
+        }
+        /// Label not found. Thrown when an index does not contain a requested label.
+        LabelNotFound(label: String) {
+            description("Label not found")
+            display("Label not found: {}", label)
+        }
+        /// Level not found. Thrown when an index has no level at the requested position or with the requested name.
+        LevelNotFound(level: String) {
+            description("Level not found")
+            display("Level not found: {}", level)
+        }
+        /// Index out of bounds. Thrown when a position lies outside of a series or a dataframe. `index` is the
+        /// requested position and `length` the number of available positions.
+        IndexOutOfBounds { index: usize, length: usize } {
+            description("Index out of bounds")
+            display("Index out of bounds: the length is {} but the index is {}", length, index)
+        }
+        /// Duplicate column. Thrown when a column with the same name already exists.
+        DuplicateColumn(name: String) {
+            description("Duplicate column")
+            display("Duplicate column: {}", name)
+        }
+        /// Parse error. Thrown when a value read from a source cannot be parsed into the data type of its column. `row`
+        /// is the position of the record in the data (header excluded), `column` the name of the column and `value`
+        /// the unparsed text.
+        Parse { row: usize, column: String, value: String } {
+            description("Parse error")
+            display("Parse error: cannot parse {:?} in column {} at row {}", value, column, row)
+        }
+        /// I/O error. Thrown when reading from or writing to a file or stream fails.
+        Io(err: ::std::io::Error) {
+            from()
+            description("I/O error")
+            display("I/O error: {}", err)
+            cause(err)
+        }
+        /// CSV error. Thrown when a CSV source is malformed.
+        Csv(err: ::csv::Error) {
+            from()
+            description("CSV error")
+            display("CSV error: {}", err)
+            cause(err)
+        }
+        /// Excel error. Thrown when an Excel workbook cannot be opened or is malformed.
+        Xlsx(err: ::calamine::XlsxError) {
+            from()
+            description("Excel error")
+            display("Excel error: {}", err)
+            cause(err)
+        }
+        /// Sheet not found. Thrown when a workbook contains no sheet with the requested name or position.
+        SheetNotFound(sheet: String) {
+            description("Sheet not found")
+            display("Sheet not found: {}", sheet)
+        }
+        /// Invalid range. Thrown when a cell range is not in `A1:C10` notation.
+        InvalidRange(range: String) {
+            description("Invalid cell range")
+            display("Invalid cell range: {}", range)
+        }
+        /// Arithmetic overflow. Thrown when the result of an arithmetic operation does not fit into its data type.
+        ArithmeticOverflow {
+            description("Arithmetic overflow")
+            display("Arithmetic overflow")
+        }
+        /// Division by zero. Thrown when an integer is divided by zero.
+        DivisionByZero {
+            description("Division by zero")
+            display("Division by zero")
+        }
+        /// Invalid argument. Thrown when an argument lies outside of the values accepted by an operation.
+        InvalidArgument(message: String) {
+            description("Invalid argument")
+            display("Invalid argument: {}", message)
+        }
+        /// Unsupported operation. Thrown when an operation is not defined for the data types of its operands.
+        UnsupportedOperation(operation: String) {
+            description("Unsupported operation")
+            display("Unsupported operation: {}", operation)
         }
     }
 }
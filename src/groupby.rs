@@ -0,0 +1,558 @@
+//! Split-apply-combine operations on a [`DataFrame`], similar to python's `pandas.DataFrame.groupby`.
+//!
+//! [`DataFrame`]: ../dataframe/struct.DataFrame.html
+//!
+//! A [`GroupBy`] is obtained using [`DataFrame::groupby`]. It splits the rows of the frame into groups sharing the same
+//! values in the key columns, applies an aggregation to every other column of each group, and combines the results
+//! into a new `DataFrame` holding one row per group. The key columns come first in the result, followed by the
+//! aggregated columns in the order of the original frame.
+//!
+//! [`GroupBy`]: ./struct.GroupBy.html
+//! [`DataFrame::groupby`]: ../dataframe/struct.DataFrame.html#method.groupby
+//!
+//! Keys are compared like [`OrderedEntry`]s, hence `DataEntry::Integer(1)` and `DataEntry::Double(1.0)` fall into the
+//! same group. By default, groups appear in the order in which their key first appears in the frame, and rows having a
+//! missing key, that is `DataEntry::NA` or NaN, are dropped.
+//!
+//! [`OrderedEntry`]: ../entry/struct.OrderedEntry.html
+//!
+//! # Examples
+//! ```
+//! use raccoon::{DataEntry, DataFrame, Series};
+//!
+//! let df = DataFrame::from_series(vec![
+//!     Series::from_vector("canton".to_owned(), vec!["ZH", "BE", "ZH", "BE", "GE"]),
+//!     Series::from_vector("population".to_owned(), vec![415, 134, 91, 43, 201]),
+//! ]).unwrap();
+//!
+//! let totals = df.groupby(&["canton"]).unwrap().sum().unwrap();
+//! assert_eq!(totals.columns(), vec!["canton", "population"]);
+//! assert_eq!(totals["canton"], vec!["ZH", "BE", "GE"]);
+//! assert_eq!(totals["population"], vec![506i64, 177, 201]);
+//! ```
+
+use dataframe::DataFrame;
+use entry::{DataEntry, DataType, OrderedEntry};
+use error::{RaccoonResult, RaccoonError};
+use series::Series;
+
+use std::collections::{HashMap, HashSet};
+
+/// An aggregation applied to the columns of each group. See [`GroupBy::agg`].
+///
+/// [`GroupBy::agg`]: ./struct.GroupBy.html#method.agg
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aggregation {
+    /// The sum of the entries, see `Series::sum`.
+    Sum,
+    /// The product of the entries, see `Series::prod`.
+    Prod,
+    /// The arithmetic mean of the entries, see `Series::mean`.
+    Mean,
+    /// The median of the entries, see `Series::median`.
+    Median,
+    /// The sample variance of the entries, see `Series::var`.
+    Var,
+    /// The sample standard deviation of the entries, see `Series::std`.
+    Std,
+    /// The number of entries that are not `DataEntry::NA`, see `Series::count`.
+    Count,
+    /// The smallest entry, see `Series::min`.
+    Min,
+    /// The largest entry, see `Series::max`.
+    Max,
+    /// The first entry that is not missing.
+    First,
+    /// The last entry that is not missing.
+    Last,
+    /// The number of distinct entries that are not missing.
+    NUnique,
+}
+
+impl Aggregation {
+    /// Aggregates the entries of `series`.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{Aggregation, DataEntry, Series};
+    /// let series = Series::from(vec![DataEntry::NA, DataEntry::Integer(3), DataEntry::Integer(3)]);
+    /// assert_eq!(Aggregation::First.apply(&series).unwrap(), DataEntry::Integer(3));
+    /// assert_eq!(Aggregation::NUnique.apply(&series).unwrap(), DataEntry::ULong(1));
+    /// ```
+    pub fn apply(&self, series: &Series) -> RaccoonResult<DataEntry> {
+        match *self {
+            Aggregation::Sum        => series.sum(),
+            Aggregation::Prod       => series.prod(),
+            Aggregation::Mean       => series.mean(),
+            Aggregation::Median     => series.median(),
+            Aggregation::Var        => series.var(),
+            Aggregation::Std        => series.std(),
+            Aggregation::Count      => series.count(),
+            Aggregation::Min        => series.min(),
+            Aggregation::Max        => series.max(),
+            Aggregation::First      => Ok(series.iter().find(|x| !x.is_missing()).unwrap_or(DataEntry::NA)),
+            Aggregation::Last       => {
+                Ok(series.iter().filter(|x| !x.is_missing()).last().unwrap_or(DataEntry::NA))
+            },
+            Aggregation::NUnique    => {
+                let distinct: HashSet<OrderedEntry> = series.iter()
+                    .filter(|x| !x.is_missing())
+                    .map(OrderedEntry)
+                    .collect();
+                Ok(DataEntry::ULong(distinct.len() as u64))
+            },
+        }
+    }
+
+    /// Returns the data type of the aggregation of a series of `data_type`.
+    fn data_type(&self, data_type: &DataType) -> DataType {
+        match *self {
+            Aggregation::Sum | Aggregation::Prod    => match *data_type {
+                DataType::Integer | DataType::Long      => DataType::Long,
+                DataType::UInteger | DataType::ULong    => DataType::ULong,
+                _                                       => DataType::Double,
+            },
+            Aggregation::Mean | Aggregation::Median |
+            Aggregation::Var | Aggregation::Std     => DataType::Double,
+            Aggregation::Count | Aggregation::NUnique => DataType::ULong,
+            Aggregation::Min | Aggregation::Max |
+            Aggregation::First | Aggregation::Last  => data_type.clone(),
+        }
+    }
+}
+
+/// The rows of a dataframe grouped by the values of key columns, see `raccoon::groupby`.
+///
+/// # Example
+/// ```
+/// use raccoon::{DataFrame, Series};
+///
+/// let df = DataFrame::from_series(vec![
+///     Series::from_vector("shop".to_owned(), vec!["b", "a", "b", "a"]),
+///     Series::from_vector("item".to_owned(), vec!['x', 'x', 'y', 'x']),
+///     Series::from_vector("sold".to_owned(), vec![3, 1, 4, 1]),
+/// ]).unwrap();
+///
+/// let grouped = df.groupby(&["shop", "item"]).unwrap().sort(true);
+/// assert_eq!(grouped.ngroups(), 3);
+///
+/// let means = grouped.mean().unwrap();
+/// assert_eq!(means["shop"], vec!["a", "b", "b"]);
+/// assert_eq!(means["item"], vec!['x', 'x', 'y']);
+/// assert_eq!(means["sold"], vec![1.0f64, 3.0, 4.0]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct GroupBy<'a> {
+    df: &'a DataFrame,
+    keys: Vec<String>,
+    groups: Vec<Group>,
+    sort: bool,
+    dropna: bool,
+}
+
+/// The key and the row positions of a group.
+#[derive(Debug, Clone)]
+struct Group {
+    key: Vec<OrderedEntry>,
+    positions: Vec<usize>,
+}
+
+impl<'a> GroupBy<'a> {
+    /// Groups the rows of `df` by the values of the `keys` columns.
+    ///
+    /// # Errors
+    /// - `RaccoonError::InvalidArgument` if no key column is given.
+    /// - `RaccoonError::ColumnNotFound` if a key column does not exist.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataFrame, GroupBy, Series};
+    /// let df = DataFrame::from_series(vec![Series::from_vector("a".to_owned(), vec![1, 2, 1])]).unwrap();
+    /// assert_eq!(GroupBy::new(&df, &["a"]).unwrap().ngroups(), 2);
+    /// assert!(GroupBy::new(&df, &["b"]).is_err());
+    /// ```
+    pub fn new(df: &'a DataFrame, keys: &[&str]) -> RaccoonResult<GroupBy<'a>> {
+        if keys.is_empty() {
+            return Err(RaccoonError::InvalidArgument("groupby requires at least one key column".to_owned()));
+        }
+        let columns = keys.iter()
+            .map(|key| df.column(key).ok_or_else(|| RaccoonError::ColumnNotFound(key.to_string())))
+            .collect::<RaccoonResult<Vec<_>>>()?;
+
+        let mut groups: Vec<Group> = Vec::new();
+        let mut lookup: HashMap<Vec<OrderedEntry>, usize> = HashMap::new();
+        let mut entries: Vec<_> = columns.iter().map(|series| series.iter()).collect();
+        for row in 0..df.len() {
+            let key: Vec<OrderedEntry> = entries.iter_mut()
+                .map(|iter| OrderedEntry(iter.next().unwrap_or(DataEntry::NA)))
+                .collect();
+            let idx = *lookup.entry(key.clone()).or_insert_with(|| {
+                groups.push(Group { key, positions: Vec::new() });
+                groups.len() - 1
+            });
+            groups[idx].positions.push(row);
+        }
+
+        Ok(GroupBy {
+            df,
+            keys: keys.iter().map(|key| key.to_string()).collect(),
+            groups,
+            sort: false,
+            dropna: true,
+        })
+    }
+
+    /// Sets whether groups are sorted by their keys, rather than kept in the order of first appearance. Defaults to
+    /// false.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataFrame, Series};
+    /// let df = DataFrame::from_series(vec![Series::from_vector("a".to_owned(), vec![3, 1, 2, 1])]).unwrap();
+    /// let grouped = df.groupby(&["a"]).unwrap();
+    /// assert_eq!(grouped.count().unwrap()["a"], vec![3, 1, 2]);
+    /// assert_eq!(grouped.sort(true).count().unwrap()["a"], vec![1, 2, 3]);
+    /// ```
+    pub fn sort(mut self, sort: bool) -> GroupBy<'a> {
+        self.sort = sort;
+        self
+    }
+
+    /// Sets whether rows having a missing entry, that is `DataEntry::NA` or NaN, in a key column are dropped. If they
+    /// are kept, `DataEntry::NA` and NaN each form a group of their own. Defaults to true.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataEntry, DataFrame, Series};
+    /// let keys = Series::from_vector("a".to_owned(), vec![DataEntry::NA, DataEntry::Integer(1)]);
+    /// let df = DataFrame::from_series(vec![keys]).unwrap();
+    /// assert_eq!(df.groupby(&["a"]).unwrap().ngroups(), 1);
+    /// assert_eq!(df.groupby(&["a"]).unwrap().dropna(false).ngroups(), 2);
+    /// ```
+    pub fn dropna(mut self, dropna: bool) -> GroupBy<'a> {
+        self.dropna = dropna;
+        self
+    }
+
+    /// Returns the number of groups.
+    pub fn ngroups(&self) -> usize {
+        self.groups().len()
+    }
+
+    /// Returns the sum of each column for each group. See [`Aggregation`] for the other aggregations.
+    ///
+    /// [`Aggregation`]: ./enum.Aggregation.html
+    pub fn sum(&self) -> RaccoonResult<DataFrame> {
+        self.aggregate(Aggregation::Sum)
+    }
+
+    /// Returns the arithmetic mean of each column for each group.
+    pub fn mean(&self) -> RaccoonResult<DataFrame> {
+        self.aggregate(Aggregation::Mean)
+    }
+
+    /// Returns the number of entries that are not `DataEntry::NA` in each column for each group.
+    pub fn count(&self) -> RaccoonResult<DataFrame> {
+        self.aggregate(Aggregation::Count)
+    }
+
+    /// Returns the smallest entry of each column for each group.
+    pub fn min(&self) -> RaccoonResult<DataFrame> {
+        self.aggregate(Aggregation::Min)
+    }
+
+    /// Returns the largest entry of each column for each group.
+    pub fn max(&self) -> RaccoonResult<DataFrame> {
+        self.aggregate(Aggregation::Max)
+    }
+
+    /// Returns the first entry that is not missing of each column for each group.
+    pub fn first(&self) -> RaccoonResult<DataFrame> {
+        self.aggregate(Aggregation::First)
+    }
+
+    /// Returns the last entry that is not missing of each column for each group.
+    pub fn last(&self) -> RaccoonResult<DataFrame> {
+        self.aggregate(Aggregation::Last)
+    }
+
+    /// Returns the number of distinct entries that are not missing of each column for each group.
+    pub fn nunique(&self) -> RaccoonResult<DataFrame> {
+        self.aggregate(Aggregation::NUnique)
+    }
+
+    /// Applies `aggregation` to every column which is not a key column.
+    ///
+    /// # Errors
+    /// Any error returned by the aggregation, for instance `RaccoonError::UnsupportedOperation` when computing the
+    /// mean of a text column.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{Aggregation, DataFrame, Series};
+    /// let df = DataFrame::from_series(vec![
+    ///     Series::from_vector("key".to_owned(), vec!['a', 'b', 'a']),
+    ///     Series::from_vector("value".to_owned(), vec![2, 7, 4]),
+    /// ]).unwrap();
+    ///
+    /// let grouped = df.groupby(&["key"]).unwrap();
+    /// assert_eq!(grouped.aggregate(Aggregation::Prod).unwrap()["value"], vec![8i64, 7]);
+    /// ```
+    pub fn aggregate(&self, aggregation: Aggregation) -> RaccoonResult<DataFrame> {
+        let columns = self.values().map(|series| (series, aggregation)).collect();
+        self.combine(columns)
+    }
+
+    /// Applies an aggregation per column. Only the columns in `aggregations` are kept, in the order of the original
+    /// dataframe.
+    ///
+    /// # Errors
+    /// - `RaccoonError::ColumnNotFound` if a column does not exist.
+    /// - `RaccoonError::DuplicateColumn` if a key column is aggregated.
+    /// - Any error returned by an aggregation.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{Aggregation, DataFrame, Series};
+    /// # use std::collections::HashMap;
+    /// let df = DataFrame::from_series(vec![
+    ///     Series::from_vector("key".to_owned(), vec!['a', 'b', 'a']),
+    ///     Series::from_vector("x".to_owned(), vec![2, 7, 4]),
+    ///     Series::from_vector("y".to_owned(), vec!["u", "v", "w"]),
+    /// ]).unwrap();
+    ///
+    /// let mut aggregations = HashMap::new();
+    /// aggregations.insert("y", Aggregation::Last);
+    /// aggregations.insert("x", Aggregation::Max);
+    ///
+    /// let result = df.groupby(&["key"]).unwrap().agg(&aggregations).unwrap();
+    /// assert_eq!(result.columns(), vec!["key", "x", "y"]);
+    /// assert_eq!(result["x"], vec![4, 7]);
+    /// assert_eq!(result["y"], vec!["w", "v"]);
+    /// ```
+    pub fn agg(&self, aggregations: &HashMap<&str, Aggregation>) -> RaccoonResult<DataFrame> {
+        if let Some(name) = aggregations.keys().find(|name| self.df.column(name).is_none()) {
+            return Err(RaccoonError::ColumnNotFound(name.to_string()));
+        }
+        let columns = self.df.iter()
+            .filter_map(|series| aggregations.get(series.name()).map(|aggregation| (series, *aggregation)))
+            .collect();
+        self.combine(columns)
+    }
+
+    /// Applies a custom aggregation to every column which is not a key column. The closure is called with a series
+    /// holding the entries of a group, and named after the column. The data type of each resulting column is the data
+    /// type of its first entry that is not `DataEntry::NA`.
+    ///
+    /// # Errors
+    /// Any error returned by `function`.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataEntry, DataFrame, Series};
+    /// let df = DataFrame::from_series(vec![
+    ///     Series::from_vector("key".to_owned(), vec!['a', 'b', 'a', 'a']),
+    ///     Series::from_vector("value".to_owned(), vec![2.0, 7.0, 4.0, 6.0]),
+    /// ]).unwrap();
+    ///
+    /// let range = df.groupby(&["key"]).unwrap().apply(|group| {
+    ///     let max = group.max()?;
+    ///     let min = group.min()?;
+    ///     Ok(max - min)
+    /// }).unwrap();
+    /// assert_eq!(range["value"], vec![4.0, 0.0]);
+    /// ```
+    pub fn apply<F>(&self, function: F) -> RaccoonResult<DataFrame> where F: Fn(&Series) -> RaccoonResult<DataEntry> {
+        let groups = self.groups();
+        let mut df = self.keys(&groups)?;
+        for series in self.values() {
+            let entries = groups.iter()
+                .map(|group| function(&series.take(&group.positions)?))
+                .collect::<RaccoonResult<Vec<_>>>()?;
+            df.add_column(Series::from_vector(series.name().to_owned(), entries))?;
+        }
+        Ok(df)
+    }
+
+    /// Returns the groups in the configured order, without the dropped groups.
+    fn groups(&self) -> Vec<&Group> {
+        let mut groups: Vec<&Group> = self.groups.iter()
+            .filter(|group| !self.dropna || group.key.iter().all(|entry| !entry.0.is_missing()))
+            .collect();
+        if self.sort {
+            groups.sort_by(|a, b| a.key.cmp(&b.key));
+        }
+        groups
+    }
+
+    /// Returns the columns which are not key columns.
+    fn values(&self) -> impl Iterator<Item = &'a Series> + '_ {
+        self.df.iter().filter(move |series| !self.keys.iter().any(|key| key == series.name()))
+    }
+
+    /// Builds a dataframe holding the key columns of `groups`.
+    fn keys(&self, groups: &[&Group]) -> RaccoonResult<DataFrame> {
+        let firsts: Vec<usize> = groups.iter().map(|group| group.positions[0]).collect();
+        let mut df = DataFrame::with_capacity(self.keys.len());
+        for key in &self.keys {
+            df.add_column(self.df[key.as_str()].take(&firsts)?)?;
+        }
+        Ok(df)
+    }
+
+    /// Applies an aggregation to each column of each group, and combines the results with the key columns.
+    fn combine(&self, columns: Vec<(&Series, Aggregation)>) -> RaccoonResult<DataFrame> {
+        let groups = self.groups();
+        let mut df = self.keys(&groups)?;
+        for (series, aggregation) in columns {
+            let entries = groups.iter()
+                .map(|group| aggregation.apply(&series.take(&group.positions)?))
+                .collect::<RaccoonResult<Vec<_>>>()?;
+            let data_type = aggregation.data_type(series.data_type());
+            df.add_column(Series::from_entries(series.name().to_owned(), data_type, entries))?;
+        }
+        Ok(df)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn group_order() {
+        let df = DataFrame::from_series(vec![
+            Series::from_vector("region".to_owned(), vec!["north", "south", "north", "east", "south", "north"]),
+            Series::from_vector("year".to_owned(), vec![2020, 2020, 2021, 2020, 2020, 2020]),
+            Series::from_vector("units".to_owned(), vec![
+                DataEntry::Integer(10), DataEntry::Integer(4), DataEntry::NA,
+                DataEntry::Integer(7), DataEntry::Integer(6), DataEntry::Integer(2),
+            ]),
+            Series::from_vector("product".to_owned(), vec!["pen", "ink", "pen", "pad", "ink", "pad"]),
+        ]).unwrap();
+        let grouped = df.groupby(&["region"]).unwrap();
+        assert_eq!(3, grouped.ngroups());
+        let counts = grouped.count().unwrap();
+        assert_eq!(vec!["region", "year", "units", "product"], counts.columns());
+        assert_eq!(counts["region"], vec!["north", "south", "east"]);
+        assert_eq!(counts["units"], vec![2u64, 2, 1]);
+        assert_eq!(counts["product"], vec![3u64, 2, 1]);
+
+        let sorted = df.groupby(&["region"]).unwrap().sort(true).first().unwrap();
+        assert_eq!(sorted["region"], vec!["east", "north", "south"]);
+        assert_eq!(sorted["product"], vec!["pad", "pen", "ink"]);
+        assert_eq!(sorted["units"], vec![7, 10, 4]);
+    }
+
+    #[test]
+    fn multiple_keys() {
+        let df = DataFrame::from_series(vec![
+            Series::from_vector("region".to_owned(), vec!["north", "south", "north", "east", "south", "north"]),
+            Series::from_vector("year".to_owned(), vec![2020, 2020, 2021, 2020, 2020, 2020]),
+            Series::from_vector("units".to_owned(), vec![
+                DataEntry::Integer(10), DataEntry::Integer(4), DataEntry::NA,
+                DataEntry::Integer(7), DataEntry::Integer(6), DataEntry::Integer(2),
+            ]),
+            Series::from_vector("product".to_owned(), vec!["pen", "ink", "pen", "pad", "ink", "pad"]),
+        ]).unwrap();
+        let grouped = df.groupby(&["year", "region"]).unwrap();
+        assert_eq!(4, grouped.ngroups());
+
+        let mut aggregations = HashMap::new();
+        aggregations.insert("units", Aggregation::Sum);
+        aggregations.insert("product", Aggregation::NUnique);
+        let result = grouped.agg(&aggregations).unwrap();
+        assert_eq!(vec!["year", "region", "units", "product"], result.columns());
+        assert_eq!(result["year"], vec![2020, 2020, 2021, 2020]);
+        assert_eq!(result["region"], vec!["north", "south", "north", "east"]);
+        assert_eq!(result["units"], vec![12i64, 10, 0, 7]);
+        assert_eq!(result["product"], vec![2u64, 1, 1, 1]);
+
+        let result = grouped.max().unwrap();
+        assert_eq!(&DataType::Integer, result["units"].data_type());
+        assert_eq!(DataEntry::NA, result["units"].get(2).unwrap());
+        assert_eq!(result["product"], vec!["pen", "ink", "pen", "pad"]);
+        assert_eq!(grouped.last().unwrap()["product"], vec!["pad", "ink", "pen", "pad"]);
+        assert_eq!(grouped.min().unwrap()["units"].get(0).unwrap(), DataEntry::Integer(2));
+    }
+
+    #[test]
+    fn numeric_keys_and_na() {
+        let df = DataFrame::from_series(vec![
+            Series::from_vector("key".to_owned(), vec![
+                DataEntry::Double(1.0), DataEntry::NA, DataEntry::Double(f64::NAN),
+                DataEntry::Double(1.0), DataEntry::Double(f64::NAN),
+            ]),
+            Series::from_vector("value".to_owned(), vec![1.0, 2.0, 3.0, 4.0, 5.0]),
+        ]).unwrap();
+
+        let means = df.groupby(&["key"]).unwrap().mean().unwrap();
+        assert_eq!(1, means.len());
+        assert_eq!(means["value"], vec![2.5]);
+
+        let sums = df.groupby(&["key"]).unwrap().dropna(false).sort(true).sum().unwrap();
+        assert_eq!(DataEntry::NA, sums["key"].get(2).unwrap());
+        assert_eq!(sums["value"], vec![5.0, 8.0, 2.0]);
+    }
+
+    #[test]
+    fn skipping_nan() {
+        let series = Series::from(vec![f64::NAN, 2.0, f64::NAN, 2.0, 3.0, f64::NAN]);
+        assert_eq!(DataEntry::Double(2.0), Aggregation::First.apply(&series).unwrap());
+        assert_eq!(DataEntry::Double(3.0), Aggregation::Last.apply(&series).unwrap());
+        assert_eq!(DataEntry::ULong(2), Aggregation::NUnique.apply(&series).unwrap());
+
+        let series = Series::from(vec![f32::NAN]);
+        assert_eq!(DataEntry::NA, Aggregation::First.apply(&series).unwrap());
+        assert_eq!(DataEntry::ULong(0), Aggregation::NUnique.apply(&series).unwrap());
+    }
+
+    #[test]
+    fn custom_aggregations() {
+        let df = DataFrame::from_series(vec![
+            Series::from_vector("region".to_owned(), vec!["north", "south", "north", "east", "south", "north"]),
+            Series::from_vector("year".to_owned(), vec![2020, 2020, 2021, 2020, 2020, 2020]),
+            Series::from_vector("units".to_owned(), vec![
+                DataEntry::Integer(10), DataEntry::Integer(4), DataEntry::NA,
+                DataEntry::Integer(7), DataEntry::Integer(6), DataEntry::Integer(2),
+            ]),
+            Series::from_vector("product".to_owned(), vec!["pen", "ink", "pen", "pad", "ink", "pad"]),
+        ]).unwrap();
+        let grouped = df.groupby(&["region", "year", "product"]).unwrap();
+        let sizes = grouped.apply(|group| Ok(DataEntry::ULong(group.len() as u64))).unwrap();
+        assert_eq!(sizes["units"], vec![1u64, 2, 1, 1, 1]);
+
+        let result = df.groupby(&["region"]).unwrap()
+            .apply(|group| if group.name() == "units" { group.max() } else { Ok(DataEntry::NA) })
+            .unwrap();
+        assert_eq!(result["units"], vec![10, 6, 7]);
+        assert_eq!(&DataType::NA, result["product"].data_type());
+    }
+
+    #[test]
+    fn errors() {
+        let df = DataFrame::from_series(vec![
+            Series::from_vector("region".to_owned(), vec!["north", "south", "north", "east", "south", "north"]),
+            Series::from_vector("year".to_owned(), vec![2020, 2020, 2021, 2020, 2020, 2020]),
+            Series::from_vector("units".to_owned(), vec![
+                DataEntry::Integer(10), DataEntry::Integer(4), DataEntry::NA,
+                DataEntry::Integer(7), DataEntry::Integer(6), DataEntry::Integer(2),
+            ]),
+            Series::from_vector("product".to_owned(), vec!["pen", "ink", "pen", "pad", "ink", "pad"]),
+        ]).unwrap();
+        assert!(matches!(df.groupby(&[]), Err(RaccoonError::InvalidArgument(_))));
+        assert!(matches!(df.groupby(&["country"]), Err(RaccoonError::ColumnNotFound(ref name)) if name == "country"));
+
+        let grouped = df.groupby(&["region"]).unwrap();
+        assert!(matches!(grouped.mean(), Err(RaccoonError::UnsupportedOperation(_))));
+
+        let mut aggregations = HashMap::new();
+        aggregations.insert("price", Aggregation::Sum);
+        assert!(matches!(grouped.agg(&aggregations), Err(RaccoonError::ColumnNotFound(ref name)) if name == "price"));
+
+        let mut aggregations = HashMap::new();
+        aggregations.insert("region", Aggregation::Count);
+        assert!(matches!(grouped.agg(&aggregations), Err(RaccoonError::DuplicateColumn(_))));
+    }
+}
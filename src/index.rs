@@ -0,0 +1,757 @@
+//! Row labels of a [`DataFrame`] or a [`Series`], similar to python's `pandas.Index`.
+//!
+//! [`DataFrame`]: ../dataframe/struct.DataFrame.html
+//! [`Series`]: ../series/struct.Series.html
+//!
+//! Every dataframe has an [`Index`] labelling its rows, shared by the series forming its columns. A series on its own
+//! is labelled by a range index unless given another index using [`Series::with_index`]. Indexes come in three kinds:
+//! - A **range** index, the default, labels the rows by their positions as `DataType::Long` entries. It does not store
+//!   any labels.
+//! - A **label** index holds a label of any data type for every row, typically moved out of a column using
+//!   [`DataFrame::set_index`]. Labels are hashed like [`OrderedEntry`]s, hence numerical labels match across data
+//!   types, and may be repeated.
+//! - A **datetime** index is a label index over a column of `DataType::Date` or `DataType::DateTime`. Texts are parsed
+//!   into its data type when looking up labels.
+//! - A **multi** index labels every row with a tuple of labels, one for each of its levels, see
+//!   [`raccoon::multiindex`]. Single labels and selectors refer to its first level.
+//!
+//! [`Index`]: ./struct.Index.html
+//! [`Series::with_index`]: ../series/struct.Series.html#method.with_index
+//! [`DataFrame::set_index`]: ../dataframe/struct.DataFrame.html#method.set_index
+//! [`raccoon::multiindex`]: ../multiindex/index.html
+//! [`OrderedEntry`]: ../entry/struct.OrderedEntry.html
+//!
+//! # Selection
+//! [`DataFrame::loc`] selects rows by label using a [`LabelSelector`], which is either a single label, a range of
+//! labels including both ends, or a list of labels. [`DataFrame::iloc`] selects rows by position using a
+//! [`PositionSelector`]. Both are built from plain values, ranges and vectors using `From`. [`Series::loc`] and
+//! [`Series::iloc`] select the entries of a series alike.
+//!
+//! A range of labels selects the rows from the first row labelled with its start to the last row labelled with its end.
+//! For range and datetime indexes, it selects the rows whose labels lie between its ends instead, whether or not the
+//! ends are labels of the index.
+//!
+//! [`DataFrame::loc`]: ../dataframe/struct.DataFrame.html#method.loc
+//! [`DataFrame::iloc`]: ../dataframe/struct.DataFrame.html#method.iloc
+//! [`Series::loc`]: ../series/struct.Series.html#method.loc
+//! [`Series::iloc`]: ../series/struct.Series.html#method.iloc
+//! [`LabelSelector`]: ./enum.LabelSelector.html
+//! [`PositionSelector`]: ./enum.PositionSelector.html
+//!
+//! # Examples
+//! ```
+//! use raccoon::{DataFrame, IndexKind, Series};
+//!
+//! let df = DataFrame::from_series(vec![
+//!     Series::from_vector("city".to_owned(), vec!["Zürich", "Geneva", "Basel", "Bern"]),
+//!     Series::from_vector("population".to_owned(), vec![415_367, 201_818, 171_017, 133_883]),
+//! ]).unwrap();
+//! assert_eq!(df.index().kind(), IndexKind::Range);
+//! assert_eq!(df.iloc(1..3).unwrap()["city"], vec!["Geneva", "Basel"]);
+//!
+//! let df = df.set_index("city").unwrap();
+//! assert_eq!(df.index().kind(), IndexKind::Label);
+//! assert_eq!(df.loc("Basel").unwrap()["population"], vec![171_017]);
+//! assert_eq!(df.loc("Geneva"..="Bern").unwrap()["population"], vec![201_818, 171_017, 133_883]);
+//! assert_eq!(df.loc(vec!["Bern", "Zürich"]).unwrap()["population"], vec![133_883, 415_367]);
+//! ```
+
+use entry::{DataEntry, DataType, OrderedEntry};
+use error::{RaccoonResult, RaccoonError};
+use multiindex::{Level, MultiIndex};
+use series::Series;
+
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+use std::sync::{Arc, OnceLock};
+
+/// The kind of an [`Index`], see `raccoon::index`.
+///
+/// [`Index`]: ./struct.Index.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndexKind {
+    /// Labels the rows by their positions.
+    Range,
+    /// Holds a label of any data type for every row.
+    Label,
+    /// Holds a date or a date time for every row.
+    Datetime,
+    /// Holds a tuple of labels for every row, see `raccoon::multiindex`.
+    Multi,
+}
+
+/// The labels of the rows of a dataframe, see `raccoon::index`.
+///
+/// # Example
+/// ```
+/// use raccoon::{DataEntry, Index, IndexKind, Series};
+///
+/// let index = Index::range(3);
+/// assert_eq!(index.get(2), Some(DataEntry::Long(2)));
+/// assert_eq!(index.positions(&DataEntry::Integer(1)), vec![1]);
+///
+/// let index = Index::from_series(Series::from_vector("key".to_owned(), vec!['a', 'b', 'a']));
+/// assert_eq!(index.kind(), IndexKind::Label);
+/// assert_eq!(index.name(), Some("key"));
+/// assert_eq!(index.positions(&DataEntry::from('a')), vec![0, 2]);
+/// assert!(!index.is_unique());
+/// ```
+#[derive(Clone)]
+pub struct Index {
+    kind: IndexKind,
+    len: usize,
+    name: Option<String>,
+    // labels of label and datetime indexes, range indexes do not store theirs. Labels are shared between the copies
+    // of an index, such as the indexes of the columns of a dataframe.
+    labels: Option<Arc<Series>>,
+    // positions of every label, built on the first lookup
+    lookup: Arc<OnceLock<HashMap<OrderedEntry, Vec<usize>>>>,
+    // levels of multi indexes, which do not store labels themselves
+    multi: Option<Arc<MultiIndex>>,
+}
+
+impl Index {
+    /// Constructs a range index labelling `len` rows by their positions.
+    pub fn range(len: usize) -> Index {
+        Index {
+            kind: IndexKind::Range,
+            len,
+            name: None,
+            labels: None,
+            lookup: Arc::new(OnceLock::new()),
+            multi: None,
+        }
+    }
+
+    /// Constructs an index holding the entries of `series` as labels, named after the series. The index is a datetime
+    /// index if the series is of `DataType::Date` or `DataType::DateTime`, and a label index otherwise.
+    pub fn from_series(series: Series) -> Index {
+        let name = series.name().to_owned();
+        Index {
+            name: Some(name),
+            ..Index::from_labels(series)
+        }
+    }
+
+    /// Constructs an unnamed index holding the entries of `labels`.
+    pub(crate) fn from_labels(mut labels: Series) -> Index {
+        let len = labels.len();
+        labels.relabel(Index::range(len));
+        let kind = match *labels.data_type() {
+            DataType::Date | DataType::DateTime => IndexKind::Datetime,
+            _                                   => IndexKind::Label,
+        };
+        Index {
+            kind,
+            len,
+            name: None,
+            labels: Some(Arc::new(labels)),
+            lookup: Arc::new(OnceLock::new()),
+            multi: None,
+        }
+    }
+
+    /// Returns the kind of the index.
+    pub fn kind(&self) -> IndexKind {
+        self.kind
+    }
+
+    /// Returns the name of the index, which is the name of the column it was built from. Range indexes and indexes
+    /// built from vectors are unnamed.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns a copy of the index named `name`.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::Index;
+    /// let index = Index::from(vec![1.5, 2.5]).with_name("x");
+    /// assert_eq!(index.name(), Some("x"));
+    /// ```
+    pub fn with_name<S>(mut self, name: S) -> Index where S: Into<String> {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Returns the number of labels.
+    pub fn len(&self) -> usize {
+        match self.multi {
+            Some(ref multi) => multi.len(),
+            None            => self.len,
+        }
+    }
+
+    /// Checks whether the index contains no labels.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of levels, which is 1 unless the index is a multi index.
+    pub fn nlevels(&self) -> usize {
+        self.multi.as_ref().map_or(1, |multi| multi.nlevels())
+    }
+
+    /// Returns the levels of a multi index, or `None` for other kinds of indexes.
+    pub fn multi(&self) -> Option<&MultiIndex> {
+        self.multi.as_deref()
+    }
+
+    /// Returns the levels of the index, from the outermost to the innermost. An index that is not a multi index is its
+    /// only level.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::Index;
+    /// let index = Index::from(vec![1, 2]).with_name("x");
+    /// assert_eq!(index.levels(), vec![index.clone()]);
+    /// ```
+    pub fn levels(&self) -> Vec<Index> {
+        match self.multi {
+            Some(ref multi) => multi.levels().to_vec(),
+            None            => vec![self.clone()],
+        }
+    }
+
+    /// Returns a level, selected by position or by name. An index that is not a multi index is its only level, at
+    /// position 0.
+    ///
+    /// # Errors
+    /// `RaccoonError::LevelNotFound` if there is no such level.
+    pub fn level<L>(&self, level: L) -> RaccoonResult<Index> where L: Into<Level> {
+        MultiIndex::from(self.clone()).level(level).cloned()
+    }
+
+    /// Returns the data type of the labels, `DataType::Long` for range indexes. For multi indexes, it is the data type
+    /// of the first level.
+    pub fn data_type(&self) -> &DataType {
+        if let Some(ref multi) = self.multi {
+            return multi.levels()[0].data_type();
+        }
+        match self.labels {
+            Some(ref labels)    => labels.data_type(),
+            None                => &DataType::Long,
+        }
+    }
+
+    /// Returns the label at `position`, or `None` if the position is out of bounds. For multi indexes, it is the
+    /// label of the first level, see [`MultiIndex::get`] for all levels.
+    ///
+    /// [`MultiIndex::get`]: ../multiindex/struct.MultiIndex.html#method.get
+    pub fn get(&self, position: usize) -> Option<DataEntry> {
+        if let Some(ref multi) = self.multi {
+            return multi.levels()[0].get(position);
+        }
+        match self.labels {
+            Some(ref labels)                => labels.get(position),
+            None if position < self.len     => Some(DataEntry::Long(position as i64)),
+            None                            => None,
+        }
+    }
+
+    /// Returns the labels as a series named after the index, or `"index"` if the index is unnamed. For multi indexes,
+    /// it holds the labels of the first level.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::Index;
+    /// let labels = Index::range(3).to_series();
+    /// assert_eq!(labels.name(), "index");
+    /// assert_eq!(labels, vec![0i64, 1, 2]);
+    /// ```
+    pub fn to_series(&self) -> Series {
+        if let Some(ref multi) = self.multi {
+            return multi.levels()[0].to_series();
+        }
+        let name = self.name.clone().unwrap_or_else(|| "index".to_owned());
+        match self.labels {
+            Some(ref labels)    => {
+                let mut labels = Series::clone(labels);
+                labels.set_name(name);
+                labels
+            },
+            None                => Series::from_vector(name, (0..self.len as i64).collect()),
+        }
+    }
+
+    /// Checks whether no label is repeated. For multi indexes, whether no tuple of labels is repeated.
+    pub fn is_unique(&self) -> bool {
+        match self.multi {
+            Some(ref multi) => multi.is_unique(),
+            None            => self.labels.is_none() || self.lookup().len() == self.len,
+        }
+    }
+
+    /// Returns the positions of the rows labelled `label`, in ascending order. For multi indexes, `label` is a label of
+    /// the first level.
+    pub fn positions(&self, label: &DataEntry) -> Vec<usize> {
+        if let Some(ref multi) = self.multi {
+            return multi.levels()[0].positions(label);
+        }
+        let label = self.coerce(label);
+        if self.labels.is_none() {
+            return range_position(&label, self.len).into_iter().collect();
+        }
+        self.lookup().get(&OrderedEntry(label)).cloned().unwrap_or_default()
+    }
+
+    /// Returns the positions of the rows selected by `selector`, see `raccoon::index`.
+    ///
+    /// Fails with `RaccoonError::LabelNotFound` if a single label or a label of a list is not in the index, or if an
+    /// end of a range of a label index is not, and with `RaccoonError::TypeMismatch` if an end of a range cannot be
+    /// compared with the labels.
+    pub(crate) fn select(&self, selector: &LabelSelector) -> RaccoonResult<Vec<usize>> {
+        if let Some(ref multi) = self.multi {
+            return multi.levels()[0].select(selector);
+        }
+        match *selector {
+            LabelSelector::Label(ref label)             => self.require(label),
+            LabelSelector::List(ref labels)             => {
+                let mut positions = Vec::new();
+                for label in labels {
+                    positions.extend(self.require(label)?);
+                }
+                Ok(positions)
+            },
+            LabelSelector::Range(ref start, ref end)    => {
+                let (start, end) = (start.as_ref().map(|x| self.coerce(x)), end.as_ref().map(|x| self.coerce(x)));
+                for bound in start.iter().chain(end.iter()) {
+                    if self.data_type().common_type(&bound.data_type()).is_none() {
+                        return Err(RaccoonError::TypeMismatch {
+                            expected: self.data_type().clone(),
+                            found: bound.data_type(),
+                        });
+                    }
+                }
+                if self.kind == IndexKind::Label {
+                    let first = start.map_or(Ok(0), |label| self.require(&label).map(|positions| positions[0]))?;
+                    let last = match end {
+                        Some(label) => self.require(&label)?.pop().map_or(0, |position| position + 1),
+                        None        => self.len,
+                    };
+                    return Ok((first..last).collect());
+                }
+                let (start, end) = (start.map(OrderedEntry), end.map(OrderedEntry));
+                Ok((0..self.len)
+                    .filter(|&position| {
+                        let label = OrderedEntry(self.get(position).unwrap_or(DataEntry::NA));
+                        label.0 != DataEntry::NA && start.as_ref().is_none_or(|start| *start <= label)
+                            && end.as_ref().is_none_or(|end| label <= *end)
+                    })
+                    .collect())
+            },
+        }
+    }
+
+    /// Returns the index of the rows at `positions`, which must all be in bounds. A range index becomes a label index
+    /// unless all its rows are taken in order.
+    pub(crate) fn take(&self, positions: &[usize]) -> Index {
+        if let Some(ref multi) = self.multi {
+            return Index::from(multi.take(positions));
+        }
+        let labels = match self.labels {
+            Some(ref labels)                                        => labels.take_entries(positions)
+                .expect("positions of an index are in bounds"),
+            None if positions.iter().cloned().eq(0..self.len)       => return self.clone(),
+            None                                                    => {
+                Series::from_vector(String::new(), positions.iter().map(|&position| position as i64).collect())
+            },
+        };
+        Index {
+            name: self.name.clone(),
+            ..Index::from_labels(labels)
+        }
+    }
+
+    /// Checks whether the index has the same labels as `other` in the same order, without repeated labels, such that
+    /// rows can be matched by position.
+    pub(crate) fn is_aligned_with(&self, other: &Index) -> bool {
+        self.len() == other.len() && self.has_labels_of(other) && self.is_unique()
+    }
+
+    /// Appends a label for a row pushed onto a series: the next position for range indexes, and `DataEntry::NA`
+    /// otherwise.
+    pub(crate) fn push(&mut self) {
+        if let Some(ref mut multi) = self.multi {
+            return Arc::make_mut(multi).push();
+        }
+        if let Some(ref mut labels) = self.labels {
+            Arc::make_mut(labels).push_entry(DataEntry::NA).expect("NA is accepted by any series");
+            self.lookup = Arc::new(OnceLock::new());
+        }
+        self.len += 1;
+    }
+
+    /// Removes the label of the last row, for a row popped from a series.
+    pub(crate) fn pop(&mut self) {
+        if let Some(ref mut multi) = self.multi {
+            return Arc::make_mut(multi).pop();
+        }
+        if let Some(ref mut labels) = self.labels {
+            Arc::make_mut(labels).pop_entry();
+            self.lookup = Arc::new(OnceLock::new());
+        }
+        self.len = self.len.saturating_sub(1);
+    }
+
+    /// Returns the labels of `indexes` one after the other, for rows concatenated. Range indexes give a range index,
+    /// and multi indexes are concatenated level by level. A name shared by all indexes is kept.
+    ///
+    /// Fails with `RaccoonError::LengthMismatch` if the indexes have different numbers of levels, and with
+    /// `RaccoonError::TypeMismatch` if the labels of a level have no common data type.
+    pub(crate) fn concat(indexes: &[&Index]) -> RaccoonResult<Index> {
+        if indexes.iter().all(|index| index.kind == IndexKind::Range) {
+            return Ok(Index::range(indexes.iter().map(|index| index.len()).sum()));
+        }
+        let nlevels = indexes.first().map_or(1, |index| index.nlevels());
+        if let Some(index) = indexes.iter().find(|index| index.nlevels() != nlevels) {
+            return Err(RaccoonError::LengthMismatch { expected: nlevels, found: index.nlevels() });
+        }
+        if nlevels == 1 {
+            return Index::concat_labels(indexes);
+        }
+        let levels: Vec<Vec<Index>> = indexes.iter().map(|index| index.levels()).collect();
+        let levels = (0..nlevels)
+            .map(|level| Index::concat_labels(&levels.iter().map(|levels| &levels[level]).collect::<Vec<&Index>>()))
+            .collect::<RaccoonResult<Vec<Index>>>()?;
+        Ok(Index::from(MultiIndex::new(levels)?))
+    }
+
+    /// Checks whether the index has the labels of `other` in the same order, assuming both have the same length.
+    fn has_labels_of(&self, other: &Index) -> bool {
+        match (&self.multi, &other.multi) {
+            (Some(multi), Some(other))  => {
+                Arc::ptr_eq(multi, other) || (multi.nlevels() == other.nlevels()
+                    && multi.levels().iter().zip(other.levels()).all(|(level, other)| level.has_labels_of(other)))
+            },
+            (None, None)                => match (&self.labels, &other.labels) {
+                (None, None)                => true,
+                (Some(labels), Some(other)) => Arc::ptr_eq(labels, other) || labels.iter().eq(other.iter()),
+                _                           => false,
+            },
+            _                           => false,
+        }
+    }
+
+    /// Returns the labels of `indexes`, which are not multi indexes, one after the other, converted to a common data
+    /// type.
+    fn concat_labels(indexes: &[&Index]) -> RaccoonResult<Index> {
+        let mut data_type = DataType::NA;
+        for index in indexes {
+            data_type = data_type.common_type(index.data_type())
+                .ok_or_else(|| RaccoonError::TypeMismatch { expected: data_type, found: index.data_type().clone() })?;
+        }
+        let mut labels = Vec::with_capacity(indexes.iter().map(|index| index.len()).sum());
+        for index in indexes {
+            labels.extend(index.to_series().iter().map(|label| label.convert_to(&data_type)));
+        }
+        let index = Index::from_labels(Series::from_entries(String::new(), data_type, labels));
+        match indexes.first().and_then(|first| first.name()) {
+            Some(name) if indexes.iter().all(|index| index.name() == Some(name))    => Ok(index.with_name(name)),
+            _                                                                       => Ok(index),
+        }
+    }
+
+    /// Returns the positions of the rows labelled `label`, failing with `RaccoonError::LabelNotFound` if there are
+    /// none.
+    fn require(&self, label: &DataEntry) -> RaccoonResult<Vec<usize>> {
+        let positions = self.positions(label);
+        if positions.is_empty() {
+            return Err(RaccoonError::LabelNotFound(format!("{:?}", label)));
+        }
+        Ok(positions)
+    }
+
+    /// Parses textual labels looked up in a datetime index into the data type of the index.
+    fn coerce(&self, label: &DataEntry) -> DataEntry {
+        match *label {
+            DataEntry::Text(_) if self.kind == IndexKind::Datetime  => {
+                match label.convert_to(self.data_type()) {
+                    DataEntry::NA   => label.clone(),
+                    converted       => converted,
+                }
+            },
+            _                                                       => label.clone(),
+        }
+    }
+
+    /// Returns the positions of every label, building them on the first call.
+    fn lookup(&self) -> &HashMap<OrderedEntry, Vec<usize>> {
+        self.lookup.get_or_init(|| {
+            let mut lookup: HashMap<OrderedEntry, Vec<usize>> = HashMap::with_capacity(self.len);
+            if let Some(ref labels) = self.labels {
+                for (position, label) in labels.iter().enumerate() {
+                    lookup.entry(OrderedEntry(label)).or_default().push(position);
+                }
+            }
+            lookup
+        })
+    }
+}
+
+impl Default for Index {
+    fn default() -> Index {
+        Index::range(0)
+    }
+}
+
+impl PartialEq for Index {
+    fn eq(&self, other: &Index) -> bool {
+        if let (Some(multi), Some(other)) = (&self.multi, &other.multi) {
+            return Arc::ptr_eq(multi, other) || multi == other;
+        }
+        self.kind == other.kind && self.len == other.len && self.name == other.name
+            && match (&self.labels, &other.labels) {
+                (Some(labels), Some(other)) => Arc::ptr_eq(labels, other) || labels.iter().eq(other.iter()),
+                (labels, other)             => labels.is_none() && other.is_none(),
+            }
+    }
+}
+
+impl fmt::Debug for Index {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut debug = f.debug_struct("Index");
+        debug.field("kind", &self.kind).field("name", &self.name);
+        if let Some(ref multi) = self.multi {
+            return debug.field("levels", &multi.levels()).finish();
+        }
+        match self.labels {
+            Some(ref labels)    => debug.field("labels", &labels.iter().collect::<Vec<DataEntry>>()),
+            None                => debug.field("len", &self.len),
+        };
+        debug.finish()
+    }
+}
+
+impl From<Series> for Index {
+    fn from(series: Series) -> Self {
+        Index::from_series(series)
+    }
+}
+
+impl From<MultiIndex> for Index {
+    /// Builds an index of kind `IndexKind::Multi`, unless the multi index has a single level which becomes the index.
+    fn from(multi: MultiIndex) -> Self {
+        if multi.nlevels() == 1 {
+            return multi.levels()[0].clone();
+        }
+        Index {
+            kind: IndexKind::Multi,
+            len: multi.len(),
+            name: None,
+            labels: None,
+            lookup: Arc::new(OnceLock::new()),
+            multi: Some(Arc::new(multi)),
+        }
+    }
+}
+
+impl<T> From<Vec<T>> for Index where T: Into<DataEntry> {
+    fn from(labels: Vec<T>) -> Self {
+        Index::from_labels(Series::from_vector(String::new(), labels))
+    }
+}
+
+/// Returns the position a label refers to in a range index of `len` rows, if any.
+fn range_position(label: &DataEntry, len: usize) -> Option<usize> {
+    let position = match *label {
+        DataEntry::Integer(int)                 => int as i128,
+        DataEntry::UInteger(int)                => int as i128,
+        DataEntry::Long(int)                    => int as i128,
+        DataEntry::ULong(int)                   => int as i128,
+        DataEntry::Float(f) if f.fract() == 0.0 => f as i128,
+        DataEntry::Double(f) if f.fract() == 0.0 => f as i128,
+        _                                       => return None,
+    };
+    if 0 <= position && position < len as i128 { Some(position as usize) } else { None }
+}
+
+/// Rows selected by label, see `raccoon::index`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LabelSelector {
+    /// The rows having a label.
+    Label(DataEntry),
+    /// The rows between two labels, both included. A missing end selects up to the first or the last row.
+    Range(Option<DataEntry>, Option<DataEntry>),
+    /// The rows having any of the labels, in the order of the labels.
+    List(Vec<DataEntry>),
+}
+
+impl<T> From<T> for LabelSelector where T: Into<DataEntry> {
+    fn from(label: T) -> Self {
+        LabelSelector::Label(label.into())
+    }
+}
+
+impl<T> From<RangeInclusive<T>> for LabelSelector where T: Into<DataEntry> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        let (start, end) = range.into_inner();
+        LabelSelector::Range(Some(start.into()), Some(end.into()))
+    }
+}
+
+impl<T> From<RangeFrom<T>> for LabelSelector where T: Into<DataEntry> {
+    fn from(range: RangeFrom<T>) -> Self {
+        LabelSelector::Range(Some(range.start.into()), None)
+    }
+}
+
+impl<T> From<RangeToInclusive<T>> for LabelSelector where T: Into<DataEntry> {
+    fn from(range: RangeToInclusive<T>) -> Self {
+        LabelSelector::Range(None, Some(range.end.into()))
+    }
+}
+
+impl<T> From<Vec<T>> for LabelSelector where T: Into<DataEntry> {
+    fn from(labels: Vec<T>) -> Self {
+        LabelSelector::List(labels.into_iter().map(|label| label.into()).collect())
+    }
+}
+
+/// Rows selected by position, see `raccoon::index`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PositionSelector {
+    /// The row at a position.
+    Position(usize),
+    /// The rows from a start position up to an end position, excluded. A missing end selects up to the last row. Like
+    /// python slices, ranges are clipped to the rows available.
+    Range(usize, Option<usize>),
+    /// The rows at a list of positions, in the order of the list.
+    List(Vec<usize>),
+}
+
+impl PositionSelector {
+    /// Returns the positions selected among `len` rows.
+    ///
+    /// Fails with `RaccoonError::IndexOutOfBounds` if a single position or a position of a list is not smaller than
+    /// `len`.
+    pub(crate) fn positions(&self, len: usize) -> RaccoonResult<Vec<usize>> {
+        let positions = match *self {
+            PositionSelector::Position(position)    => vec![position],
+            PositionSelector::Range(start, end)     => return Ok((start..end.unwrap_or(len).min(len)).collect()),
+            PositionSelector::List(ref positions)   => positions.clone(),
+        };
+        match positions.iter().find(|&&position| position >= len) {
+            Some(&index)    => Err(RaccoonError::IndexOutOfBounds { index, length: len }),
+            None            => Ok(positions),
+        }
+    }
+}
+
+impl From<usize> for PositionSelector {
+    fn from(position: usize) -> Self {
+        PositionSelector::Position(position)
+    }
+}
+
+impl From<Range<usize>> for PositionSelector {
+    fn from(range: Range<usize>) -> Self {
+        PositionSelector::Range(range.start, Some(range.end))
+    }
+}
+
+impl From<RangeInclusive<usize>> for PositionSelector {
+    fn from(range: RangeInclusive<usize>) -> Self {
+        PositionSelector::Range(*range.start(), Some(range.end() + 1))
+    }
+}
+
+impl From<RangeFrom<usize>> for PositionSelector {
+    fn from(range: RangeFrom<usize>) -> Self {
+        PositionSelector::Range(range.start, None)
+    }
+}
+
+impl From<RangeTo<usize>> for PositionSelector {
+    fn from(range: RangeTo<usize>) -> Self {
+        PositionSelector::Range(0, Some(range.end))
+    }
+}
+
+impl From<RangeFull> for PositionSelector {
+    fn from(_: RangeFull) -> Self {
+        PositionSelector::Range(0, None)
+    }
+}
+
+impl From<Vec<usize>> for PositionSelector {
+    fn from(positions: Vec<usize>) -> Self {
+        PositionSelector::List(positions)
+    }
+}
+
+impl<'a> From<&'a [usize]> for PositionSelector {
+    fn from(positions: &'a [usize]) -> Self {
+        PositionSelector::List(positions.to_vec())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn lookups() {
+        let index = Index::range(4);
+        assert_eq!(index.positions(&DataEntry::Double(3.0)), vec![3]);
+        assert!(index.positions(&DataEntry::Long(4)).is_empty());
+        assert!(index.positions(&DataEntry::from("1")).is_empty());
+        assert!(index.is_unique());
+
+        let index = Index::from(vec![DataEntry::Integer(7), DataEntry::NA, DataEntry::Integer(7)]);
+        assert_eq!(index.name(), None);
+        assert_eq!(index.positions(&DataEntry::ULong(7)), vec![0, 2]);
+        assert_eq!(index.positions(&DataEntry::NA), vec![1]);
+        assert_eq!(index.to_series().name(), "index");
+        assert!(!index.is_unique());
+    }
+
+    #[test]
+    fn selections() {
+        let index = Index::from(vec!["d", "a", "c", "a"]);
+        assert_eq!(index.select(&"a".into()).unwrap(), vec![1, 3]);
+        assert_eq!(index.select(&vec!["c", "d"].into()).unwrap(), vec![2, 0]);
+        assert_eq!(index.select(&("a"..="c").into()).unwrap(), vec![1, 2]);
+        assert_eq!(index.select(&("c"..).into()).unwrap(), vec![2, 3]);
+        assert_eq!(index.select(&(..="a").into()).unwrap(), vec![0, 1, 2, 3]);
+        assert!(matches!(index.select(&"b".into()), Err(RaccoonError::LabelNotFound(_))));
+        assert!(matches!(index.select(&("a"..="b").into()), Err(RaccoonError::LabelNotFound(_))));
+        assert!(matches!(index.select(&(1..=2).into()), Err(RaccoonError::TypeMismatch { .. })));
+
+        let index = Index::range(5);
+        assert_eq!(index.select(&(1..=3).into()).unwrap(), vec![1, 2, 3]);
+        assert_eq!(index.select(&(3.5..).into()).unwrap(), vec![4]);
+        assert_eq!(index.select(&(-3..=0).into()).unwrap(), vec![0]);
+
+        let dates = (1..=4).map(|day| NaiveDate::from_ymd_opt(2021, 3, day).unwrap()).collect();
+        let index = Index::from_series(Series::from_vector("day".to_owned(), dates));
+        assert_eq!(index.kind(), IndexKind::Datetime);
+        assert_eq!(index.select(&"2021-03-02".into()).unwrap(), vec![1]);
+        assert_eq!(index.select(&("2021-02-01"..="2021-03-02").into()).unwrap(), vec![0, 1]);
+
+        assert_eq!(PositionSelector::from(2..9).positions(4).unwrap(), vec![2, 3]);
+        assert_eq!(PositionSelector::from(..).positions(2).unwrap(), vec![0, 1]);
+        assert_eq!(PositionSelector::from(1..=1).positions(2).unwrap(), vec![1]);
+        let result = PositionSelector::from(vec![0, 4]).positions(4);
+        assert!(matches!(result, Err(RaccoonError::IndexOutOfBounds { index: 4, length: 4 })));
+    }
+
+    #[test]
+    fn taking_rows() {
+        let index = Index::range(3);
+        assert_eq!(index.take(&[0, 1, 2]), index);
+        let taken = index.take(&[2, 0]);
+        assert_eq!(taken.kind(), IndexKind::Label);
+        assert_eq!(taken.to_series(), vec![2i64, 0]);
+
+        let index = Index::from(vec!['x', 'y']).with_name("key");
+        let taken = index.take(&[1]);
+        assert_eq!(taken.name(), Some("key"));
+        assert_eq!(taken.get(0), Some(DataEntry::from('y')));
+        assert_eq!(taken.get(1), None);
+    }
+}
@@ -0,0 +1,695 @@
+//! Loading and saving of [`DataFrame`]s and [`Series`] from and to CSV files.
+//!
+//! [`DataFrame`]: ../../dataframe/struct.DataFrame.html
+//! [`Series`]: ../../series/struct.Series.html
+//!
+//! # Type Inference
+//! Unless a data type is set explicitly for a column, the [`CsvReader`] infers it from the content of the column. The
+//! cells are parsed using the same rules [`DataEntry::convert_to`] applies to `DataEntry::Text`, trying the following
+//! data types in order:
+//!
+//! 1. `DataType::Boolean`,
+//! 2. `DataType::Integer`, `DataType::UInteger`, `DataType::Long`, and `DataType::ULong`,
+//! 3. `DataType::Double`,
+//! 4. `DataType::Date` and `DataType::DateTime`, in ISO 8601 format,
+//! 5. `DataType::Character`,
+//! 6. `DataType::Text`.
+//!
+//! The first data type into which all cells of the column can be parsed is used. `DataType::Float` is never inferred,
+//! as every cell that can be parsed into a `f64` can also be parsed into a lossy `f32`. Cells matching one of the NA
+//! markers are ignored during inference and become `DataEntry::NA`. A column containing only NA markers has type
+//! `DataType::NA`.
+//!
+//! [`CsvReader`]: ./struct.CsvReader.html
+//! [`DataEntry::convert_to`]: ../../entry/enum.DataEntry.html#method.convert_to
+//!
+//! # Examples
+//! ```
+//! use raccoon::{CsvReader, DataEntry, DataType};
+//!
+//! let data = "id;name;score\n1;Anna;4.5\n2;Tom;NA\n";
+//! let df = CsvReader::new().delimiter(b';').read(data.as_bytes()).unwrap();
+//!
+//! assert_eq!(df.shape(), (2, 3));
+//! assert_eq!(df.dtypes(), vec![&DataType::Integer, &DataType::Text, &DataType::Double]);
+//! assert_eq!(df["score"].get(1), Some(DataEntry::NA));
+//! ```
+//!
+//! Writing a `DataFrame` with a [`CsvWriter`]:
+//!
+//! [`CsvWriter`]: ./struct.CsvWriter.html
+//! ```
+//! use raccoon::{CsvWriter, DataFrame, Series};
+//!
+//! let df = DataFrame::from_series(vec![
+//!     Series::from_vector("x".to_owned(), vec![1.0f64, 2.25]),
+//!     Series::from_vector("label".to_owned(), vec!["a", "b;c"]),
+//! ]).unwrap();
+//!
+//! let mut buffer = Vec::new();
+//! CsvWriter::new().delimiter(b';').write(&df, &mut buffer).unwrap();
+//! assert_eq!(String::from_utf8(buffer).unwrap(), "x;label\n1.0;a\n2.25;\"b;c\"\n");
+//! ```
+
+use csv;
+use csv::{ReaderBuilder, StringRecord, WriterBuilder};
+
+use dataframe::DataFrame;
+use entry::{DataEntry, DataType};
+use error::{RaccoonResult, RaccoonError};
+use series::Series;
+
+use std::fs::File;
+use std::io::{Read, Write};
+use std::path::Path;
+
+/// The data types tried during type inference, in order of preference. `DataType::Text` always succeeds and is
+/// therefore not part of this list.
+const INFERENCE_ORDER: [DataType; 9] = [
+    DataType::Boolean,
+    DataType::Integer,
+    DataType::UInteger,
+    DataType::Long,
+    DataType::ULong,
+    DataType::Double,
+    DataType::Date,
+    DataType::DateTime,
+    DataType::Character,
+];
+
+/// A configurable reader building a [`DataFrame`] from CSV data.
+///
+/// [`DataFrame`]: ../../dataframe/struct.DataFrame.html
+///
+/// # Defaults
+/// - the delimiter is `,`,
+/// - the quote character is `"`,
+/// - the first row is a header containing the column names,
+/// - the NA markers are `""`, `"NA"`, `"N/A"`, `"NaN"`, and `"null"`,
+/// - no rows are skipped,
+/// - all columns are read.
+///
+/// # Example
+/// ```
+/// use raccoon::{CsvReader, DataEntry, DataType};
+///
+/// let data = "# exported data\n'a';'b';'c'\n1;'x';-\n2;'y';3\n";
+/// let df = CsvReader::new()
+///     .delimiter(b';')
+///     .quote(b'\'')
+///     .skip_rows(1)
+///     .na_values(vec!["-"])
+///     .columns(vec!["a", "c"])
+///     .dtype("a", DataType::Long)
+///     .read(data.as_bytes())
+///     .unwrap();
+///
+/// assert_eq!(df.columns(), vec!["a", "c"]);
+/// assert_eq!(df.dtypes(), vec![&DataType::Long, &DataType::Integer]);
+/// assert_eq!(df["c"].get(0), Some(DataEntry::NA));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CsvReader {
+    delimiter: u8,
+    quote: u8,
+    has_header: bool,
+    na_values: Vec<String>,
+    skip_rows: usize,
+    columns: Option<Vec<String>>,
+    dtypes: Vec<(String, DataType)>,
+    strict: bool,
+}
+
+impl CsvReader {
+    /// Constructs a new `CsvReader` using the default options.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::CsvReader;
+    /// let df = CsvReader::new().read("a,b\n1,2\n".as_bytes()).unwrap();
+    /// assert_eq!(df.shape(), (1, 2));
+    /// ```
+    pub fn new() -> CsvReader {
+        CsvReader {
+            delimiter: b',',
+            quote: b'"',
+            has_header: true,
+            na_values: vec!["".to_owned(), "NA".to_owned(), "N/A".to_owned(), "NaN".to_owned(), "null".to_owned()],
+            skip_rows: 0,
+            columns: None,
+            dtypes: Vec::new(),
+            strict: false,
+        }
+    }
+
+    /// Sets the field delimiter.
+    pub fn delimiter(mut self, delimiter: u8) -> CsvReader {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Sets the character used to quote fields.
+    pub fn quote(mut self, quote: u8) -> CsvReader {
+        self.quote = quote;
+        self
+    }
+
+    /// Sets whether the first row (after the skipped rows) contains the column names.
+    ///
+    /// If there is no header, the columns are named after their position, starting at `"0"`.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::CsvReader;
+    /// let df = CsvReader::new().has_header(false).read("1,2\n3,4\n".as_bytes()).unwrap();
+    /// assert_eq!(df.columns(), vec!["0", "1"]);
+    /// assert_eq!(df.shape(), (2, 2));
+    /// ```
+    pub fn has_header(mut self, has_header: bool) -> CsvReader {
+        self.has_header = has_header;
+        self
+    }
+
+    /// Sets the markers that are read as `DataEntry::NA`. This replaces the default markers.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{CsvReader, DataEntry};
+    /// let df = CsvReader::new().na_values(vec!["missing"]).read("a\n1\nmissing\n".as_bytes()).unwrap();
+    /// assert_eq!(df["a"].get(1), Some(DataEntry::NA));
+    /// ```
+    pub fn na_values<T>(mut self, na_values: Vec<T>) -> CsvReader where T: Into<String> {
+        self.na_values = na_values.into_iter().map(|x| x.into()).collect();
+        self
+    }
+
+    /// Sets the number of rows to skip at the beginning of the data, before the header.
+    pub fn skip_rows(mut self, skip_rows: usize) -> CsvReader {
+        self.skip_rows = skip_rows;
+        self
+    }
+
+    /// Restricts the columns read to the ones named. The columns keep the order they have in the data.
+    ///
+    /// Reading fails if one of the names does not match any column.
+    pub fn columns<T>(mut self, columns: Vec<T>) -> CsvReader where T: Into<String> {
+        self.columns = Some(columns.into_iter().map(|x| x.into()).collect());
+        self
+    }
+
+    /// Sets the data type of a column instead of inferring it. Cells that cannot be parsed into this data type become
+    /// `DataEntry::NA`, unless the reader is `strict`.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{CsvReader, DataEntry, DataType};
+    /// let df = CsvReader::new().dtype("a", DataType::UInteger).read("a\n1\n-2\n".as_bytes()).unwrap();
+    /// assert_eq!(df["a"].data_type(), &DataType::UInteger);
+    /// assert_eq!(df["a"].get(1), Some(DataEntry::NA));
+    /// ```
+    pub fn dtype<T>(mut self, column: T, data_type: DataType) -> CsvReader where T: Into<String> {
+        let column = column.into();
+        self.dtypes.retain(|(name, _)| *name != column);
+        self.dtypes.push((column, data_type));
+        self
+    }
+
+    /// Sets whether cells that cannot be parsed into the data type set with `dtype` make reading fail, instead of
+    /// becoming `DataEntry::NA`.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{CsvReader, DataType, RaccoonError};
+    /// let result = CsvReader::new().dtype("a", DataType::UInteger).strict(true).read("a\n1\n-2\n".as_bytes());
+    /// assert!(matches!(result, Err(RaccoonError::Parse { row: 1, .. })));
+    /// ```
+    pub fn strict(mut self, strict: bool) -> CsvReader {
+        self.strict = strict;
+        self
+    }
+
+    /// Reads a `DataFrame` from the CSV file at `path`.
+    pub fn read_path<P>(&self, path: P) -> RaccoonResult<DataFrame> where P: AsRef<Path> {
+        let file = File::open(path)?;
+        self.read(file)
+    }
+
+    /// Reads a `DataFrame` from CSV data provided by any reader.
+    pub fn read<R>(&self, reader: R) -> RaccoonResult<DataFrame> where R: Read {
+        let mut csv_reader = ReaderBuilder::new()
+            .delimiter(self.delimiter)
+            .quote(self.quote)
+            .has_headers(false)
+            .flexible(true)
+            .from_reader(reader);
+        let mut records = csv_reader.records().skip(self.skip_rows);
+
+        let mut names: Vec<String> = Vec::new();
+        if self.has_header {
+            match records.next() {
+                Some(record) => names = record?.iter().map(|x| x.to_owned()).collect(),
+                None         => return Ok(DataFrame::new()),
+            }
+        }
+
+        let mut rows: Vec<StringRecord> = Vec::new();
+        for record in records {
+            let record = record?;
+            if !self.has_header && names.is_empty() {
+                names = (0..record.len()).map(|idx| idx.to_string()).collect();
+            }
+            if record.len() != names.len() {
+                return Err(RaccoonError::LengthMismatch { expected: names.len(), found: record.len() });
+            }
+            rows.push(record);
+        }
+
+        let mut df = DataFrame::with_capacity(names.len());
+        for idx in self.selected_columns(&names)? {
+            let cells: Vec<Option<&str>> = rows.iter().map(|row| self.cell(&row[idx])).collect();
+            let data_type = match self.dtypes.iter().find(|(name, _)| *name == names[idx]) {
+                Some((_, data_type)) => data_type.clone(),
+                None                 => infer_type(&cells),
+            };
+            let mut series = Series::with_capacity(names[idx].clone(), data_type.clone(), cells.len());
+            for (row, cell) in cells.into_iter().enumerate() {
+                let entry = match cell {
+                    Some(text) => DataEntry::parse(text, &data_type),
+                    None       => DataEntry::NA,
+                };
+                if self.strict && entry == DataEntry::NA && data_type != DataType::NA {
+                    if let Some(text) = cell {
+                        return Err(RaccoonError::Parse { row, column: names[idx].clone(), value: text.to_owned() });
+                    }
+                }
+                series.push_entry(entry)?;
+            }
+            df.add_column(series)?;
+        }
+        Ok(df)
+    }
+
+    /// Returns the positions of the columns to read.
+    fn selected_columns(&self, names: &[String]) -> RaccoonResult<Vec<usize>> {
+        match self.columns {
+            Some(ref selection) => {
+                let mut positions = Vec::with_capacity(selection.len());
+                for column in selection {
+                    match names.iter().position(|name| name == column) {
+                        Some(idx) => positions.push(idx),
+                        None      => return Err(RaccoonError::ColumnNotFound(column.clone())),
+                    }
+                }
+                positions.sort_unstable();
+                positions.dedup();
+                Ok(positions)
+            },
+            None                => Ok((0..names.len()).collect()),
+        }
+    }
+
+    /// Returns the content of a cell, or `None` if the cell matches one of the NA markers.
+    fn cell<'a>(&self, text: &'a str) -> Option<&'a str> {
+        if self.na_values.iter().any(|na| na == text) {
+            None
+        } else {
+            Some(text)
+        }
+    }
+}
+
+impl Default for CsvReader {
+    fn default() -> Self {
+        CsvReader::new()
+    }
+}
+
+/// The quoting policy used by a [`CsvWriter`].
+///
+/// [`CsvWriter`]: ./struct.CsvWriter.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuoteStyle {
+    /// Quote fields only when they contain the delimiter, the quote character, or a line break.
+    Necessary,
+    /// Quote all fields.
+    Always,
+    /// Quote all fields that do not contain a number.
+    NonNumeric,
+    /// Never quote fields, even if this produces invalid CSV data.
+    Never,
+}
+
+/// A configurable writer saving a [`DataFrame`] or a [`Series`] as CSV data.
+///
+/// [`DataFrame`]: ../../dataframe/struct.DataFrame.html
+/// [`Series`]: ../../series/struct.Series.html
+///
+/// Floating point entries are written such that the [`CsvReader`] infers `DataType::Double` when reading them back,
+/// for example `1.0` instead of `1`. This does not hold if a precision of zero decimals is set.
+///
+/// [`CsvReader`]: ./struct.CsvReader.html
+///
+/// # Defaults
+/// - the delimiter is `,`,
+/// - the quote character is `"`,
+/// - fields are only quoted when necessary,
+/// - `DataEntry::NA` is written as an empty field,
+/// - floating point numbers are written with as many decimals as needed to represent them exactly,
+/// - a header containing the column names is written.
+///
+/// # Example
+/// ```
+/// use raccoon::{CsvWriter, DataEntry, QuoteStyle, Series};
+///
+/// let mut series = Series::from_vector("values".to_owned(), vec![1.23456f64, 2.0]);
+/// series.push_entry(DataEntry::NA).unwrap();
+///
+/// let mut buffer = Vec::new();
+/// CsvWriter::new()
+///     .quote_style(QuoteStyle::Always)
+///     .na_rep("NA")
+///     .float_precision(Some(2))
+///     .has_header(false)
+///     .write_series(&series, &mut buffer)
+///     .unwrap();
+/// assert_eq!(String::from_utf8(buffer).unwrap(), "\"1.23\"\n\"2.00\"\n\"NA\"\n");
+/// ```
+#[derive(Debug, Clone)]
+pub struct CsvWriter {
+    delimiter: u8,
+    quote: u8,
+    quote_style: QuoteStyle,
+    na_rep: String,
+    float_precision: Option<usize>,
+    has_header: bool,
+}
+
+impl CsvWriter {
+    /// Constructs a new `CsvWriter` using the default options.
+    pub fn new() -> CsvWriter {
+        CsvWriter {
+            delimiter: b',',
+            quote: b'"',
+            quote_style: QuoteStyle::Necessary,
+            na_rep: String::new(),
+            float_precision: None,
+            has_header: true,
+        }
+    }
+
+    /// Sets the field delimiter.
+    pub fn delimiter(mut self, delimiter: u8) -> CsvWriter {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Sets the character used to quote fields.
+    pub fn quote(mut self, quote: u8) -> CsvWriter {
+        self.quote = quote;
+        self
+    }
+
+    /// Sets the quoting policy.
+    pub fn quote_style(mut self, quote_style: QuoteStyle) -> CsvWriter {
+        self.quote_style = quote_style;
+        self
+    }
+
+    /// Sets the text written for `DataEntry::NA`.
+    pub fn na_rep<T>(mut self, na_rep: T) -> CsvWriter where T: Into<String> {
+        self.na_rep = na_rep.into();
+        self
+    }
+
+    /// Sets the number of decimals written for `DataEntry::Float` and `DataEntry::Double`. `None` writes as many
+    /// decimals as needed to represent the number exactly.
+    pub fn float_precision(mut self, float_precision: Option<usize>) -> CsvWriter {
+        self.float_precision = float_precision;
+        self
+    }
+
+    /// Sets whether a header containing the column names is written.
+    pub fn has_header(mut self, has_header: bool) -> CsvWriter {
+        self.has_header = has_header;
+        self
+    }
+
+    /// Writes a `DataFrame` as CSV data into `writer`.
+    pub fn write<W>(&self, df: &DataFrame, writer: W) -> RaccoonResult where W: Write {
+        let columns: Vec<&Series> = df.iter().collect();
+        self.write_columns(&columns, writer)
+    }
+
+    /// Writes a `Series` as CSV data with a single column into `writer`. The header contains the name of the series.
+    pub fn write_series<W>(&self, series: &Series, writer: W) -> RaccoonResult where W: Write {
+        self.write_columns(&[series], writer)
+    }
+
+    /// Writes the columns as CSV data into `writer`. All columns must have the same length.
+    fn write_columns<W>(&self, columns: &[&Series], writer: W) -> RaccoonResult where W: Write {
+        let mut csv_writer = WriterBuilder::new()
+            .delimiter(self.delimiter)
+            .quote(self.quote)
+            .quote_style(match self.quote_style {
+                QuoteStyle::Necessary  => csv::QuoteStyle::Necessary,
+                QuoteStyle::Always     => csv::QuoteStyle::Always,
+                QuoteStyle::NonNumeric => csv::QuoteStyle::NonNumeric,
+                QuoteStyle::Never      => csv::QuoteStyle::Never,
+            })
+            .from_writer(writer);
+
+        if self.has_header {
+            csv_writer.write_record(columns.iter().map(|series| series.name()))?;
+        }
+        let rows = columns.first().map_or(0, |series| series.len());
+        let mut record: Vec<String> = Vec::with_capacity(columns.len());
+        for idx in 0..rows {
+            record.clear();
+            record.extend(columns.iter().map(|series| self.format(&series.get(idx).unwrap_or(DataEntry::NA))));
+            csv_writer.write_record(&record)?;
+        }
+        csv_writer.flush()?;
+        Ok(())
+    }
+
+    /// Formats an entry as a CSV field.
+    fn format(&self, entry: &DataEntry) -> String {
+        match *entry {
+            DataEntry::Float(f)     => {
+                match self.float_precision {
+                    Some(precision) => format!("{:.*}", precision, f),
+                    None            => format!("{:?}", f),
+                }
+            },
+            DataEntry::Double(f)    => {
+                match self.float_precision {
+                    Some(precision) => format!("{:.*}", precision, f),
+                    None            => format!("{:?}", f),
+                }
+            },
+            DataEntry::NA           => self.na_rep.clone(),
+            _                       => {
+                match entry.convert_to(&DataType::Text) {
+                    DataEntry::Text(text)   => text,
+                    _                       => self.na_rep.clone(),
+                }
+            },
+        }
+    }
+}
+
+impl Default for CsvWriter {
+    fn default() -> Self {
+        CsvWriter::new()
+    }
+}
+
+/// Infers the data type of a column from its non-NA cells. See the module documentation for the inference rules.
+fn infer_type(cells: &[Option<&str>]) -> DataType {
+    let mut viable = [true; INFERENCE_ORDER.len()];
+    let mut any_value = false;
+    for text in cells.iter().filter_map(|cell| *cell) {
+        any_value = true;
+        for (idx, data_type) in INFERENCE_ORDER.iter().enumerate() {
+            if viable[idx] && DataEntry::parse(text, data_type) == DataEntry::NA {
+                viable[idx] = false;
+            }
+        }
+    }
+    if !any_value {
+        return DataType::NA;
+    }
+    match viable.iter().position(|x| *x) {
+        Some(idx) => INFERENCE_ORDER[idx].clone(),
+        None      => DataType::Text,
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn infer_types() {
+        assert_eq!(DataType::Boolean, infer_type(&[Some("true"), None, Some("false")]));
+        assert_eq!(DataType::Integer, infer_type(&[Some("1"), Some("-2"), Some("3")]));
+        assert_eq!(DataType::UInteger, infer_type(&[Some("1"), Some("3000000000")]));
+        assert_eq!(DataType::Long, infer_type(&[Some("-1"), Some("3000000000")]));
+        assert_eq!(DataType::ULong, infer_type(&[Some("1"), Some("18000000000000000000")]));
+        assert_eq!(DataType::Double, infer_type(&[Some("1"), Some("2.5"), Some("-1e10")]));
+        assert_eq!(DataType::Date, infer_type(&[Some("2021-03-14"), Some("1999-12-31")]));
+        assert_eq!(DataType::DateTime, infer_type(&[Some("2021-03-14"), Some("2021-03-14 15:09:26")]));
+        assert_eq!(DataType::DateTime, infer_type(&[Some("2021-03-14T15:09:26Z"), None]));
+        assert_eq!(DataType::Character, infer_type(&[Some("a"), Some("b"), Some("ü")]));
+        assert_eq!(DataType::Text, infer_type(&[Some("a"), Some("bc")]));
+        assert_eq!(DataType::Text, infer_type(&[Some("true"), Some("1")]));
+        assert_eq!(DataType::NA, infer_type(&[None, None]));
+        assert_eq!(DataType::NA, infer_type(&[]));
+    }
+
+    #[test]
+    fn read_file() {
+        let df = DataFrame::read_csv("tests/data/cities.csv").unwrap();
+        assert_eq!((6, 5), df.shape());
+        assert_eq!(vec!["city", "canton", "population", "area", "lake"], df.columns());
+        assert_eq!(
+            vec![&DataType::Text, &DataType::Text, &DataType::Integer, &DataType::Double, &DataType::Boolean],
+            df.dtypes()
+        );
+        assert_eq!(DataEntry::Text("Zürich".to_owned()), df["city"].get(0).unwrap());
+        assert_eq!(DataEntry::Integer(139_111), df["population"].get(3).unwrap());
+        assert_eq!(DataEntry::Double(23.91), df["area"].get(2).unwrap());
+        assert_eq!(DataEntry::NA, df["area"].get(5).unwrap());
+        assert_eq!(DataEntry::Boolean(false), df["lake"].get(4).unwrap());
+    }
+
+    #[test]
+    fn missing_file() {
+        let result = DataFrame::read_csv("tests/data/does_not_exist.csv");
+        assert!(matches!(result, Err(RaccoonError::Io(_))));
+    }
+
+    #[test]
+    fn reader_options() {
+        let data = "metadata line\n\"a\"|\"b\"|\"c\"\n1|\"x|y\"|?\n2|z|4\n";
+        let df = CsvReader::new()
+            .delimiter(b'|')
+            .skip_rows(1)
+            .na_values(vec!["?"])
+            .read(data.as_bytes())
+            .unwrap();
+        assert_eq!(vec!["a", "b", "c"], df.columns());
+        assert_eq!(DataEntry::Text("x|y".to_owned()), df["b"].get(0).unwrap());
+        assert_eq!(DataEntry::NA, df["c"].get(0).unwrap());
+        assert_eq!(DataEntry::Integer(4), df["c"].get(1).unwrap());
+
+        let df = CsvReader::new()
+            .has_header(false)
+            .columns(vec!["2", "0"])
+            .read("1,a,true\n2,b,false\n".as_bytes())
+            .unwrap();
+        assert_eq!(vec!["0", "2"], df.columns());
+        assert_eq!(vec![&DataType::Integer, &DataType::Boolean], df.dtypes());
+
+        let result = CsvReader::new().columns(vec!["d"]).read("a,b\n1,2\n".as_bytes());
+        assert!(matches!(result, Err(RaccoonError::ColumnNotFound(ref name)) if name == "d"));
+    }
+
+    #[test]
+    fn explicit_types() {
+        let df = CsvReader::new()
+            .dtype("a", DataType::Float)
+            .dtype("b", DataType::Integer)
+            .read("a,b\n1.5,1\n2,x\n".as_bytes())
+            .unwrap();
+        assert_eq!(vec![&DataType::Float, &DataType::Integer], df.dtypes());
+        assert_eq!(DataEntry::Float(2.0), df["a"].get(1).unwrap());
+        assert_eq!(DataEntry::NA, df["b"].get(1).unwrap());
+
+        let result = CsvReader::new()
+            .dtype("b", DataType::Integer)
+            .strict(true)
+            .read("a,b\n1.5,1\n2,x\n".as_bytes());
+        match result {
+            Err(RaccoonError::Parse { row, column, value }) => {
+                assert_eq!((1, "b", "x"), (row, column.as_str(), value.as_str()));
+            },
+            _ => panic!("expected a parse error"),
+        }
+    }
+
+    #[test]
+    fn malformed_data() {
+        let result = CsvReader::new().read("a,b\n1,2\n3\n".as_bytes());
+        assert!(matches!(result, Err(RaccoonError::LengthMismatch { expected: 2, found: 1 })));
+
+        let df = CsvReader::new().read("".as_bytes()).unwrap();
+        assert_eq!((0, 0), df.shape());
+
+        let df = CsvReader::new().read("a,b\n".as_bytes()).unwrap();
+        assert_eq!((0, 2), df.shape());
+        assert_eq!(vec![&DataType::NA, &DataType::NA], df.dtypes());
+    }
+
+    #[test]
+    fn write_options() {
+        let mut values = Series::from_vector("value".to_owned(), vec![0.5f64, 1.0 / 3.0]);
+        values.push_entry(DataEntry::NA).unwrap();
+        let labels = Series::from_vector("label".to_owned(), vec!["plain", "with \"quotes\"", "with|pipe"]);
+        let df = DataFrame::from_series(vec![values, labels]).unwrap();
+
+        let mut buffer = Vec::new();
+        CsvWriter::new().write(&df, &mut buffer).unwrap();
+        assert_eq!(
+            "value,label\n0.5,plain\n0.3333333333333333,\"with \"\"quotes\"\"\"\n,with|pipe\n",
+            String::from_utf8(buffer).unwrap()
+        );
+
+        let mut buffer = Vec::new();
+        CsvWriter::new()
+            .delimiter(b'|')
+            .quote_style(QuoteStyle::NonNumeric)
+            .na_rep("NULL")
+            .float_precision(Some(3))
+            .has_header(false)
+            .write(&df, &mut buffer)
+            .unwrap();
+        assert_eq!(
+            "0.500|\"plain\"\n0.333|\"with \"\"quotes\"\"\"\n\"NULL\"|\"with|pipe\"\n",
+            String::from_utf8(buffer).unwrap()
+        );
+
+        let mut buffer = Vec::new();
+        CsvWriter::new().quote_style(QuoteStyle::Never).write(&df, &mut buffer).unwrap();
+        assert!(String::from_utf8(buffer).unwrap().ends_with(",with|pipe\n"));
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut ints = Series::from_vector("ints".to_owned(), vec![-1, 2]);
+        let mut longs = Series::from_vector("longs".to_owned(), vec![-1i64, 5_000_000_000]);
+        let mut doubles = Series::from_vector("doubles".to_owned(), vec![1.0f64, -2.5e-10]);
+        let mut bools = Series::from_vector("bools".to_owned(), vec![true, false]);
+        let mut chars = Series::from_vector("chars".to_owned(), vec!['a', 'ß']);
+        let mut texts = Series::from_vector("texts".to_owned(), vec!["a, b", "line\nbreak"]);
+        for series in [&mut ints, &mut longs, &mut doubles, &mut bools, &mut chars, &mut texts] {
+            series.push_entry(DataEntry::NA).unwrap();
+        }
+        let df = DataFrame::from_series(vec![ints, longs, doubles, bools, chars, texts]).unwrap();
+
+        let mut buffer = Vec::new();
+        df.to_csv(&mut buffer).unwrap();
+        let result = CsvReader::new().read(&buffer[..]).unwrap();
+        assert_eq!(df, result);
+
+        // types that cannot be inferred are kept using explicit data types
+        let mut floats = Series::from_vector("floats".to_owned(), vec![1.5f32, 2.0]);
+        floats.push_entry(DataEntry::NA).unwrap();
+        let mut buffer = Vec::new();
+        floats.to_csv(&mut buffer).unwrap();
+        let result = CsvReader::new().dtype("floats", DataType::Float).read(&buffer[..]).unwrap();
+        assert_eq!(&floats, &result["floats"]);
+    }
+}
@@ -0,0 +1,14 @@
+//! Reading and writing of [`DataFrame`]s from and to CSV files and Excel workbooks.
+//!
+//! [`DataFrame`]: ../dataframe/struct.DataFrame.html
+//!
+//! The readers in this module infer the [`DataType`] of every column from its content. See the documentation of each
+//! reader for the exact inference rules.
+//!
+//! [`DataType`]: ../entry/enum.DataType.html
+
+pub mod csv;
+pub mod xlsx;
+
+pub use self::csv::{CsvReader, CsvWriter, QuoteStyle};
+pub use self::xlsx::{Sheet, XlsxReader};
@@ -0,0 +1,362 @@
+//! Loading of [`DataFrame`]s from Excel workbooks (`.xlsx`).
+//!
+//! [`DataFrame`]: ../../dataframe/struct.DataFrame.html
+//!
+//! # Type Mapping
+//! Every column is given the data type matching the kind of its cells:
+//!
+//! - numbers become `DataType::Long` if all of them are whole numbers, and `DataType::Double` otherwise,
+//! - booleans become `DataType::Boolean`,
+//! - strings become `DataType::Text`,
+//! - empty cells and cells containing an error become `DataEntry::NA`.
+//!
+//! Dates and times are read as their numeric Excel representation. A column mixing several kinds of cells becomes
+//! `DataType::Text`, where every cell is converted to text using [`DataEntry::convert_to`]. A column containing only
+//! empty cells has type `DataType::NA`.
+//!
+//! [`DataEntry::convert_to`]: ../../entry/enum.DataEntry.html#method.convert_to
+//!
+//! # Examples
+//! ```
+//! use raccoon::{DataEntry, DataType, XlsxReader};
+//!
+//! let df = XlsxReader::new()
+//!     .sheet("report")
+//!     .range("B4:D7")
+//!     .read_path("tests/data/cities.xlsx")
+//!     .unwrap();
+//!
+//! assert_eq!(df.columns(), vec!["quarter", "revenue", "note"]);
+//! assert_eq!(df.dtypes(), vec![&DataType::Text, &DataType::Double, &DataType::Text]);
+//! assert_eq!(df["revenue"].get(2), Some(DataEntry::NA));
+//! ```
+
+use calamine::{open_workbook, Data, Range, Reader, Xlsx};
+
+use dataframe::DataFrame;
+use entry::{DataEntry, DataType};
+use error::{RaccoonResult, RaccoonError};
+use series::Series;
+
+use std::path::Path;
+
+/// Selects a sheet of a workbook either by name or by position.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Sheet {
+    /// The sheet with the given name.
+    Name(String),
+    /// The sheet at the given position, starting at 0.
+    Index(usize),
+}
+
+impl<'a> From<&'a str> for Sheet {
+    fn from(name: &'a str) -> Self {
+        Sheet::Name(name.to_owned())
+    }
+}
+
+impl From<String> for Sheet {
+    fn from(name: String) -> Self {
+        Sheet::Name(name)
+    }
+}
+
+impl From<usize> for Sheet {
+    fn from(idx: usize) -> Self {
+        Sheet::Index(idx)
+    }
+}
+
+/// A configurable reader building a [`DataFrame`] from a sheet of an Excel workbook.
+///
+/// [`DataFrame`]: ../../dataframe/struct.DataFrame.html
+///
+/// # Defaults
+/// - the first sheet of the workbook is read,
+/// - the first row is a header containing the column names,
+/// - all cells between the top-left and the bottom-right non-empty cells of the sheet are read.
+///
+/// # Example
+/// ```
+/// use raccoon::{DataType, XlsxReader};
+///
+/// let df = XlsxReader::new().sheet(0).read_path("tests/data/cities.xlsx").unwrap();
+/// assert_eq!(df.shape(), (6, 5));
+/// assert_eq!(df["population"].data_type(), &DataType::Long);
+/// ```
+#[derive(Debug, Clone)]
+pub struct XlsxReader {
+    sheet: Sheet,
+    has_header: bool,
+    range: Option<String>,
+}
+
+impl XlsxReader {
+    /// Constructs a new `XlsxReader` using the default options.
+    pub fn new() -> XlsxReader {
+        XlsxReader {
+            sheet: Sheet::Index(0),
+            has_header: true,
+            range: None,
+        }
+    }
+
+    /// Sets the sheet to read, either by name or by position.
+    pub fn sheet<S>(mut self, sheet: S) -> XlsxReader where S: Into<Sheet> {
+        self.sheet = sheet.into();
+        self
+    }
+
+    /// Sets whether the first row of the range contains the column names.
+    ///
+    /// If there is no header, the columns are named after their position in the range, starting at `"0"`. Empty
+    /// header cells are named the same way.
+    pub fn has_header(mut self, has_header: bool) -> XlsxReader {
+        self.has_header = has_header;
+        self
+    }
+
+    /// Restricts the cells read to a range in Excel's `A1:C10` notation. Both corners are included.
+    ///
+    /// An invalid range makes reading fail with `RaccoonError::InvalidRange`.
+    pub fn range<T>(mut self, range: T) -> XlsxReader where T: Into<String> {
+        self.range = Some(range.into());
+        self
+    }
+
+    /// Reads a `DataFrame` from the workbook at `path`.
+    pub fn read_path<P>(&self, path: P) -> RaccoonResult<DataFrame> where P: AsRef<Path> {
+        let mut workbook: Xlsx<_> = open_workbook(path)?;
+        let range = match self.sheet {
+            Sheet::Name(ref name) => {
+                if !workbook.sheet_names().contains(name) {
+                    return Err(RaccoonError::SheetNotFound(name.clone()));
+                }
+                workbook.worksheet_range(name)?
+            },
+            Sheet::Index(idx)     => {
+                match workbook.worksheet_range_at(idx) {
+                    Some(range) => range?,
+                    None        => return Err(RaccoonError::SheetNotFound(idx.to_string())),
+                }
+            },
+        };
+        self.build(&range)
+    }
+
+    /// Builds the dataframe from the cells of a worksheet.
+    fn build(&self, range: &Range<Data>) -> RaccoonResult<DataFrame> {
+        let (start, end) = match self.range {
+            Some(ref text) => parse_range(text).ok_or_else(|| RaccoonError::InvalidRange(text.clone()))?,
+            None           => {
+                match (range.start(), range.end()) {
+                    (Some(start), Some(end)) => (start, end),
+                    _                        => return Ok(DataFrame::new()),
+                }
+            },
+        };
+
+        let mut df = DataFrame::with_capacity((end.1 - start.1 + 1) as usize);
+        for col in start.1..=end.1 {
+            let mut rows = start.0..=end.0;
+            let position = (col - start.1).to_string();
+            let name = if self.has_header {
+                match rows.next().and_then(|row| range.get_value((row, col))) {
+                    Some(&Data::Empty) | None => position,
+                    Some(cell)                => text(&to_entry(cell)),
+                }
+            } else {
+                position
+            };
+            let entries: Vec<DataEntry> = rows.map(|row| range.get_value((row, col)).map_or(DataEntry::NA, to_entry))
+                                              .collect();
+            df.add_column(build_series(name, entries)?)?;
+        }
+        Ok(df)
+    }
+}
+
+impl Default for XlsxReader {
+    fn default() -> Self {
+        XlsxReader::new()
+    }
+}
+
+/// Converts a cell into an entry. Numbers are converted into `DataEntry::Double`.
+fn to_entry(cell: &Data) -> DataEntry {
+    match *cell {
+        Data::Int(int)              => DataEntry::Double(int as f64),
+        Data::Float(float)          => DataEntry::Double(float),
+        Data::Bool(boolean)         => DataEntry::Boolean(boolean),
+        Data::String(ref string)    => DataEntry::Text(string.clone()),
+        Data::DateTime(ref date)    => DataEntry::Double(date.as_f64()),
+        Data::DateTimeIso(ref iso)  => DataEntry::Text(iso.clone()),
+        Data::DurationIso(ref iso)  => DataEntry::Text(iso.clone()),
+        Data::Error(_)              => DataEntry::NA,
+        Data::Empty                 => DataEntry::NA,
+    }
+}
+
+/// Builds a series from the entries of a column, choosing its data type according to the module documentation.
+fn build_series(name: String, entries: Vec<DataEntry>) -> RaccoonResult<Series> {
+    let mut data_type = DataType::NA;
+    for entry in &entries {
+        let entry_type = entry.data_type();
+        if entry_type == DataType::NA || entry_type == data_type {
+            continue;
+        }
+        data_type = if data_type == DataType::NA { entry_type } else { DataType::Text };
+    }
+    if data_type == DataType::Double && entries.iter().all(is_whole) {
+        data_type = DataType::Long;
+    }
+
+    let mut series = Series::with_capacity(name, data_type.clone(), entries.len());
+    for entry in entries {
+        series.push_entry(entry.convert_to(&data_type))?;
+    }
+    Ok(series)
+}
+
+/// Checks if an entry is NA or a number without a fractional part that fits into a `i64`.
+fn is_whole(entry: &DataEntry) -> bool {
+    match *entry {
+        DataEntry::Double(float)    => float.fract() == 0.0 && float >= i64::MIN as f64 && float < i64::MAX as f64,
+        _                           => true,
+    }
+}
+
+/// Converts an entry into the text used as a column name.
+fn text(entry: &DataEntry) -> String {
+    let entry = match *entry {
+        DataEntry::Double(_) if is_whole(entry) => entry.convert_to(&DataType::Long),
+        _                                       => entry.clone(),
+    };
+    match entry.convert_to(&DataType::Text) {
+        DataEntry::Text(text)   => text,
+        _                       => String::new(),
+    }
+}
+
+/// Parses a range in `A1:C10` notation into zero-based `(row, column)` positions of its top-left and bottom-right
+/// corners.
+fn parse_range(range: &str) -> Option<((u32, u32), (u32, u32))> {
+    let mut corners = range.split(':');
+    let first = parse_cell(corners.next()?)?;
+    let second = match corners.next() {
+        Some(cell) => parse_cell(cell)?,
+        None       => first,
+    };
+    if corners.next().is_some() {
+        return None;
+    }
+    Some(((first.0.min(second.0), first.1.min(second.1)), (first.0.max(second.0), first.1.max(second.1))))
+}
+
+/// Parses a cell in `A1` notation into a zero-based `(row, column)` position.
+fn parse_cell(cell: &str) -> Option<(u32, u32)> {
+    let cell = cell.trim();
+    let split = cell.find(|ch: char| ch.is_ascii_digit())?;
+    let (letters, digits) = cell.split_at(split);
+    if letters.is_empty() || !letters.chars().all(|ch| ch.is_ascii_alphabetic()) {
+        return None;
+    }
+    let mut col: u32 = 0;
+    for ch in letters.chars() {
+        col = col.checked_mul(26)?.checked_add(ch.to_ascii_uppercase() as u32 - 'A' as u32 + 1)?;
+    }
+    let row: u32 = digits.parse().ok()?;
+    if row == 0 {
+        return None;
+    }
+    Some((row - 1, col - 1))
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cell_references() {
+        assert_eq!(Some((0, 0)), parse_cell("A1"));
+        assert_eq!(Some((9, 27)), parse_cell("ab10"));
+        assert_eq!(None, parse_cell("A0"));
+        assert_eq!(None, parse_cell("10"));
+        assert_eq!(None, parse_cell("A1B"));
+        assert_eq!(Some(((1, 1), (6, 3))), parse_range("B2:D7"));
+        assert_eq!(Some(((1, 1), (6, 3))), parse_range("D7:B2"));
+        assert_eq!(Some(((2, 2), (2, 2))), parse_range("C3"));
+        assert_eq!(None, parse_range("A1:B2:C3"));
+        assert_eq!(None, parse_range(""));
+    }
+
+    #[test]
+    fn read_sheet() {
+        let df = DataFrame::read_xlsx("tests/data/cities.xlsx", "cities").unwrap();
+        assert_eq!((6, 5), df.shape());
+        assert_eq!(vec!["city", "canton", "population", "area", "lake"], df.columns());
+        assert_eq!(
+            vec![&DataType::Text, &DataType::Text, &DataType::Long, &DataType::Double, &DataType::Boolean],
+            df.dtypes()
+        );
+        assert_eq!(DataEntry::Text("Zürich".to_owned()), df["city"].get(0).unwrap());
+        assert_eq!(DataEntry::Long(139_111), df["population"].get(3).unwrap());
+        assert_eq!(DataEntry::Double(23.91), df["area"].get(2).unwrap());
+        assert_eq!(DataEntry::NA, df["area"].get(5).unwrap());
+        assert_eq!(DataEntry::Boolean(false), df["lake"].get(4).unwrap());
+
+        // the csv fixture contains the same data
+        let csv = DataFrame::read_csv("tests/data/cities.csv").unwrap();
+        assert_eq!(csv["city"], df["city"]);
+        assert_eq!(csv["area"], df["area"]);
+    }
+
+    #[test]
+    fn read_range() {
+        let df = XlsxReader::new().sheet(1).range("B4:D8").read_path("tests/data/cities.xlsx").unwrap();
+        assert_eq!((4, 3), df.shape());
+        assert_eq!(vec![&DataType::Text, &DataType::Double, &DataType::Text], df.dtypes());
+        assert_eq!(DataEntry::Text("12".to_owned()), df["note"].get(1).unwrap());
+        assert_eq!(DataEntry::NA, df["note"].get(2).unwrap());
+        assert_eq!(DataEntry::Double(3380.5), df["revenue"].get(3).unwrap());
+
+        let df = XlsxReader::new()
+            .sheet("report")
+            .range("C5:C6")
+            .has_header(false)
+            .read_path("tests/data/cities.xlsx")
+            .unwrap();
+        assert_eq!(vec!["0"], df.columns());
+        assert_eq!(vec![&DataType::Double], df.dtypes());
+
+        // ranges may reach beyond the cells in use
+        let df = XlsxReader::new().sheet("report").range("A4:B5").read_path("tests/data/cities.xlsx").unwrap();
+        assert_eq!(vec!["0", "quarter"], df.columns());
+        assert_eq!(vec![&DataType::NA, &DataType::Text], df.dtypes());
+    }
+
+    #[test]
+    fn read_whole_sheet() {
+        let df = XlsxReader::new().sheet("report").has_header(false).read_path("tests/data/cities.xlsx").unwrap();
+        assert_eq!((7, 3), df.shape());
+        assert_eq!(DataEntry::Text("Quarterly report".to_owned()), df["0"].get(0).unwrap());
+        assert_eq!(DataEntry::NA, df["1"].get(0).unwrap());
+    }
+
+    #[test]
+    fn invalid_input() {
+        let result = DataFrame::read_xlsx("tests/data/cities.xlsx", "missing");
+        assert!(matches!(result, Err(RaccoonError::SheetNotFound(ref name)) if name == "missing"));
+
+        let result = DataFrame::read_xlsx("tests/data/cities.xlsx", 2);
+        assert!(matches!(result, Err(RaccoonError::SheetNotFound(ref name)) if name == "2"));
+
+        let result = XlsxReader::new().range("B:D").read_path("tests/data/cities.xlsx");
+        assert!(matches!(result, Err(RaccoonError::InvalidRange(ref range)) if range == "B:D"));
+
+        let result = DataFrame::read_xlsx("tests/data/cities.csv", 0);
+        assert!(matches!(result, Err(RaccoonError::Xlsx(_))));
+    }
+}
@@ -10,12 +10,46 @@
 
 #[macro_use]
 extern crate quick_error;
+extern crate csv;
+extern crate calamine;
+pub extern crate chrono;
+extern crate regex;
 
 pub mod error;
 pub mod entry;
+pub mod datetime;
 pub mod series;
+pub mod index;
+pub mod multiindex;
+pub mod align;
+pub mod aggregate;
 pub mod dataframe;
+pub mod groupby;
+pub mod merge;
+pub mod concat;
+pub mod reshape;
+pub mod missing;
+pub mod sort;
+pub mod strings;
+pub mod window;
+pub mod io;
+mod storage;
 
-pub use entry::{DataEntry, DataType};
-pub use series::Series;
+pub use entry::{DataEntry, DataType, OrderedEntry};
+pub use datetime::DatetimeAccessor;
+pub use series::{Operand, Series};
+pub use index::{Index, IndexKind, LabelSelector, PositionSelector};
+pub use multiindex::{Level, MultiIndex};
+pub use align::Alignment;
+pub use aggregate::Aggregator;
+pub use dataframe::DataFrame;
+pub use groupby::{Aggregation, GroupBy};
+pub use merge::{JoinType, Merge};
+pub use concat::{concat, Axis, Concat};
+pub use reshape::{Melt, PivotTable};
+pub use missing::{DropHow, Fill};
+pub use sort::{NaPosition, Sorter, SortOrder};
+pub use strings::{PadSide, StrAccessor};
+pub use window::Window;
+pub use io::{CsvReader, CsvWriter, QuoteStyle, Sheet, XlsxReader};
 pub use error::{RaccoonError, RaccoonResult};
@@ -0,0 +1,646 @@
+//! Database-style joins of two [`DataFrame`]s, similar to python's `pandas.merge`.
+//!
+//! [`DataFrame`]: ../dataframe/struct.DataFrame.html
+//!
+//! Rows of the left and the right frame are matched on the values of key columns using a hash join: the keys of one
+//! frame are hashed, and the rows of the other frame look up their matches, which takes linear time in the number of
+//! rows plus the number of rows of the result. Keys are compared like [`OrderedEntry`]s, hence numerical keys match
+//! across data types. Rows having a `DataEntry::NA` key do not match any row, unless [`Merge::match_na`] is set.
+//!
+//! [`OrderedEntry`]: ../entry/struct.OrderedEntry.html
+//! [`Merge::match_na`]: ./struct.Merge.html#method.match_na
+//!
+//! The labels of the [`Index`] of a frame can be used as its keys instead of key columns, see [`Merge::left_index`].
+//!
+//! [`Index`]: ../index/struct.Index.html
+//! [`Merge::left_index`]: ./struct.Merge.html#method.left_index
+//!
+//! # Result Layout
+//! The columns of the left frame come first, followed by the columns of the right frame. A key column having the same
+//! name in both frames appears once, holding the key of the left row, or of the right row if there is no left row,
+//! converted to the common data type of both key columns. Other columns present in both frames are renamed using
+//! suffixes, `_x` and `_y` by default.
+//!
+//! Rows keep the order of the left frame, except for right joins which keep the order of the right frame. A row
+//! matching several rows of the other frame is repeated for each match, in the order of the other frame. The rows of
+//! the right frame without a match are appended at the end of an outer join. Columns of the frame not taking part in a
+//! row are filled with `DataEntry::NA`.
+//!
+//! A join on the indexes of both frames is labelled by the label of the left row, or of the right row if there is no
+//! left row. Other joins are labelled by a range index.
+//!
+//! # Examples
+//! ```
+//! use raccoon::{DataEntry, DataFrame, JoinType, Series};
+//!
+//! let people = DataFrame::from_series(vec![
+//!     Series::from_vector("name".to_owned(), vec!["Anna", "Tom", "Eva"]),
+//!     Series::from_vector("city".to_owned(), vec!["ZH", "BE", "LU"]),
+//! ]).unwrap();
+//! let cities = DataFrame::from_series(vec![
+//!     Series::from_vector("city".to_owned(), vec!["BE", "ZH"]),
+//!     Series::from_vector("lake".to_owned(), vec![false, true]),
+//! ]).unwrap();
+//!
+//! let merged = people.merge(&cities, &["city"], JoinType::Left).unwrap();
+//! assert_eq!(merged.columns(), vec!["name", "city", "lake"]);
+//! assert_eq!(merged["lake"].get(0), Some(DataEntry::Boolean(true)));
+//! assert_eq!(merged["lake"].get(2), Some(DataEntry::NA));
+//! ```
+
+use dataframe::DataFrame;
+use entry::{DataEntry, OrderedEntry};
+use error::{RaccoonResult, RaccoonError};
+use index::Index;
+use multiindex::MultiIndex;
+use series::Series;
+
+use std::collections::HashMap;
+
+/// The type of join performed by a [`Merge`].
+///
+/// [`Merge`]: ./struct.Merge.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JoinType {
+    /// Only keeps the rows having a match in both frames.
+    Inner,
+    /// Keeps all the rows of the left frame.
+    Left,
+    /// Keeps all the rows of the right frame.
+    Right,
+    /// Keeps all the rows of both frames.
+    Outer,
+    /// Combines every row of the left frame with every row of the right frame, without key columns.
+    Cross,
+}
+
+/// A join of two dataframes using configurable options, see `raccoon::merge`.
+///
+/// # Example
+/// ```
+/// use raccoon::{DataFrame, JoinType, Merge, Series};
+///
+/// let orders = DataFrame::from_series(vec![
+///     Series::from_vector("customer".to_owned(), vec![2, 1, 2, 3]),
+///     Series::from_vector("amount".to_owned(), vec![20.0, 35.5, 12.0, 9.0]),
+/// ]).unwrap();
+/// let customers = DataFrame::from_series(vec![
+///     Series::from_vector("id".to_owned(), vec![1, 2, 4]),
+///     Series::from_vector("name".to_owned(), vec!["Anna", "Tom", "Eva"]),
+/// ]).unwrap();
+///
+/// let merged = Merge::new(&orders, &customers)
+///     .left_on(&["customer"])
+///     .right_on(&["id"])
+///     .how(JoinType::Outer)
+///     .indicator(true)
+///     .join()
+///     .unwrap();
+/// assert_eq!(merged.columns(), vec!["customer", "amount", "id", "name", "_merge"]);
+/// assert_eq!(merged["_merge"], vec!["both", "both", "both", "left_only", "right_only"]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Merge<'a> {
+    left: &'a DataFrame,
+    right: &'a DataFrame,
+    how: JoinType,
+    left_on: Vec<String>,
+    right_on: Vec<String>,
+    suffixes: (String, String),
+    indicator: bool,
+    match_na: bool,
+    left_index: bool,
+    right_index: bool,
+}
+
+/// The positions of the left and the right row forming each row of a join.
+pub(crate) type Pairs = Vec<(Option<usize>, Option<usize>)>;
+
+impl<'a> Merge<'a> {
+    /// Constructs a new inner `Merge` of `left` and `right`, without key columns.
+    pub fn new(left: &'a DataFrame, right: &'a DataFrame) -> Merge<'a> {
+        Merge {
+            left,
+            right,
+            how: JoinType::Inner,
+            left_on: Vec::new(),
+            right_on: Vec::new(),
+            suffixes: ("_x".to_owned(), "_y".to_owned()),
+            indicator: false,
+            match_na: false,
+            left_index: false,
+            right_index: false,
+        }
+    }
+
+    /// Sets the type of join. Defaults to `JoinType::Inner`.
+    pub fn how(mut self, how: JoinType) -> Merge<'a> {
+        self.how = how;
+        self
+    }
+
+    /// Sets the key columns, which have the same names in both frames.
+    pub fn on(self, on: &[&str]) -> Merge<'a> {
+        self.left_on(on).right_on(on)
+    }
+
+    /// Sets the key columns of the left frame.
+    pub fn left_on(mut self, left_on: &[&str]) -> Merge<'a> {
+        self.left_on = left_on.iter().map(|name| name.to_string()).collect();
+        self
+    }
+
+    /// Sets the key columns of the right frame, matched in order with the key columns of the left frame.
+    pub fn right_on(mut self, right_on: &[&str]) -> Merge<'a> {
+        self.right_on = right_on.iter().map(|name| name.to_string()).collect();
+        self
+    }
+
+    /// Sets whether the labels of the index of the left frame are used as its key, instead of key columns. Defaults to
+    /// false.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataEntry, DataFrame, JoinType, Merge, Series};
+    /// let prices = DataFrame::from_series(vec![
+    ///     Series::from_vector("item".to_owned(), vec!["pen", "ink"]),
+    ///     Series::from_vector("price".to_owned(), vec![2.5, 7.0]),
+    /// ]).unwrap().set_index("item").unwrap();
+    /// let orders = DataFrame::from_series(vec![
+    ///     Series::from_vector("item".to_owned(), vec!["ink", "pad", "ink"]),
+    /// ]).unwrap();
+    ///
+    /// let merged = Merge::new(&prices, &orders)
+    ///     .left_index(true)
+    ///     .right_on(&["item"])
+    ///     .how(JoinType::Right)
+    ///     .join()
+    ///     .unwrap();
+    /// assert_eq!(merged["price"], vec![DataEntry::Double(7.0), DataEntry::NA, DataEntry::Double(7.0)]);
+    /// ```
+    pub fn left_index(mut self, left_index: bool) -> Merge<'a> {
+        self.left_index = left_index;
+        self
+    }
+
+    /// Sets whether the labels of the index of the right frame are used as its key, instead of key columns. Defaults
+    /// to false.
+    pub fn right_index(mut self, right_index: bool) -> Merge<'a> {
+        self.right_index = right_index;
+        self
+    }
+
+    /// Sets the suffixes appended to the names of the columns present in both frames. Defaults to `_x` and `_y`.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataFrame, Merge, Series};
+    /// let left = DataFrame::from_series(vec![
+    ///     Series::from_vector("id".to_owned(), vec![1, 2]),
+    ///     Series::from_vector("value".to_owned(), vec![10, 20]),
+    /// ]).unwrap();
+    ///
+    /// let merged = Merge::new(&left, &left).on(&["id"]).suffixes("_old", "_new").join().unwrap();
+    /// assert_eq!(merged.columns(), vec!["id", "value_old", "value_new"]);
+    /// ```
+    pub fn suffixes<S, T>(mut self, left: S, right: T) -> Merge<'a> where S: Into<String>, T: Into<String> {
+        self.suffixes = (left.into(), right.into());
+        self
+    }
+
+    /// Sets whether a `_merge` column is added, telling whether each row comes from the left frame only
+    /// (`"left_only"`), the right frame only (`"right_only"`), or from both frames (`"both"`). Defaults to false.
+    pub fn indicator(mut self, indicator: bool) -> Merge<'a> {
+        self.indicator = indicator;
+        self
+    }
+
+    /// Sets whether `DataEntry::NA` keys match each other. Defaults to false.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataEntry, DataFrame, Merge, Series};
+    /// let keys = Series::from_vector("key".to_owned(), vec![DataEntry::NA, DataEntry::Integer(1)]);
+    /// let df = DataFrame::from_series(vec![keys]).unwrap();
+    ///
+    /// assert_eq!(Merge::new(&df, &df).on(&["key"]).join().unwrap().len(), 1);
+    /// assert_eq!(Merge::new(&df, &df).on(&["key"]).match_na(true).join().unwrap().len(), 2);
+    /// ```
+    pub fn match_na(mut self, match_na: bool) -> Merge<'a> {
+        self.match_na = match_na;
+        self
+    }
+
+    /// Joins the two frames.
+    ///
+    /// # Errors
+    /// - `RaccoonError::InvalidArgument` if no key is set for a join other than `JoinType::Cross`, if keys are set for
+    ///   a cross join, or if both key columns and the index are used as keys of a frame.
+    /// - `RaccoonError::LengthMismatch` if the left and right frames do not have as many keys.
+    /// - `RaccoonError::ColumnNotFound` if a key column does not exist.
+    /// - `RaccoonError::TypeMismatch` if a left key column and its right key column cannot be compared, for instance
+    ///   a text and a numerical column.
+    /// - `RaccoonError::DuplicateColumn` if a suffixed column name is already used.
+    pub fn join(&self) -> RaccoonResult<DataFrame> {
+        let left_labels = if self.left_index { Some(levels(self.left.index())) } else { None };
+        let right_labels = if self.right_index { Some(levels(self.right.index())) } else { None };
+        let (left_keys, right_keys) = self.keys(left_labels.as_deref(), right_labels.as_deref())?;
+        let pairs = match self.how {
+            JoinType::Cross => {
+                let rows = self.right.len();
+                (0..self.left.len() * rows).map(|idx| (Some(idx / rows), Some(idx % rows))).collect()
+            },
+            how             => join_pairs(&left_keys, &right_keys, how, self.match_na),
+        };
+        let df = self.combine(&pairs)?;
+        if self.left_index && self.right_index {
+            return df.with_index(join_index(self.left.index(), self.right.index(), &pairs));
+        }
+        Ok(df)
+    }
+
+    /// Returns the keys of the left and the right frame, after verifying them. The labels of the levels of the index of
+    /// a frame are given if they are used as its keys.
+    fn keys<'b>(&self, left_labels: Option<&'b [Series]>, right_labels: Option<&'b [Series]>)
+        -> RaccoonResult<(Vec<&'b Series>, Vec<&'b Series>)> where 'a: 'b {
+        let (left_index, right_index) = (left_labels.is_some(), right_labels.is_some());
+        let uses_keys = left_index || right_index || !(self.left_on.is_empty() && self.right_on.is_empty());
+        if self.how == JoinType::Cross && uses_keys {
+            return Err(RaccoonError::InvalidArgument("a cross join does not use key columns".to_owned()));
+        }
+        if self.how != JoinType::Cross && self.left_on.is_empty() && !left_index {
+            return Err(RaccoonError::InvalidArgument("merge requires at least one key column".to_owned()));
+        }
+        if (left_index && !self.left_on.is_empty()) || (right_index && !self.right_on.is_empty()) {
+            return Err(RaccoonError::InvalidArgument("merge uses either key columns or the index".to_owned()));
+        }
+        let left_keys = match left_labels {
+            Some(labels)    => labels.iter().collect(),
+            None            => columns(self.left, &self.left_on)?,
+        };
+        let right_keys = match right_labels {
+            Some(labels)    => labels.iter().collect(),
+            None            => columns(self.right, &self.right_on)?,
+        };
+        if left_keys.len() != right_keys.len() {
+            return Err(RaccoonError::LengthMismatch { expected: left_keys.len(), found: right_keys.len() });
+        }
+        for (left, right) in left_keys.iter().zip(right_keys.iter()) {
+            let (left_type, right_type) = (left.data_type(), right.data_type());
+            if left_type.common_type(right_type).is_none() {
+                return Err(RaccoonError::TypeMismatch { expected: left_type.clone(), found: right_type.clone() });
+            }
+        }
+        Ok((left_keys, right_keys))
+    }
+
+    /// Checks whether a column is a key column having the same name in both frames.
+    fn is_shared_key(&self, name: &str) -> bool {
+        self.left_on.iter().zip(self.right_on.iter()).any(|(left, right)| left == name && right == name)
+    }
+
+    /// Builds the joined frame from the positions of the rows of each side.
+    fn combine(&self, pairs: &[(Option<usize>, Option<usize>)]) -> RaccoonResult<DataFrame> {
+        let left_rows: Vec<Option<usize>> = pairs.iter().map(|pair| pair.0).collect();
+        let right_rows: Vec<Option<usize>> = pairs.iter().map(|pair| pair.1).collect();
+
+        let right_columns: Vec<&Series> = self.right.iter()
+            .filter(|series| !self.is_shared_key(series.name()))
+            .collect();
+        let mut df = DataFrame::with_capacity(self.left.shape().1 + right_columns.len() + 1);
+        for series in self.left.iter() {
+            let name = series.name();
+            let mut column = if self.is_shared_key(name) {
+                coalesce(series.take_or_na(&left_rows), &self.right[name].take_or_na(&right_rows))
+            } else {
+                series.take_or_na(&left_rows)
+            };
+            if right_columns.iter().any(|other| other.name() == name) {
+                column.set_name(format!("{}{}", name, self.suffixes.0));
+            }
+            df.add_column(column)?;
+        }
+        for series in right_columns {
+            let mut column = series.take_or_na(&right_rows);
+            if self.left.column(series.name()).is_some() {
+                column.set_name(format!("{}{}", series.name(), self.suffixes.1));
+            }
+            df.add_column(column)?;
+        }
+        if self.indicator {
+            let indicator = pairs.iter().map(|pair| match *pair {
+                (Some(_), Some(_))  => "both",
+                (Some(_), None)     => "left_only",
+                _                   => "right_only",
+            }).collect();
+            df.add_column(Series::from_vector("_merge".to_owned(), indicator))?;
+        }
+        Ok(df)
+    }
+}
+
+/// Matches the rows of two indexes having the same labels, returning the positions of the left and the right row
+/// forming each row of the join, ordered like the rows of `Merge::join`, along with the index of the join. Labels that
+/// are `DataEntry::NA` do not match.
+///
+/// Fails with `RaccoonError::InvalidArgument` for cross joins, and with `RaccoonError::TypeMismatch` if the labels of
+/// both indexes cannot be compared.
+pub(crate) fn align(left: &Index, right: &Index, how: JoinType) -> RaccoonResult<(Pairs, Index)> {
+    if how == JoinType::Cross {
+        return Err(RaccoonError::InvalidArgument("a cross join cannot align indexes".to_owned()));
+    }
+    if left.nlevels() != right.nlevels() {
+        return Err(RaccoonError::LengthMismatch { expected: left.nlevels(), found: right.nlevels() });
+    }
+    let (left_labels, right_labels) = (levels(left), levels(right));
+    for (left, right) in left_labels.iter().zip(right_labels.iter()) {
+        let (left_type, right_type) = (left.data_type(), right.data_type());
+        if left_type.common_type(right_type).is_none() {
+            return Err(RaccoonError::TypeMismatch { expected: left_type.clone(), found: right_type.clone() });
+        }
+    }
+    let left_keys: Vec<&Series> = left_labels.iter().collect();
+    let right_keys: Vec<&Series> = right_labels.iter().collect();
+    let pairs = join_pairs(&left_keys, &right_keys, how, false);
+    let index = join_index(left, right, &pairs);
+    Ok((pairs, index))
+}
+
+/// Returns the index of a join on the indexes of both frames, labelling each row by the labels of its left row, or of
+/// its right row if there is no left row. Both indexes have the same number of levels.
+fn join_index(left: &Index, right: &Index, pairs: &[(Option<usize>, Option<usize>)]) -> Index {
+    let left_rows: Vec<Option<usize>> = pairs.iter().map(|pair| pair.0).collect();
+    let right_rows: Vec<Option<usize>> = pairs.iter().map(|pair| pair.1).collect();
+    let levels = left.levels().iter().zip(right.levels().iter())
+        .map(|(left, right)| {
+            let labels = coalesce(left.to_series().take_or_na(&left_rows), &right.to_series().take_or_na(&right_rows));
+            let level = Index::from_labels(labels);
+            match left.name().or(right.name()) {
+                Some(name)  => level.with_name(name),
+                None        => level,
+            }
+        })
+        .collect();
+    Index::from(MultiIndex::new(levels).expect("levels of a join have the same length"))
+}
+
+/// Returns the labels of every level of `index`.
+fn levels(index: &Index) -> Vec<Series> {
+    index.levels().iter().map(Index::to_series).collect()
+}
+
+/// Matches the rows of the left and the right keys for a join other than `JoinType::Cross`.
+fn join_pairs(left_keys: &[&Series], right_keys: &[&Series], how: JoinType, match_na: bool) -> Pairs {
+    match how {
+        JoinType::Right => {
+            hash_join(right_keys, left_keys, true, false, match_na).into_iter()
+                .map(|(right, left)| (left, right))
+                .collect()
+        },
+        how             => hash_join(left_keys, right_keys, how != JoinType::Inner, how == JoinType::Outer, match_na),
+    }
+}
+
+/// Returns the columns of `df` named `names`.
+fn columns<'a>(df: &'a DataFrame, names: &[String]) -> RaccoonResult<Vec<&'a Series>> {
+    names.iter().map(|name| df.column(name).ok_or_else(|| RaccoonError::ColumnNotFound(name.clone()))).collect()
+}
+
+/// Returns the keys of the rows of `columns` in order, each being `None` if it contains `DataEntry::NA` and NA keys do
+/// not match.
+fn keys<'a>(columns: &'a [&Series], match_na: bool) -> impl Iterator<Item = Option<Vec<OrderedEntry>>> + 'a {
+    let len = columns.first().map_or(0, |series| series.len());
+    let mut entries: Vec<_> = columns.iter().map(|series| series.iter()).collect();
+    (0..len).map(move |_| {
+        let key: Vec<OrderedEntry> = entries.iter_mut()
+            .map(|iter| OrderedEntry(iter.next().unwrap_or(DataEntry::NA)))
+            .collect();
+        if !match_na && key.iter().any(|entry| entry.0 == DataEntry::NA) {
+            return None;
+        }
+        Some(key)
+    })
+}
+
+/// Matches the rows of the probe side with the rows of the build side having the same key, by hashing the keys of the
+/// build side. Unmatched rows of the probe side are kept in place if `keep_probe` is set, and unmatched rows of the
+/// build side are appended if `keep_build` is set.
+fn hash_join(probe: &[&Series], build: &[&Series], keep_probe: bool, keep_build: bool, match_na: bool) -> Pairs {
+    // rows sharing a key are chained in order: the table holds the first and last row of each key, and `next` links
+    // each row to the following row having the same key
+    let build_len = build.first().map_or(0, |series| series.len());
+    let mut table: HashMap<Vec<OrderedEntry>, (usize, usize)> = HashMap::with_capacity(build_len);
+    let mut next = vec![None; build_len];
+    for (row, key) in keys(build, match_na).enumerate() {
+        if let Some(key) = key {
+            let chain = table.entry(key).or_insert((row, row));
+            if chain.1 != row {
+                next[chain.1] = Some(row);
+                chain.1 = row;
+            }
+        }
+    }
+
+    let mut pairs = Vec::new();
+    let mut matched = vec![false; build_len];
+    for (row, key) in keys(probe, match_na).enumerate() {
+        let mut other = key.and_then(|key| table.get(&key)).map(|chain| chain.0);
+        if other.is_none() && keep_probe {
+            pairs.push((Some(row), None));
+        }
+        while let Some(idx) = other {
+            matched[idx] = true;
+            pairs.push((Some(row), Some(idx)));
+            other = next[idx];
+        }
+    }
+    if keep_build {
+        pairs.extend((0..build_len).filter(|&row| !matched[row]).map(|row| (None, Some(row))));
+    }
+    pairs
+}
+
+/// Merges two key columns, taking the entries of `left` and falling back to the entries of `right` where `left` is
+/// `DataEntry::NA`. The result has the common data type of both columns.
+fn coalesce(left: Series, right: &Series) -> Series {
+    let data_type = left.data_type().common_type(right.data_type()).unwrap_or_else(|| left.data_type().clone());
+    let entries: Vec<DataEntry> = left.iter().zip(right.iter())
+        .map(|(left, right)| if left == DataEntry::NA { right } else { left })
+        .collect();
+    Series::from_entries(left.name().to_owned(), data_type, entries)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use entry::DataType;
+
+    #[test]
+    fn join_types() {
+        let left = DataFrame::from_series(vec![
+            Series::from_vector("name".to_owned(), vec!["Anna", "Tom", "Eva", "Max"]),
+            Series::from_vector("dept".to_owned(), vec![
+                DataEntry::Integer(1), DataEntry::Integer(2), DataEntry::Integer(1), DataEntry::NA,
+            ]),
+        ]).unwrap();
+        let right = DataFrame::from_series(vec![
+            Series::from_vector("dept".to_owned(), vec![
+                DataEntry::Long(3), DataEntry::Long(1), DataEntry::NA,
+            ]),
+            Series::from_vector("name".to_owned(), vec!["Sales", "Research", "Unknown"]),
+        ]).unwrap();
+
+        let inner = left.merge(&right, &["dept"], JoinType::Inner).unwrap();
+        assert_eq!(vec!["name_x", "dept", "name_y"], inner.columns());
+        assert_eq!(inner["name_x"], vec!["Anna", "Eva"]);
+        assert_eq!(inner["name_y"], vec!["Research", "Research"]);
+        assert_eq!(&DataType::Long, inner["dept"].data_type());
+
+        let left_join = left.merge(&right, &["dept"], JoinType::Left).unwrap();
+        assert_eq!(4, left_join.len());
+        assert_eq!(left_join["name_y"].get(1).unwrap(), DataEntry::NA);
+        assert_eq!(left_join["name_y"].get(3).unwrap(), DataEntry::NA);
+
+        let right_join = left.merge(&right, &["dept"], JoinType::Right).unwrap();
+        assert_eq!(right_join["name_y"], vec!["Sales", "Research", "Research", "Unknown"]);
+        assert_eq!(right_join["name_x"].get(0).unwrap(), DataEntry::NA);
+        assert_eq!(right_join["dept"].get(0).unwrap(), DataEntry::Long(3));
+        assert_eq!(right_join["dept"].get(3).unwrap(), DataEntry::NA);
+
+        let outer = left.merge(&right, &["dept"], JoinType::Outer).unwrap();
+        assert_eq!(6, outer.len());
+        assert_eq!(outer["name_x"].get(4).unwrap(), DataEntry::NA);
+        assert_eq!(outer["name_y"].get(4).unwrap(), DataEntry::from("Sales"));
+        assert_eq!(outer["name_y"].get(5).unwrap(), DataEntry::from("Unknown"));
+
+        let cross = Merge::new(&left, &right).how(JoinType::Cross).join().unwrap();
+        assert_eq!((12, 4), cross.shape());
+        assert_eq!(vec!["name_x", "dept_x", "dept_y", "name_y"], cross.columns());
+        assert_eq!(cross["name_x"].get(3).unwrap(), DataEntry::from("Tom"));
+        assert_eq!(cross["name_y"].get(3).unwrap(), DataEntry::from("Sales"));
+    }
+
+    #[test]
+    fn options() {
+        let left = DataFrame::from_series(vec![
+            Series::from_vector("name".to_owned(), vec!["Anna", "Tom", "Eva", "Max"]),
+            Series::from_vector("dept".to_owned(), vec![
+                DataEntry::Integer(1), DataEntry::Integer(2), DataEntry::Integer(1), DataEntry::NA,
+            ]),
+        ]).unwrap();
+        let right = DataFrame::from_series(vec![
+            Series::from_vector("dept".to_owned(), vec![
+                DataEntry::Long(3), DataEntry::Long(1), DataEntry::NA,
+            ]),
+            Series::from_vector("name".to_owned(), vec!["Sales", "Research", "Unknown"]),
+        ]).unwrap();
+
+        let merged = Merge::new(&left, &right)
+            .how(JoinType::Outer)
+            .on(&["dept"])
+            .match_na(true)
+            .indicator(true)
+            .suffixes("", "_dept")
+            .join()
+            .unwrap();
+        assert_eq!(vec!["name", "dept", "name_dept", "_merge"], merged.columns());
+        assert_eq!(merged["_merge"], vec!["both", "left_only", "both", "both", "right_only"]);
+        assert_eq!(merged["name_dept"].get(3).unwrap(), DataEntry::from("Unknown"));
+
+        let renamed = DataFrame::from_series(vec![
+            Series::from_vector("id".to_owned(), vec![2.0, 1.0]),
+            Series::from_vector("budget".to_owned(), vec![100, 250]),
+        ]).unwrap();
+        let merged = Merge::new(&left, &renamed).left_on(&["dept"]).right_on(&["id"]).join().unwrap();
+        assert_eq!(vec!["name", "dept", "id", "budget"], merged.columns());
+        assert_eq!(merged["budget"], vec![250, 100, 250]);
+        assert_eq!(merged["id"], vec![1.0, 2.0, 1.0]);
+    }
+
+    #[test]
+    fn multiple_keys() {
+        let left = DataFrame::from_series(vec![
+            Series::from_vector("a".to_owned(), vec![1, 1, 2]),
+            Series::from_vector("b".to_owned(), vec!['x', 'y', 'x']),
+            Series::from_vector("v".to_owned(), vec![10, 20, 30]),
+        ]).unwrap();
+        let right = DataFrame::from_series(vec![
+            Series::from_vector("b".to_owned(), vec!['x', 'x', 'y']),
+            Series::from_vector("a".to_owned(), vec![2, 1, 1]),
+            Series::from_vector("w".to_owned(), vec![true, false, true]),
+        ]).unwrap();
+        let merged = left.merge(&right, &["a", "b"], JoinType::Inner).unwrap();
+        assert_eq!(vec!["a", "b", "v", "w"], merged.columns());
+        assert_eq!(merged["v"], vec![10, 20, 30]);
+        assert_eq!(merged["w"], vec![false, true, true]);
+    }
+
+    #[test]
+    fn index_keys() {
+        let left = DataFrame::from_series(vec![
+            Series::from_vector("name".to_owned(), vec!["Anna", "Tom", "Eva", "Max"]),
+            Series::from_vector("dept".to_owned(), vec![
+                DataEntry::Integer(1), DataEntry::Integer(2), DataEntry::Integer(1), DataEntry::NA,
+            ]),
+        ]).unwrap();
+        let right = DataFrame::from_series(vec![
+            Series::from_vector("dept".to_owned(), vec![
+                DataEntry::Long(3), DataEntry::Long(1), DataEntry::NA,
+            ]),
+            Series::from_vector("name".to_owned(), vec!["Sales", "Research", "Unknown"]),
+        ]).unwrap().set_index("dept").unwrap();
+        let merged = Merge::new(&left, &right).left_on(&["dept"]).right_index(true).join().unwrap();
+        assert_eq!(vec!["name_x", "dept", "name_y"], merged.columns());
+        assert_eq!(merged["name_y"], vec!["Research", "Research"]);
+        assert_eq!(merged.index(), &Index::range(2));
+
+        let left = left.set_index("dept").unwrap();
+        let joined = left.join(&right, JoinType::Outer).unwrap();
+        assert_eq!(joined.index().name(), Some("dept"));
+        assert_eq!(joined.index().to_series(), vec![
+            DataEntry::Long(1), DataEntry::Long(2), DataEntry::Long(1), DataEntry::NA, DataEntry::Long(3),
+            DataEntry::NA,
+        ]);
+        assert_eq!(joined["name_y"].get(2).unwrap(), DataEntry::from("Research"));
+        assert_eq!(joined["name_x"].get(5).unwrap(), DataEntry::NA);
+
+        let joined = left.join(&right, JoinType::Inner).unwrap();
+        assert_eq!(joined.loc(1).unwrap()["name_x"], vec!["Anna", "Eva"]);
+
+        let result = Merge::new(&left, &right).on(&["name"]).left_index(true).join();
+        assert!(matches!(result, Err(RaccoonError::InvalidArgument(_))));
+        let result = Merge::new(&left, &right).left_index(true).how(JoinType::Cross).join();
+        assert!(matches!(result, Err(RaccoonError::InvalidArgument(_))));
+        let result = Merge::new(&left, &right).left_index(true).right_on(&["name"]).join();
+        assert!(matches!(result, Err(RaccoonError::TypeMismatch { .. })));
+    }
+
+    #[test]
+    fn errors() {
+        let left = DataFrame::from_series(vec![
+            Series::from_vector("name".to_owned(), vec!["Anna", "Tom", "Eva", "Max"]),
+            Series::from_vector("dept".to_owned(), vec![
+                DataEntry::Integer(1), DataEntry::Integer(2), DataEntry::Integer(1), DataEntry::NA,
+            ]),
+        ]).unwrap();
+        let right = DataFrame::from_series(vec![
+            Series::from_vector("dept".to_owned(), vec![
+                DataEntry::Long(3), DataEntry::Long(1), DataEntry::NA,
+            ]),
+            Series::from_vector("name".to_owned(), vec!["Sales", "Research", "Unknown"]),
+        ]).unwrap();
+        assert!(matches!(left.merge(&right, &[], JoinType::Inner), Err(RaccoonError::InvalidArgument(_))));
+        assert!(matches!(left.merge(&right, &["dept"], JoinType::Cross), Err(RaccoonError::InvalidArgument(_))));
+        assert!(matches!(left.merge(&right, &["id"], JoinType::Left), Err(RaccoonError::ColumnNotFound(_))));
+        let result = Merge::new(&left, &right).left_on(&["dept"]).right_on(&["dept", "name"]).join();
+        assert!(matches!(result, Err(RaccoonError::LengthMismatch { expected: 1, found: 2 })));
+        let result = left.merge(&right, &["name"], JoinType::Inner);
+        assert!(result.is_ok());
+        let result = Merge::new(&left, &right).left_on(&["dept"]).right_on(&["name"]).join();
+        assert!(matches!(result,
+            Err(RaccoonError::TypeMismatch { expected: DataType::Integer, found: DataType::Text })));
+        let result = Merge::new(&left, &right).on(&["dept"]).suffixes("", "").join();
+        assert!(matches!(result, Err(RaccoonError::DuplicateColumn(_))));
+    }
+}
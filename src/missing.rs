@@ -0,0 +1,54 @@
+//! Detection and repair of missing values in [`Series`] and [`DataFrame`]s.
+//!
+//! [`Series`]: ../series/struct.Series.html
+//! [`DataFrame`]: ../dataframe/struct.DataFrame.html
+//!
+//! An entry is missing if it is `DataEntry::NA` or a NaN floating point number, see [`DataEntry::is_missing`].
+//! Missing values appear in data read from files, and are produced by conversions and arithmetic that cannot be
+//! performed. They can be detected with `Series::isna` and `Series::notna`, removed with `dropna`, or replaced with
+//! `fillna` and `Series::interpolate`.
+//!
+//! [`DataEntry::is_missing`]: ../entry/enum.DataEntry.html#method.is_missing
+//!
+//! # Examples
+//! ```
+//! use raccoon::{DataEntry, DataFrame, DropHow, Fill, Series};
+//! use std::collections::HashMap;
+//!
+//! let df = DataFrame::from_series(vec![
+//!     Series::from_vector("day".to_owned(), vec![1, 2, 3, 4]),
+//!     Series::from_vector("temperature".to_owned(), vec![
+//!         DataEntry::Double(12.5), DataEntry::NA, DataEntry::Double(f64::NAN), DataEntry::Double(15.5),
+//!     ]),
+//! ]).unwrap();
+//!
+//! assert_eq!(df["temperature"].isna(), vec![false, true, true, false]);
+//! assert_eq!(df["temperature"].interpolate().unwrap(), vec![12.5f64, 13.5, 14.5, 15.5]);
+//! assert_eq!(df.dropna(DropHow::Any, &[]).unwrap()["day"], vec![1, 4]);
+//!
+//! let mut fills = HashMap::new();
+//! fills.insert("temperature", Fill::Forward);
+//! assert_eq!(df.fillna(&fills).unwrap()["temperature"], vec![12.5f64, 12.5, 12.5, 15.5]);
+//! ```
+
+use entry::DataEntry;
+
+/// The strategy used to replace missing values by `fillna`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Fill {
+    /// Replaces missing values with the given entry, converted to the data type of the series.
+    Value(DataEntry),
+    /// Replaces missing values with the last preceding entry that is not missing. Leading missing values are kept.
+    Forward,
+    /// Replaces missing values with the first following entry that is not missing. Trailing missing values are kept.
+    Backward,
+}
+
+/// The condition under which `DataFrame::dropna` drops a row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DropHow {
+    /// Drops rows having a missing value in any of the considered columns.
+    Any,
+    /// Drops rows having missing values in all of the considered columns.
+    All,
+}
@@ -0,0 +1,418 @@
+//! Hierarchical labels of the rows or the columns of a [`DataFrame`], similar to python's `pandas.MultiIndex`.
+//!
+//! [`DataFrame`]: ../dataframe/struct.DataFrame.html
+//!
+//! A [`MultiIndex`] labels every row with a tuple of `DataEntry` labels, one for each of its levels. Every level is an
+//! [`Index`], which can be named. Levels are identified by a [`Level`], either their position or their name.
+//!
+//! A dataframe whose rows are labelled by a multi index has an [`Index`] of kind `IndexKind::Multi`, built from
+//! several columns using [`DataFrame::set_multi_index`]. Single labels select rows by the first level, as with
+//! [`DataFrame::loc`], while [`DataFrame::xs`] selects rows by the labels of any level. The levels can be swapped with
+//! [`DataFrame::swaplevel`] and the rows sorted by level with [`DataFrame::sort_level`].
+//!
+//! [`DataFrame::unstack`] moves a level of the rows into the columns, which are then labelled by a multi index as
+//! well, see [`DataFrame::column_index`]. [`DataFrame::stack`] moves the last level of the columns back into the rows.
+//! The names of columns built from labels are the labels converted into texts, joined with `_`. Distinct labels giving
+//! the same name, such as `("a_b", "c")` and `("a", "b_c")`, are told apart by suffixes `.1`, `.2`, ... in order.
+//!
+//! [`MultiIndex`]: ./struct.MultiIndex.html
+//! [`Index`]: ../index/struct.Index.html
+//! [`Level`]: ./enum.Level.html
+//! [`DataFrame::set_multi_index`]: ../dataframe/struct.DataFrame.html#method.set_multi_index
+//! [`DataFrame::loc`]: ../dataframe/struct.DataFrame.html#method.loc
+//! [`DataFrame::xs`]: ../dataframe/struct.DataFrame.html#method.xs
+//! [`DataFrame::swaplevel`]: ../dataframe/struct.DataFrame.html#method.swaplevel
+//! [`DataFrame::sort_level`]: ../dataframe/struct.DataFrame.html#method.sort_level
+//! [`DataFrame::unstack`]: ../dataframe/struct.DataFrame.html#method.unstack
+//! [`DataFrame::column_index`]: ../dataframe/struct.DataFrame.html#method.column_index
+//! [`DataFrame::stack`]: ../dataframe/struct.DataFrame.html#method.stack
+//!
+//! # Examples
+//! ```
+//! use raccoon::{DataEntry, DataFrame, IndexKind, Series};
+//!
+//! let df = DataFrame::from_series(vec![
+//!     Series::from_vector("canton".to_owned(), vec!["ZH", "ZH", "BE", "BE"]),
+//!     Series::from_vector("year".to_owned(), vec![2020, 2021, 2020, 2021]),
+//!     Series::from_vector("births".to_owned(), vec![16_324, 16_070, 10_075, 10_127]),
+//! ]).unwrap().set_multi_index(&["canton", "year"]).unwrap();
+//! assert_eq!(df.index().kind(), IndexKind::Multi);
+//! assert_eq!(df.index().nlevels(), 2);
+//!
+//! // rows of a label of the first level
+//! assert_eq!(df.loc("BE").unwrap()["births"], vec![10_075, 10_127]);
+//!
+//! // rows of a label of another level, without this level
+//! let year = df.xs(2021, "year").unwrap();
+//! assert_eq!(year.index().to_series(), vec!["ZH", "BE"]);
+//! assert_eq!(year["births"], vec![16_070, 10_127]);
+//!
+//! // years in columns
+//! let wide = df.unstack("year").unwrap();
+//! assert_eq!(wide.columns(), vec!["births_2020", "births_2021"]);
+//! assert_eq!(wide.index().to_series(), vec!["ZH", "BE"]);
+//! assert_eq!(wide["births_2021"], vec![16_070, 10_127]);
+//!
+//! let long = wide.stack().unwrap();
+//! assert_eq!(long.columns(), vec!["births"]);
+//! assert_eq!(long.index(), df.index());
+//! ```
+
+use entry::{DataEntry, DataType, OrderedEntry};
+use error::{RaccoonResult, RaccoonError};
+use index::{Index, IndexKind};
+use series::Series;
+use sort::{Sorter, SortOrder};
+
+use std::collections::HashSet;
+
+/// A level of a [`MultiIndex`], identified by its position or by its name. It is built implicitly from a `usize` or
+/// a string.
+///
+/// [`MultiIndex`]: ./struct.MultiIndex.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Level {
+    /// The level at a position, starting from the outermost level.
+    Position(usize),
+    /// The level having a name.
+    Name(String),
+}
+
+impl From<usize> for Level {
+    fn from(position: usize) -> Self {
+        Level::Position(position)
+    }
+}
+
+impl<'a> From<&'a str> for Level {
+    fn from(name: &'a str) -> Self {
+        Level::Name(name.to_owned())
+    }
+}
+
+impl From<String> for Level {
+    fn from(name: String) -> Self {
+        Level::Name(name)
+    }
+}
+
+/// Labels made of one entry for every level, see `raccoon::multiindex`.
+///
+/// # Example
+/// ```
+/// use raccoon::{DataEntry, Index, MultiIndex};
+///
+/// let index = MultiIndex::new(vec![
+///     Index::from(vec!['a', 'a', 'b']).with_name("letter"),
+///     Index::from(vec![1, 2, 1]).with_name("number"),
+/// ]).unwrap();
+/// assert_eq!(index.nlevels(), 2);
+/// assert_eq!(index.get(1), Some(vec![DataEntry::from('a'), DataEntry::Integer(2)]));
+/// assert_eq!(index.positions(&[DataEntry::from('a')]), vec![0, 1]);
+/// assert_eq!(index.positions(&[DataEntry::from('b'), DataEntry::Integer(1)]), vec![2]);
+///
+/// let swapped = index.swaplevel(0, "number").unwrap();
+/// assert_eq!(swapped.names(), vec![Some("number"), Some("letter")]);
+/// assert_eq!(swapped.argsort(0, true).unwrap(), vec![0, 2, 1]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultiIndex {
+    levels: Vec<Index>,
+}
+
+impl MultiIndex {
+    /// Constructs a multi index from its levels, from the outermost to the innermost.
+    ///
+    /// # Errors
+    /// - `RaccoonError::InvalidArgument` if there are no levels, or if a level is itself a multi index.
+    /// - `RaccoonError::LengthMismatch` if the levels do not all have the same length.
+    pub fn new(levels: Vec<Index>) -> RaccoonResult<MultiIndex> {
+        let len = match levels.first() {
+            Some(level) => level.len(),
+            None        => return Err(RaccoonError::InvalidArgument("a multi index needs a level".to_owned())),
+        };
+        if levels.iter().any(|level| level.kind() == IndexKind::Multi) {
+            return Err(RaccoonError::InvalidArgument("the level of a multi index cannot have levels".to_owned()));
+        }
+        if let Some(level) = levels.iter().find(|level| level.len() != len) {
+            return Err(RaccoonError::LengthMismatch { expected: len, found: level.len() });
+        }
+        Ok(MultiIndex { levels })
+    }
+
+    /// Constructs a multi index whose levels hold the entries of `levels`, and are named after them.
+    ///
+    /// # Errors
+    /// See [`new`](#method.new).
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{MultiIndex, Series};
+    /// let index = MultiIndex::from_series(vec![
+    ///     Series::from_vector("x".to_owned(), vec![1, 2]),
+    ///     Series::from_vector("y".to_owned(), vec![3, 4]),
+    /// ]).unwrap();
+    /// assert_eq!(index.names(), vec![Some("x"), Some("y")]);
+    /// ```
+    pub fn from_series(levels: Vec<Series>) -> RaccoonResult<MultiIndex> {
+        MultiIndex::new(levels.into_iter().map(Index::from_series).collect())
+    }
+
+    /// Returns the number of levels.
+    pub fn nlevels(&self) -> usize {
+        self.levels.len()
+    }
+
+    /// Returns the number of labels.
+    pub fn len(&self) -> usize {
+        self.levels[0].len()
+    }
+
+    /// Checks whether the index contains no labels.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the levels, from the outermost to the innermost.
+    pub fn levels(&self) -> &[Index] {
+        &self.levels
+    }
+
+    /// Returns the names of the levels.
+    pub fn names(&self) -> Vec<Option<&str>> {
+        self.levels.iter().map(|level| level.name()).collect()
+    }
+
+    /// Returns a level, selected by position or by name.
+    ///
+    /// # Errors
+    /// `RaccoonError::LevelNotFound` if there is no such level.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{Index, MultiIndex};
+    /// let index = MultiIndex::new(vec![Index::from(vec![1, 2]).with_name("x"), Index::from(vec![3, 4])]).unwrap();
+    /// assert_eq!(index.level("x").unwrap(), index.level(0).unwrap());
+    /// assert!(index.level(2).is_err());
+    /// ```
+    pub fn level<L>(&self, level: L) -> RaccoonResult<&Index> where L: Into<Level> {
+        Ok(&self.levels[self.position(level)?])
+    }
+
+    /// Returns the labels at `position`, one for every level, or `None` if the position is out of bounds.
+    pub fn get(&self, position: usize) -> Option<Vec<DataEntry>> {
+        self.levels.iter().map(|level| level.get(position)).collect()
+    }
+
+    /// Returns the positions of the rows whose first levels are labelled by `key`, in ascending order. The key holds a
+    /// label for each of the outermost levels, and may be shorter than the number of levels.
+    pub fn positions(&self, key: &[DataEntry]) -> Vec<usize> {
+        if key.len() > self.levels.len() {
+            return Vec::new();
+        }
+        let mut positions = match key.first() {
+            Some(label) => self.levels[0].positions(label),
+            None        => return (0..self.len()).collect(),
+        };
+        for (level, label) in self.levels.iter().zip(key.iter()).skip(1) {
+            let matching: HashSet<usize> = level.positions(label).into_iter().collect();
+            positions.retain(|position| matching.contains(position));
+        }
+        positions
+    }
+
+    /// Checks whether no tuple of labels is repeated.
+    pub fn is_unique(&self) -> bool {
+        let mut keys = HashSet::with_capacity(self.len());
+        (0..self.len()).all(|position| keys.insert(self.key(position)))
+    }
+
+    /// Returns a copy of the multi index in which levels `first` and `second` are swapped.
+    ///
+    /// # Errors
+    /// `RaccoonError::LevelNotFound` if either level does not exist.
+    pub fn swaplevel<L, M>(&self, first: L, second: M) -> RaccoonResult<MultiIndex>
+        where L: Into<Level>, M: Into<Level> {
+        let (first, second) = (self.position(first)?, self.position(second)?);
+        let mut levels = self.levels.clone();
+        levels.swap(first, second);
+        Ok(MultiIndex { levels })
+    }
+
+    /// Returns the index without `level`: an index of kind `IndexKind::Multi` if several levels remain, and the
+    /// remaining level otherwise.
+    ///
+    /// # Errors
+    /// - `RaccoonError::LevelNotFound` if the level does not exist.
+    /// - `RaccoonError::InvalidArgument` if it is the only level.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{Index, IndexKind, MultiIndex};
+    /// let index = MultiIndex::new(vec![Index::from(vec![1, 2]).with_name("x"), Index::from(vec![3, 4])]).unwrap();
+    /// let dropped = index.droplevel("x").unwrap();
+    /// assert_eq!(dropped.kind(), IndexKind::Label);
+    /// assert_eq!(dropped.to_series(), vec![3, 4]);
+    /// ```
+    pub fn droplevel<L>(&self, level: L) -> RaccoonResult<Index> where L: Into<Level> {
+        let position = self.position(level)?;
+        if self.levels.len() == 1 {
+            return Err(RaccoonError::InvalidArgument("cannot drop the only level of an index".to_owned()));
+        }
+        let mut levels = self.levels.clone();
+        levels.remove(position);
+        Ok(Index::from(MultiIndex { levels }))
+    }
+
+    /// Returns the positions of the labels sorted by `level`, then by the other levels from the outermost to the
+    /// innermost. Missing labels are placed last, and equal tuples keep their original order.
+    ///
+    /// # Errors
+    /// `RaccoonError::LevelNotFound` if the level does not exist.
+    pub fn argsort<L>(&self, level: L, ascending: bool) -> RaccoonResult<Vec<usize>> where L: Into<Level> {
+        let position = self.position(level)?;
+        let order = if ascending { SortOrder::Ascending } else { SortOrder::Descending };
+        let labels: Vec<Series> = self.levels.iter().map(Index::to_series).collect();
+        let sorter = labels.iter().enumerate()
+            .filter(|&(idx, _)| idx != position)
+            .fold(Sorter::new().by(&labels[position], order), |sorter, (_, labels)| sorter.by(labels, order));
+        sorter.argsort()
+    }
+
+    /// Returns the position of `level`, failing with `RaccoonError::LevelNotFound` if there is no such level.
+    pub(crate) fn position<L>(&self, level: L) -> RaccoonResult<usize> where L: Into<Level> {
+        let level = level.into();
+        let position = match level {
+            Level::Position(position)   => Some(position).filter(|&position| position < self.levels.len()),
+            Level::Name(ref name)       => self.levels.iter().position(|level| level.name() == Some(name)),
+        };
+        position.ok_or_else(|| RaccoonError::LevelNotFound(match level {
+            Level::Position(position)   => position.to_string(),
+            Level::Name(name)           => name,
+        }))
+    }
+
+    /// Returns the labels at `position`, which must be in bounds, as a hashable key.
+    pub(crate) fn key(&self, position: usize) -> Vec<OrderedEntry> {
+        self.levels.iter().map(|level| OrderedEntry(level.get(position).unwrap_or(DataEntry::NA))).collect()
+    }
+
+    /// Returns the multi index of the rows at `positions`, which must all be in bounds.
+    pub(crate) fn take(&self, positions: &[usize]) -> MultiIndex {
+        MultiIndex { levels: self.levels.iter().map(|level| level.take(positions)).collect() }
+    }
+
+    /// Appends a label to every level, for a row pushed onto a series.
+    pub(crate) fn push(&mut self) {
+        for level in self.levels.iter_mut() {
+            level.push();
+        }
+    }
+
+    /// Removes the labels of the last row, for a row popped from a series.
+    pub(crate) fn pop(&mut self) {
+        for level in self.levels.iter_mut() {
+            level.pop();
+        }
+    }
+}
+
+impl From<Index> for MultiIndex {
+    fn from(index: Index) -> Self {
+        match index.multi() {
+            Some(multi) => multi.clone(),
+            None        => MultiIndex { levels: vec![index] },
+        }
+    }
+}
+
+/// Returns the name of a column labelled by `labels`: the labels converted into texts and joined with `_`, with `NA`
+/// for missing labels.
+fn column_name(labels: &[DataEntry]) -> String {
+    let texts: Vec<String> = labels.iter()
+        .map(|label| match label.convert_to(&DataType::Text) {
+            DataEntry::Text(text)   => text,
+            _                       => "NA".to_owned(),
+        })
+        .collect();
+    texts.join("_")
+}
+
+/// Returns the names of the columns labelled by `keys`, see [`column_name`]. A name already given to a previous column
+/// is followed by the first suffix `.1`, `.2`, ... giving a name that no other column has.
+pub(crate) fn column_names(keys: &[Vec<DataEntry>]) -> Vec<String> {
+    let names: Vec<String> = keys.iter().map(|key| column_name(key)).collect();
+    let mut taken: HashSet<String> = names.iter().cloned().collect();
+    let mut given: HashSet<&str> = HashSet::new();
+    names.iter()
+        .map(|name| {
+            if given.insert(name) {
+                return name.clone();
+            }
+            (1..).map(|suffix| format!("{}.{}", name, suffix))
+                .find(|candidate| taken.insert(candidate.clone()))
+                .expect("there are more suffixes than columns")
+        })
+        .collect()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn construction() {
+        let index = MultiIndex::new(vec![
+            Index::from(vec!["b", "a", "b", "a"]).with_name("letter"),
+            Index::from(vec![2, 2, 1, 1]).with_name("number"),
+        ]).unwrap();
+        assert_eq!((4, 2), (index.len(), index.nlevels()));
+        assert!(index.is_unique());
+        assert!(!MultiIndex::new(vec![Index::from(vec![1, 1]), Index::from(vec![2, 2])]).unwrap().is_unique());
+        assert!(matches!(MultiIndex::new(Vec::new()), Err(RaccoonError::InvalidArgument(_))));
+        let result = MultiIndex::new(vec![Index::range(2), Index::range(3)]);
+        assert!(matches!(result, Err(RaccoonError::LengthMismatch { expected: 2, found: 3 })));
+        let result = MultiIndex::new(vec![Index::from(index.clone())]);
+        assert!(matches!(result, Err(RaccoonError::InvalidArgument(_))));
+
+        // a multi index of a single level is a plain index
+        let single = Index::from(MultiIndex::new(vec![Index::range(2)]).unwrap());
+        assert_eq!(single, Index::range(2));
+        assert_eq!(MultiIndex::from(single).nlevels(), 1);
+    }
+
+    #[test]
+    fn levels() {
+        let index = MultiIndex::new(vec![
+            Index::from(vec!["b", "a", "b", "a"]).with_name("letter"),
+            Index::from(vec![2, 2, 1, 1]).with_name("number"),
+        ]).unwrap();
+        assert_eq!(index.level(1).unwrap().name(), Some("number"));
+        assert!(matches!(index.level("none"), Err(RaccoonError::LevelNotFound(_))));
+        assert_eq!(index.positions(&[DataEntry::from("a"), DataEntry::Long(1)]), vec![3]);
+        assert_eq!(index.positions(&[]), vec![0, 1, 2, 3]);
+        assert!(index.positions(&[DataEntry::from("a"), DataEntry::Long(1), DataEntry::NA]).is_empty());
+
+        assert_eq!(index.argsort("letter", true).unwrap(), vec![3, 1, 2, 0]);
+        assert_eq!(index.argsort("number", false).unwrap(), vec![0, 1, 2, 3]);
+        let swapped = index.swaplevel(1, 0).unwrap();
+        assert_eq!(swapped.get(0), Some(vec![DataEntry::Integer(2), DataEntry::from("b")]));
+        assert_eq!(index.droplevel(0).unwrap().name(), Some("number"));
+        let single = MultiIndex::from(index.droplevel(1).unwrap());
+        assert!(matches!(single.droplevel(0), Err(RaccoonError::InvalidArgument(_))));
+
+        let taken = index.take(&[2, 0]);
+        assert_eq!(taken.get(1), Some(vec![DataEntry::from("b"), DataEntry::Integer(2)]));
+        assert_eq!(column_name(&taken.get(0).unwrap()), "b_1");
+        assert_eq!(column_name(&[DataEntry::NA, DataEntry::Double(0.5)]), "NA_0.5");
+        let keys = vec![
+            vec![DataEntry::from("a_b"), DataEntry::from("c")],
+            vec![DataEntry::from("a"), DataEntry::from("b_c")],
+            vec![DataEntry::from("a_b_c.1"), DataEntry::NA],
+            vec![DataEntry::from("a"), DataEntry::from("b_c.1")],
+            vec![DataEntry::from("a_b_c"), DataEntry::Integer(1)],
+        ];
+        assert_eq!(column_names(&keys), vec!["a_b_c", "a_b_c.2", "a_b_c.1_NA", "a_b_c.1", "a_b_c_1"]);
+    }
+}
@@ -0,0 +1,425 @@
+//! Conversions of a [`DataFrame`] between long and wide formats, similar to python's `pandas.pivot`,
+//! `pandas.pivot_table` and `pandas.melt`.
+//!
+//! [`DataFrame`]: ../dataframe/struct.DataFrame.html
+//!
+//! A [`PivotTable`], obtained using [`DataFrame::pivot_table`], spreads a long frame into a wide frame: the rows are
+//! labelled by the distinct entries of an index column, in ascending order, and every distinct entry of a columns
+//! column becomes a column, in ascending order, holding the entries of a value column. [`DataFrame::pivot`] requires
+//! every pair of an index entry and a column entry to appear once, while a pivot table aggregates the entries of the
+//! pairs appearing several times, and drops the rows having a `DataEntry::NA` key.
+//!
+//! The columns built are named after the entries of the columns column converted into texts, with `NA` for a missing
+//! entry. If several value columns are spread, they are named after the value column and the entry joined with `_`, and
+//! are labelled by a [`MultiIndex`], see [`DataFrame::column_index`].
+//!
+//! A [`Melt`], or [`DataFrame::melt`], gathers a wide frame into a long frame: every value column is turned into rows
+//! holding the name of the column and its entry, next to the entries of the identifier columns.
+//!
+//! [`PivotTable`]: ./struct.PivotTable.html
+//! [`DataFrame::pivot_table`]: ../dataframe/struct.DataFrame.html#method.pivot_table
+//! [`DataFrame::pivot`]: ../dataframe/struct.DataFrame.html#method.pivot
+//! [`MultiIndex`]: ../multiindex/struct.MultiIndex.html
+//! [`DataFrame::column_index`]: ../dataframe/struct.DataFrame.html#method.column_index
+//! [`Melt`]: ./struct.Melt.html
+//! [`DataFrame::melt`]: ../dataframe/struct.DataFrame.html#method.melt
+//!
+//! # Examples
+//! ```
+//! use raccoon::{Aggregation, DataEntry, DataFrame, Series};
+//!
+//! let long = DataFrame::from_series(vec![
+//!     Series::from_vector("city".to_owned(), vec!["Bern", "Bern", "Chur", "Chur", "Chur"]),
+//!     Series::from_vector("month".to_owned(), vec!["jan", "feb", "jan", "feb", "feb"]),
+//!     Series::from_vector("rain".to_owned(), vec![62.0, 55.0, 50.0, 46.0, 48.0]),
+//! ]).unwrap();
+//!
+//! let wide = long.pivot_table("city", "month").unwrap().aggregate(Aggregation::Max).unwrap();
+//! assert_eq!(wide.columns(), vec!["feb", "jan"]);
+//! assert_eq!(wide.index().to_series(), vec!["Bern", "Chur"]);
+//! assert_eq!(wide["feb"], vec![55.0, 48.0]);
+//!
+//! // pairs appearing several times cannot be pivoted without aggregation
+//! assert!(long.pivot("city", "month", &["rain"]).is_err());
+//!
+//! let back = wide.reset_index().unwrap().melt(&["city"], &["jan", "feb"]).unwrap();
+//! assert_eq!(back.columns(), vec!["city", "variable", "value"]);
+//! assert_eq!(back["variable"], vec!["jan", "jan", "feb", "feb"]);
+//! assert_eq!(back["value"], vec![62.0, 50.0, 55.0, 48.0]);
+//! ```
+
+use dataframe::DataFrame;
+use entry::{DataEntry, DataType, OrderedEntry};
+use error::{RaccoonResult, RaccoonError};
+use groupby::{Aggregation, GroupBy};
+use missing::Fill;
+use multiindex;
+use series::Series;
+
+use std::collections::HashSet;
+
+/// A pivot of a dataframe from long to wide format using configurable options, see `raccoon::reshape`.
+///
+/// # Example
+/// ```
+/// use raccoon::{Aggregation, DataFrame, PivotTable, Series};
+///
+/// let sales = DataFrame::from_series(vec![
+///     Series::from_vector("shop".to_owned(), vec!["a", "a", "b", "a"]),
+///     Series::from_vector("item".to_owned(), vec![2, 1, 2, 2]),
+///     Series::from_vector("sold".to_owned(), vec![3, 1, 4, 5]),
+///     Series::from_vector("price".to_owned(), vec![1.5, 2.0, 1.5, 1.0]),
+/// ]).unwrap();
+///
+/// let table = PivotTable::new(&sales, "shop", "item").unwrap()
+///     .values(&["sold"])
+///     .fill_value(0)
+///     .aggregate(Aggregation::Sum)
+///     .unwrap();
+/// assert_eq!(table.columns(), vec!["1", "2"]);
+/// assert_eq!(table["1"], vec![1i64, 0]);
+/// assert_eq!(table["2"], vec![8i64, 4]);
+///
+/// let table = PivotTable::new(&sales, "shop", "item").unwrap().aggregate(Aggregation::Count).unwrap();
+/// assert_eq!(table.columns(), vec!["sold_1", "sold_2", "price_1", "price_2"]);
+/// assert_eq!(table.column_index().unwrap().nlevels(), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct PivotTable<'a> {
+    df: &'a DataFrame,
+    index: String,
+    columns: String,
+    values: Vec<String>,
+    fill_value: Option<DataEntry>,
+}
+
+impl<'a> PivotTable<'a> {
+    /// Constructs a new `PivotTable` of `df`, whose rows are labelled by the entries of the column `index`, and whose
+    /// columns are built from the entries of the column `columns`. The values are all other columns, without fill
+    /// value.
+    ///
+    /// # Errors
+    /// - `RaccoonError::ColumnNotFound` if either column does not exist.
+    /// - `RaccoonError::InvalidArgument` if both columns are the same.
+    pub fn new(df: &'a DataFrame, index: &str, columns: &str) -> RaccoonResult<PivotTable<'a>> {
+        if let Some(name) = [index, columns].iter().find(|name| df.column(name).is_none()) {
+            return Err(RaccoonError::ColumnNotFound(name.to_string()));
+        }
+        if index == columns {
+            return Err(RaccoonError::InvalidArgument("a pivot requires distinct index and columns".to_owned()));
+        }
+        Ok(PivotTable {
+            df,
+            index: index.to_owned(),
+            columns: columns.to_owned(),
+            values: Vec::new(),
+            fill_value: None,
+        })
+    }
+
+    /// Sets the value columns, whose entries fill the columns built. Defaults to all columns other than the index
+    /// and the columns column.
+    pub fn values(mut self, values: &[&str]) -> PivotTable<'a> {
+        self.values = values.iter().map(|name| name.to_string()).collect();
+        self
+    }
+
+    /// Sets the value replacing the missing entries of the result, including those of the pairs of an index entry
+    /// and a column entry that do not appear.
+    pub fn fill_value<T>(mut self, fill_value: T) -> PivotTable<'a> where T: Into<DataEntry> {
+        self.fill_value = Some(fill_value.into());
+        self
+    }
+
+    /// Pivots the frame without aggregation, such that every entry of a value column fills one entry of the result.
+    /// Rows having a `DataEntry::NA` in the index or the columns column are kept.
+    ///
+    /// # Errors
+    /// - `RaccoonError::ColumnNotFound` if a value column does not exist.
+    /// - `RaccoonError::InvalidArgument` if a pair of an index entry and a column entry appears several times, or if
+    ///   there is no value column.
+    /// - `RaccoonError::TypeMismatch` if the fill value cannot be converted to the data type of a column.
+    /// - `RaccoonError::DuplicateColumn` if the names of two columns built are the same.
+    pub fn pivot(&self) -> RaccoonResult<DataFrame> {
+        let long = self.long()?;
+        let (index, columns) = (&long[self.index.as_str()], &long[self.columns.as_str()]);
+        let mut pairs = HashSet::with_capacity(long.len());
+        for (label, column) in index.iter().zip(columns.iter()) {
+            if !pairs.insert((OrderedEntry(label), OrderedEntry(column))) {
+                return Err(RaccoonError::InvalidArgument(
+                    "a pivot requires distinct pairs of index and column entries, use a pivot table".to_owned()
+                ));
+            }
+        }
+        self.spread(long)
+    }
+
+    /// Pivots the frame, applying `aggregation` to the entries of a value column sharing the same index entry and
+    /// column entry. Rows having a `DataEntry::NA` in the index or the columns column are dropped.
+    ///
+    /// # Errors
+    /// - `RaccoonError::ColumnNotFound` if a value column does not exist.
+    /// - `RaccoonError::InvalidArgument` if there is no value column.
+    /// - `RaccoonError::TypeMismatch` if the fill value cannot be converted to the data type of a column.
+    /// - `RaccoonError::DuplicateColumn` if the names of two columns built are the same.
+    /// - Any error returned by the aggregation, for instance `RaccoonError::UnsupportedOperation` when computing the
+    ///   mean of a text column.
+    pub fn aggregate(&self, aggregation: Aggregation) -> RaccoonResult<DataFrame> {
+        let long = self.long()?;
+        let aggregated = GroupBy::new(&long, &[&self.index, &self.columns])?.aggregate(aggregation)?;
+        self.spread(aggregated)
+    }
+
+    /// Returns the frame holding the index column, the columns column and the value columns.
+    fn long(&self) -> RaccoonResult<DataFrame> {
+        let values: Vec<&str> = if self.values.is_empty() {
+            self.df.columns().into_iter().filter(|name| *name != self.index && *name != self.columns).collect()
+        } else {
+            self.values.iter().map(String::as_str).collect()
+        };
+        if values.is_empty() {
+            return Err(RaccoonError::InvalidArgument("a pivot requires a value column".to_owned()));
+        }
+        let mut columns = vec![self.df[self.index.as_str()].clone(), self.df[self.columns.as_str()].clone()];
+        for name in values {
+            let series = self.df.column(name).ok_or_else(|| RaccoonError::ColumnNotFound(name.to_owned()))?;
+            columns.push(series.clone());
+        }
+        DataFrame::from_series(columns)
+    }
+
+    /// Spreads the value columns of `long`, whose pairs of index and column entries are distinct.
+    fn spread(&self, long: DataFrame) -> RaccoonResult<DataFrame> {
+        let single = long.shape().1 == 3;
+        let wide = long.set_multi_index(&[&self.index, &self.columns])?.unstack(1)?.sort_level(0, true)?;
+        let wide = if single {
+            let column_index = wide.column_index().expect("unstacked columns are labelled");
+            let keys: Vec<Vec<DataEntry>> = (0..wide.shape().1)
+                .map(|idx| column_index.get(idx).expect("every column has labels")[1..].to_vec())
+                .collect();
+            let mut df = DataFrame::with_capacity(wide.shape().1);
+            for (series, name) in wide.iter().zip(multiindex::column_names(&keys)) {
+                let mut series = series.clone();
+                series.set_name(name);
+                df.add_column(series)?;
+            }
+            df.with_index(wide.index().clone())?
+        } else {
+            wide
+        };
+        match self.fill_value {
+            Some(ref fill_value)    => {
+                let fills = wide.columns().into_iter().map(|name| (name, Fill::Value(fill_value.clone()))).collect();
+                wide.fillna(&fills)
+            },
+            None                    => Ok(wide),
+        }
+    }
+}
+
+/// A conversion of a dataframe from wide to long format using configurable options, see `raccoon::reshape`.
+///
+/// # Example
+/// ```
+/// use raccoon::{DataEntry, DataFrame, Melt, Series};
+///
+/// let df = DataFrame::from_series(vec![
+///     Series::from_vector("city".to_owned(), vec!["Bern", "Chur"]),
+///     Series::from_vector("jan".to_owned(), vec![62, 50]),
+///     Series::from_vector("feb".to_owned(), vec![DataEntry::Double(55.5), DataEntry::NA]),
+/// ]).unwrap();
+///
+/// let long = Melt::new(&df).id_vars(&["city"]).var_name("month").value_name("rain").melt().unwrap();
+/// assert_eq!(long.columns(), vec!["city", "month", "rain"]);
+/// assert_eq!(long["city"], vec!["Bern", "Chur", "Bern", "Chur"]);
+/// assert_eq!(long["month"], vec!["jan", "jan", "feb", "feb"]);
+/// assert_eq!(long["rain"], vec![DataEntry::Double(62.0), DataEntry::Double(50.0), DataEntry::Double(55.5),
+///                               DataEntry::NA]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Melt<'a> {
+    df: &'a DataFrame,
+    id_vars: Vec<String>,
+    value_vars: Option<Vec<String>>,
+    var_name: String,
+    value_name: String,
+}
+
+impl<'a> Melt<'a> {
+    /// Constructs a new `Melt` of `df`, without identifier columns, turning all columns into rows of columns named
+    /// `"variable"` and `"value"`.
+    pub fn new(df: &'a DataFrame) -> Melt<'a> {
+        Melt {
+            df,
+            id_vars: Vec::new(),
+            value_vars: None,
+            var_name: "variable".to_owned(),
+            value_name: "value".to_owned(),
+        }
+    }
+
+    /// Sets the identifier columns, which are repeated for every value column.
+    pub fn id_vars(mut self, id_vars: &[&str]) -> Melt<'a> {
+        self.id_vars = id_vars.iter().map(|name| name.to_string()).collect();
+        self
+    }
+
+    /// Sets the value columns, which are turned into rows. Defaults to all columns other than the identifier columns.
+    pub fn value_vars(mut self, value_vars: &[&str]) -> Melt<'a> {
+        self.value_vars = Some(value_vars.iter().map(|name| name.to_string()).collect());
+        self
+    }
+
+    /// Sets the name of the column holding the names of the value columns. Defaults to `"variable"`.
+    pub fn var_name<S>(mut self, var_name: S) -> Melt<'a> where S: Into<String> {
+        self.var_name = var_name.into();
+        self
+    }
+
+    /// Sets the name of the column holding the entries of the value columns. Defaults to `"value"`.
+    pub fn value_name<S>(mut self, value_name: S) -> Melt<'a> where S: Into<String> {
+        self.value_name = value_name.into();
+        self
+    }
+
+    /// Turns the value columns into rows, one value column after the other. The result has the identifier columns,
+    /// followed by the column of names and the column of entries, whose data type is promoted to a data type common to
+    /// all value columns. Its rows are labelled by a range index.
+    ///
+    /// # Errors
+    /// - `RaccoonError::ColumnNotFound` if an identifier or value column does not exist.
+    /// - `RaccoonError::TypeMismatch` if the value columns have no common data type.
+    /// - `RaccoonError::DuplicateColumn` if the name of the column of names or of entries is already used by an
+    ///   identifier column, or if both are the same.
+    pub fn melt(&self) -> RaccoonResult<DataFrame> {
+        let id_vars = self.columns(&self.id_vars)?;
+        let value_vars = match self.value_vars {
+            Some(ref value_vars)    => self.columns(value_vars)?,
+            None                    => {
+                self.df.iter().filter(|series| !self.id_vars.iter().any(|name| name == series.name())).collect()
+            },
+        };
+        let mut data_type = DataType::NA;
+        for series in &value_vars {
+            data_type = match data_type.common_type(series.data_type()) {
+                Some(data_type) => data_type,
+                None            => {
+                    return Err(RaccoonError::TypeMismatch { expected: data_type, found: series.data_type().clone() })
+                },
+            };
+        }
+
+        let positions: Vec<usize> = value_vars.iter().flat_map(|_| 0..self.df.len()).collect();
+        let mut df = DataFrame::with_capacity(id_vars.len() + 2);
+        for series in id_vars {
+            df.add_column(series.take_entries(&positions)?)?;
+        }
+        let names = value_vars.iter().flat_map(|series| (0..series.len()).map(move |_| series.name()));
+        df.add_column(Series::from_vector(self.var_name.clone(), names.collect()))?;
+        let entries = value_vars.iter().flat_map(|series| series.iter());
+        df.add_column(Series::from_entries(self.value_name.clone(), data_type, entries))?;
+        Ok(df)
+    }
+
+    /// Returns the columns of the frame named `names`.
+    fn columns(&self, names: &[String]) -> RaccoonResult<Vec<&'a Series>> {
+        names.iter()
+            .map(|name| self.df.column(name).ok_or_else(|| RaccoonError::ColumnNotFound(name.clone())))
+            .collect()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pivots() {
+        let df = DataFrame::from_series(vec![
+            Series::from_vector("region".to_owned(), vec![
+                DataEntry::from("north"), DataEntry::from("south"), DataEntry::from("north"), DataEntry::NA,
+            ]),
+            Series::from_vector("year".to_owned(), vec![2021, 2020, 2020, 2021]),
+            Series::from_vector("units".to_owned(), vec![DataEntry::Integer(10), DataEntry::Integer(4), DataEntry::NA,
+                                                         DataEntry::Integer(7)]),
+        ]).unwrap();
+        let wide = df.pivot("region", "year", &[]).unwrap();
+        assert_eq!(wide.columns(), vec!["2020", "2021"]);
+        assert_eq!(wide.index().name(), Some("region"));
+        assert_eq!(wide.index().to_series(), vec![DataEntry::from("north"), DataEntry::from("south"), DataEntry::NA]);
+        assert_eq!(wide["2021"], vec![DataEntry::Integer(10), DataEntry::NA, DataEntry::Integer(7)]);
+        assert!(wide.column_index().is_none());
+
+        let wide = df.pivot("year", "region", &["units"]).unwrap();
+        assert_eq!(wide.columns(), vec!["north", "south", "NA"]);
+        assert_eq!(wide.index().to_series(), vec![2020, 2021]);
+
+        let table = df.pivot_table("region", "year").unwrap().fill_value(0u64).aggregate(Aggregation::Count).unwrap();
+        assert_eq!(table.index().to_series(), vec!["north", "south"]);
+        assert_eq!(table["2020"], vec![0u64, 1]);
+        assert_eq!(table["2021"], vec![1u64, 0]);
+
+        let mut several = df.clone();
+        several.add_column(Series::from_vector("label".to_owned(), vec!["a", "b", "c", "d"])).unwrap();
+        let wide = several.pivot("year", "region", &[]).unwrap();
+        assert_eq!(wide.columns(), vec!["units_north", "units_south", "units_NA", "label_north", "label_south",
+                                        "label_NA"]);
+        assert_eq!(wide.column_index().unwrap().names(), vec![None, Some("region")]);
+    }
+
+    #[test]
+    fn pivot_errors() {
+        let df = DataFrame::from_series(vec![
+            Series::from_vector("region".to_owned(), vec!["north", "south"]),
+            Series::from_vector("year".to_owned(), vec![2021, 2020]),
+        ]).unwrap();
+        assert!(matches!(df.pivot("region", "month", &[]), Err(RaccoonError::ColumnNotFound(_))));
+        assert!(matches!(df.pivot("region", "year", &["units"]), Err(RaccoonError::ColumnNotFound(_))));
+        assert!(matches!(df.pivot("region", "region", &[]), Err(RaccoonError::InvalidArgument(_))));
+        assert!(matches!(df.pivot("region", "year", &[]), Err(RaccoonError::InvalidArgument(_))));
+
+        let duplicated = DataFrame::from_series(vec![
+            Series::from_vector("region".to_owned(), vec!["north", "south", "north"]),
+            Series::from_vector("year".to_owned(), vec![2021, 2020, 2021]),
+            Series::from_vector("units".to_owned(), vec![10, 4, 10]),
+        ]).unwrap();
+        assert!(matches!(duplicated.pivot("region", "year", &[]), Err(RaccoonError::InvalidArgument(_))));
+        let table = duplicated.pivot_table("region", "year").unwrap().aggregate(Aggregation::Sum).unwrap();
+        assert_eq!(table["2021"], vec![DataEntry::Long(20), DataEntry::NA]);
+        let result = duplicated.pivot_table("region", "year").unwrap().fill_value("none").aggregate(Aggregation::Sum);
+        assert!(matches!(result, Err(RaccoonError::TypeMismatch { .. })));
+        let result = duplicated.pivot_table("year", "units").unwrap().aggregate(Aggregation::Mean);
+        assert!(matches!(result, Err(RaccoonError::UnsupportedOperation(_))));
+    }
+
+    #[test]
+    fn melting() {
+        let df = DataFrame::from_series(vec![
+            Series::from_vector("region".to_owned(), vec!["north", "south", "north", "west"]),
+            Series::from_vector("year".to_owned(), vec![2021, 2020, 2020, 2021]),
+            Series::from_vector("units".to_owned(), vec![DataEntry::Integer(10), DataEntry::Integer(4), DataEntry::NA,
+                                                         DataEntry::Integer(7)]),
+        ]).unwrap();
+        let long = df.melt(&["region"], &["year", "units"]).unwrap();
+        assert_eq!((8, 3), long.shape());
+        assert_eq!(long["value"].data_type(), &DataType::Integer);
+        assert_eq!(long["variable"], vec!["year", "year", "year", "year", "units", "units", "units", "units"]);
+        assert_eq!(long["region"].get(4).unwrap(), DataEntry::from("north"));
+
+        let all = Melt::new(&df).value_vars(&["units", "year"]).melt().unwrap();
+        assert_eq!(all.columns(), vec!["variable", "value"]);
+        assert_eq!(all["value"].get(4).unwrap(), DataEntry::Integer(2021));
+
+        let none = df.melt(&["region", "year", "units"], &[]).unwrap();
+        assert_eq!((0, 5), none.shape());
+
+        assert!(matches!(Melt::new(&df).melt(), Err(RaccoonError::TypeMismatch { .. })));
+        assert!(matches!(df.melt(&["month"], &[]), Err(RaccoonError::ColumnNotFound(_))));
+        assert!(matches!(df.melt(&[], &["month"]), Err(RaccoonError::ColumnNotFound(_))));
+        let result = Melt::new(&df).id_vars(&["region"]).var_name("region").melt();
+        assert!(matches!(result, Err(RaccoonError::DuplicateColumn(_))));
+        let result = Melt::new(&df).value_vars(&["year"]).var_name("x").value_name("x").melt();
+        assert!(matches!(result, Err(RaccoonError::DuplicateColumn(_))));
+    }
+}
@@ -1,6 +1,16 @@
 //! A vector-like type that allows for aggregate operations similar to python's `pandas.Series`.
 //!
-// TODO: add info on performance.
+//! # Performance
+//! A `Series` does not store [`DataEntry`] values, which all have the size of the largest variant. Its entries are
+//! stored in a buffer typed after the data type of the series, such as a `Vec<i32>` for `DataType::Integer`, with texts
+//! stored contiguously, and a bitmap marking missing entries. Entries are converted back into `DataEntry`s when they
+//! are accessed through [`get`] or [`iter`], which is why a series cannot be indexed: indexing has to return
+//! references to entries that do not exist. Run `cargo bench --bench memory` to compare the memory usage of both
+//! representations.
+//!
+//! [`DataEntry`]: ../entry/enum.DataEntry.html
+//! [`get`]: ./struct.Series.html#method.get
+//! [`iter`]: ./struct.Series.html#method.iter
 //!
 //! # Examples
 //! You can create a new [`Series`] using [`new`]:
@@ -24,7 +34,7 @@
 //! let series = Series::from_vector("bools".to_owned(), v);
 //!
 //! // data type is infered from data passed to it
-//! assert_eq!(series[1usize], DataEntry::Boolean(false));
+//! assert_eq!(series.get(1), Some(DataEntry::Boolean(false)));
 //! ```
 //!
 //! You can also push new values onto the end of a `Series`:
@@ -36,26 +46,74 @@
 //!
 //! let result = series.push(3u32);
 //! assert!(result.is_ok());
-//! assert_eq!(series[3usize], DataEntry::UInteger(3u32));
+//! assert_eq!(series.get(3), Some(DataEntry::UInteger(3u32)));
 //!
 //! let result = series.push(false);
 //! assert!(result.is_err());
 //!
 //! // you can also push vectors
 //! let _ = series.push_vec(vec![4u32, 5u32, 6u32]);
-//! assert_eq!(series[5usize], DataEntry::UInteger(5u32));
+//! assert_eq!(series.get(5), Some(DataEntry::UInteger(5u32)));
 //!
 //! // or push `DataEntry`s
 //! let _ = series.push_entry(DataEntry::UInteger(7u32));
 //! let _ = series.push_entry_vec(vec![DataEntry::UInteger(8u32), DataEntry::UInteger(9u32)]);
-//! assert_eq!(series[9usize], DataEntry::UInteger(9u32));
+//! assert_eq!(series.get(9), Some(DataEntry::UInteger(9u32)));
+//! ```
+//!
+//! # Arithmetic
+//! References to series support the `+`, `-`, `*` and `/` operators, either with another series of the same length,
+//! applied element-wise, or with a single value, applied to every entry. The data type of the result follows the
+//! promotion rules of [`DataEntry`], and entries for which the operation fails (e.g. an integer division by zero)
+//! become `DataEntry::NA`. The operation itself fails if the lengths differ or if the operator is not defined for the
+//! data types involved. Series labelled by an [`Index`] are matched by label rather than by position, see
+//! [`raccoon::align`].
+//!
+//! [`DataEntry`]: ../entry/enum.DataEntry.html
+//! [`Index`]: ../index/struct.Index.html
+//! [`raccoon::align`]: ../align/index.html
+//! ```
+//! use raccoon::{Series, DataEntry, DataType};
+//!
+//! let a = Series::from(vec![1, 2, 3]);
+//! let b = Series::from(vec![10u32, 0, 30]);
+//!
+//! let sum = (&a + &b).unwrap();
+//! assert_eq!(sum.data_type(), &DataType::Long);
+//! assert_eq!(sum, vec![11i64, 2, 33]);
+//!
+//! let ratio = (&b / &a).unwrap();
+//! assert_eq!(ratio, vec![10i64, 0, 10]);
+//!
+//! let scaled = (&a * 0.5f64).unwrap();
+//! assert_eq!(scaled, vec![0.5f64, 1.0, 1.5]);
+//!
+//! let quotient = (&a / DataEntry::Integer(0)).unwrap();
+//! assert_eq!(quotient, vec![DataEntry::NA, DataEntry::NA, DataEntry::NA]);
+//!
+//! assert!((&a + &Series::from(vec![1, 2])).is_err());
 //! ```
 
 
-use entry::{DataEntry, DataType};
+use chrono::Duration;
+
+use aggregate::Aggregator;
+use datetime::{self, DatetimeAccessor};
+use entry::{DataEntry, DataType, OrderedEntry};
+use align::Alignment;
 use error::{RaccoonResult, RaccoonError};
+use index::{Index, LabelSelector, PositionSelector};
+use io::CsvWriter;
+use missing::Fill;
+use sort::{Sorter, SortOrder};
+use strings::StrAccessor;
+use window::Window;
+use storage::Column;
 
-use std::ops::Index;
+use std::cmp::Ordering;
+use std::fmt;
+use std::io::Write;
+use std::ops::{Add, Div, Mul, Sub};
 
 /// A growable, named series. This tries to conform to the behaviour of python's `pandas.Series`.
 ///
@@ -68,19 +126,21 @@ use std::ops::Index;
 /// series.push(67.8f64);
 ///
 /// assert_eq!(series.len(), 2);
-/// assert_eq!(series[0], DataEntry::Double(3.45));
+/// assert_eq!(series.get(0), Some(DataEntry::Double(3.45)));
 ///
 /// series.push_vec(vec![2.0f64, 2.1, 2.2, 2.3]);
 /// ```
 ///
 /// In general, this can be seen as a special type of vector, allowing aggregate operations. However, one major
-/// major difference, is the fact that a `Series` **cannot be indexed mutably**. Hence code such as the following will
-/// cause a compilation error:
+/// major difference, is the fact that a `Series` **cannot be indexed**: [`get`] returns a copy of an entry, which is
+/// converted from the typed storage of the series. Hence code such as the following will cause a compilation error:
+///
+/// [`get`]: #method.get
 /// ```ignore
 /// let mut series = Series::from(vec![1, 2, 3]);
 /// series[0] = 5;          // compile time error
 /// ```
-/// The reason for prohibiting mutable indexing is to ensure data type integrity. Code such as the following would
+/// The reason for prohibiting mutable access is to ensure data type integrity. Code such as the following would
 /// otherwise run without problems:
 /// ```ignore
 /// // creating a series containing boolean values
@@ -89,11 +149,13 @@ use std::ops::Index;
 /// // setting the second value to an integer
 /// series[1] = DataEntry::Integer(32);     // should NOT be allowed!!
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct Series {
     name: String,
-    entries: Vec<DataEntry>,
+    column: Column,
     data_type: DataType,
+    // labels of the entries, a range index unless set with `with_index`
+    index: Index,
 }
 
 impl Series {
@@ -108,11 +170,7 @@ impl Series {
     /// assert_eq!("My Series", series.name());
     /// ```
     pub fn new(name: String, data_type: DataType) -> Series {
-        Series {
-            name: name,
-            entries: Vec::new(),
-            data_type: data_type
-        }
+        Series::with_capacity(name, data_type, 0)
     }
 
     /// Constructs a new, empty `Series` with the specified name, data type, and capacity.
@@ -140,9 +198,10 @@ impl Series {
     /// ```
     pub fn with_capacity(name: String, data_type: DataType, capacity: usize) -> Series {
         Series {
-            name: name,
-            entries: Vec::with_capacity(capacity),
-            data_type: data_type
+            name,
+            column: Column::with_capacity(&data_type, capacity),
+            data_type,
+            index: Index::range(0),
         }
     }
 
@@ -163,7 +222,7 @@ impl Series {
     /// // works
     /// let result = series.push(4);
     /// assert!(result.is_ok());
-    /// assert_eq!(series[4], DataEntry::Integer(4));
+    /// assert_eq!(series.get(4), Some(DataEntry::Integer(4)));
     ///
     /// // fails
     /// let result = series.push(5.0);      // f32
@@ -190,7 +249,7 @@ impl Series {
     /// // works
     /// let result = series.push_vec(vec![4, 5, 6]);
     /// assert!(result.is_ok());
-    /// assert_eq!(series[6], DataEntry::Integer(6));
+    /// assert_eq!(series.get(6), Some(DataEntry::Integer(6)));
     ///
     /// // fails
     /// let result = series.push_vec(vec![3.4, 5.6, 1.2]);      // f32
@@ -203,9 +262,12 @@ impl Series {
 
     /// Append a `DataEntry` object to the series.
     ///
+    /// # Errors
+    /// `RaccoonError::TypeMismatch` if the entry is neither `DataEntry::NA` nor of the data type of the series.
+    ///
     /// # Example
     /// ```
-    /// # use raccoon::{Series, DataType, DataEntry};
+    /// # use raccoon::{Series, DataType, DataEntry, RaccoonError};
     /// // using `i32` to create the series
     /// let mut series = Series::from(vec![0, 1, 2, 3]);
     ///
@@ -215,17 +277,19 @@ impl Series {
     /// // works
     /// let result = series.push_entry(DataEntry::Integer(4));
     /// assert!(result.is_ok());
-    /// assert_eq!(series[4], DataEntry::Integer(4));
+    /// assert_eq!(series.get(4), Some(DataEntry::Integer(4)));
     ///
     /// // fails
     /// let result = series.push(DataEntry::Float(5.0));
-    /// assert!(result.is_err());
+    /// assert!(matches!(
+    ///     result,
+    ///     Err(RaccoonError::TypeMismatch { expected: DataType::Integer, found: DataType::Float })
+    /// ));
     /// ```
     pub fn push_entry(&mut self, data_entry: DataEntry) -> RaccoonResult {
-        if !self.verify_type(data_entry.data_type()) {
-            return Err(RaccoonError::InvalidType);
-        }
-        self.entries.push(data_entry);
+        self.check_type(data_entry.data_type())?;
+        self.column.push(data_entry);
+        self.index.push();
         Ok(())
     }
 
@@ -248,7 +312,7 @@ impl Series {
     /// ];
     /// let result = series.push_entry_vec(vector);
     /// assert!(result.is_ok());
-    /// assert_eq!(series[6], DataEntry::Integer(6));
+    /// assert_eq!(series.get(6), Some(DataEntry::Integer(6)));
     ///
     /// // fails
     /// let vector = vec![
@@ -260,15 +324,52 @@ impl Series {
     /// assert!(result.is_err());
     /// ```
     pub fn push_entry_vec(&mut self, vector: Vec<DataEntry>) -> RaccoonResult {
-        if vector.iter().any(|ref x| !self.verify_type(x.data_type())) {
-            return Err(RaccoonError::InvalidType);
+        for item in &vector {
+            self.check_type(item.data_type())?;
         }
         for item in vector {
-            self.entries.push(item);
+            self.column.push(item);
+            self.index.push();
         }
         Ok(())
     }
 
+    /// Returns a new series holding the entries of the series followed by the entries of `other`, named after the
+    /// series. Both are converted into their common data type, see [`DataType::common_type`], and the labels of
+    /// their indexes are concatenated as well: a range index is kept if both series have one.
+    ///
+    /// [`DataType::common_type`]: ../entry/enum.DataType.html#method.common_type
+    ///
+    /// # Errors
+    /// - `RaccoonError::TypeMismatch` if the series or the labels of their indexes have no common data type.
+    /// - `RaccoonError::LengthMismatch` if the indexes have different numbers of levels.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataEntry, DataType, RaccoonError, Series};
+    /// let integers = Series::from_vector("x".to_owned(), vec![DataEntry::Integer(1), DataEntry::NA]);
+    /// let doubles = Series::from_vector("y".to_owned(), vec![2.5f64]);
+    ///
+    /// let appended = integers.append(&doubles).unwrap();
+    /// assert_eq!(appended.name(), "x");
+    /// assert_eq!(appended.data_type(), &DataType::Double);
+    /// assert_eq!(appended, vec![DataEntry::Double(1.0), DataEntry::NA, DataEntry::Double(2.5)]);
+    /// assert_eq!(appended.index().len(), 3);
+    ///
+    /// let texts = Series::from_vector("z".to_owned(), vec!["a"]);
+    /// assert!(matches!(integers.append(&texts), Err(RaccoonError::TypeMismatch { .. })));
+    /// ```
+    pub fn append(&self, other: &Series) -> RaccoonResult<Series> {
+        let data_type = self.data_type.common_type(&other.data_type).ok_or_else(|| {
+            RaccoonError::TypeMismatch { expected: self.data_type.clone(), found: other.data_type.clone() }
+        })?;
+        let index = Index::concat(&[&self.index, &other.index])?;
+        let entries = self.iter().chain(other.iter()).map(|entry| entry.convert_to(&data_type));
+        let mut series = Series::from_entries(self.name.clone(), data_type.clone(), entries);
+        series.relabel(index);
+        Ok(series)
+    }
+
     /// Pops an entry from the end of the series.
     ///
     /// # Example
@@ -286,7 +387,11 @@ impl Series {
     /// assert_eq!(None, result);
     /// ```
     pub fn pop_entry(&mut self) -> Option<DataEntry> {
-        self.entries.pop()
+        let entry = self.column.pop();
+        if entry.is_some() {
+            self.index.pop();
+        }
+        entry
     }
 
     /// Returns the length of the series.
@@ -298,7 +403,7 @@ impl Series {
     /// assert_eq!(3, series.len())
     /// ```
     pub fn len(&self) -> usize {
-        self.entries.len()
+        self.column.len()
     }
 
     /// Converts the series into another data type.
@@ -347,11 +452,37 @@ impl Series {
     /// assert_eq!(series, vec![DataEntry::NA, DataEntry::NA, DataEntry::NA]);
     /// ```
     pub fn convert_to(&mut self, data_type: &DataType) {
-        let mut converted_entries: Vec<DataEntry> = Vec::new();
-        for entry in &self.entries {
-            converted_entries.push(entry.convert_to(data_type));
+        let mut column = Column::with_capacity(data_type, self.len());
+        for entry in self.iter() {
+            column.push(entry.convert_to(data_type));
         }
-        self.entries = converted_entries;
+        self.column = column;
+        self.data_type = data_type.clone();
+    }
+
+    /// Converts the series into a temporal data type, parsing text entries using a format string as described in
+    /// [`DataEntry::parse_with_format`]. Entries that are not text are converted using `convert_to`.
+    ///
+    /// [`DataEntry::parse_with_format`]: ../entry/enum.DataEntry.html#method.parse_with_format
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{Series, DataType, DataEntry};
+    /// let mut series = Series::from(vec!["14.03.2021", "15.03.2021", "n/a"]);
+    /// series.convert_with_format(&DataType::Date, "%d.%m.%Y");
+    /// assert_eq!(series.data_type(), &DataType::Date);
+    /// assert_eq!(series.get(1).unwrap().convert_to(&DataType::Text), DataEntry::from("2021-03-15"));
+    /// assert_eq!(series.get(2), Some(DataEntry::NA));
+    /// ```
+    pub fn convert_with_format(&mut self, data_type: &DataType, format: &str) {
+        let mut column = Column::with_capacity(data_type, self.len());
+        for entry in self.iter() {
+            column.push(match entry {
+                DataEntry::Text(ref text)   => DataEntry::parse_with_format(text, data_type, format),
+                entry                       => entry.convert_to(data_type),
+            });
+        }
+        self.column = column;
         self.data_type = data_type.clone();
     }
 
@@ -371,16 +502,24 @@ impl Series {
     ///
     /// In other words, this will return `true` if `data_type` is `DataType::NA` or equal to the data type of the
     /// series.
-    fn verify_type(&self, data_type: DataType) -> bool {
-        if data_type != self.data_type && data_type != DataType::NA {
-            false
+    fn verify_type(&self, data_type: &DataType) -> bool {
+        *data_type == self.data_type || *data_type == DataType::NA
+    }
+
+    /// Same as `verify_type`, but reports the mismatching data types as an error.
+    fn check_type(&self, data_type: DataType) -> RaccoonResult {
+        if self.verify_type(&data_type) {
+            Ok(())
         } else {
-            true
+            Err(RaccoonError::TypeMismatch { expected: self.data_type.clone(), found: data_type })
         }
     }
 
     /// Builds a `Series` from a vector of items and gives the series a name.
     ///
+    /// The data type of the series is the one of the first item that is not `DataEntry::NA`. Items of another data
+    /// type are converted into it using `DataEntry::convert_to`.
+    ///
     /// # Example
     /// ```
     /// # use raccoon::Series;
@@ -390,17 +529,40 @@ impl Series {
     /// ```
     pub fn from_vector<T>(name: String, vector: Vec<T>) -> Series where T: Into<DataEntry> {
         let entries: Vec<DataEntry> = vector.into_iter().map(|x| x.into()).collect();
-        let mut data_type = DataType::NA;
-        if !entries.is_empty() {
-            data_type = entries[0].data_type().clone();
+        let data_type = entries.iter().map(|x| x.data_type()).find(|x| *x != DataType::NA).unwrap_or(DataType::NA);
+        Series::from_entries(name, data_type, entries)
+    }
+
+    /// Builds a `Series` of `data_type` from entries, converting the entries of another data type.
+    pub(crate) fn from_entries<I>(name: String, data_type: DataType, entries: I) -> Series
+        where I: IntoIterator<Item = DataEntry> {
+        let entries = entries.into_iter();
+        let mut column = Column::with_capacity(&data_type, entries.size_hint().0);
+        for entry in entries {
+            if entry.data_type() == data_type {
+                column.push(entry);
+            } else {
+                column.push(entry.convert_to(&data_type));
+            }
         }
+        let len = column.len();
         Series {
-            name: name,
-            entries: entries,
-            data_type: data_type,
+            name,
+            column,
+            data_type,
+            index: Index::range(len),
         }
     }
 
+    /// Builds a `Series` of `data_type` from entries, named and labelled like this series. `entries` must have the
+    /// length of the series.
+    pub(crate) fn with_entries<I>(&self, data_type: DataType, entries: I) -> Series
+        where I: IntoIterator<Item = DataEntry> {
+        let mut series = Series::from_entries(self.name.clone(), data_type, entries);
+        series.relabel(self.index.clone());
+        series
+    }
+
     /// Getter for the series' name.
     ///
     /// # Example
@@ -428,6 +590,134 @@ impl Series {
         self.name = name;
     }
 
+    /// Returns the [`Index`] labelling the entries of the series, a range index unless set with [`with_index`].
+    ///
+    /// [`Index`]: ../index/struct.Index.html
+    /// [`with_index`]: #method.with_index
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{Index, Series};
+    /// let series = Series::from(vec![1.5, 2.5]);
+    /// assert_eq!(series.index(), &Index::range(2));
+    /// ```
+    pub fn index(&self) -> &Index {
+        &self.index
+    }
+
+    /// Returns the series with its entries labelled by `index`. Arithmetic operators and comparisons with another
+    /// series match entries by label, see [`raccoon::align`].
+    ///
+    /// [`raccoon::align`]: ../align/index.html
+    ///
+    /// # Errors
+    /// `RaccoonError::LengthMismatch` if `index` does not have the length of the series.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataEntry, Index, Series};
+    /// let series = Series::from(vec![3, 5]).with_index(Index::from(vec!["a", "b"])).unwrap();
+    /// assert_eq!(series.index().get(1), Some(DataEntry::from("b")));
+    ///
+    /// assert!(series.with_index(Index::range(3)).is_err());
+    /// ```
+    pub fn with_index(mut self, index: Index) -> RaccoonResult<Series> {
+        if index.len() != self.len() {
+            return Err(RaccoonError::LengthMismatch { expected: self.len(), found: index.len() });
+        }
+        self.index = index;
+        Ok(self)
+    }
+
+    /// Labels the entries of the series with `index`, which must have the length of the series.
+    pub(crate) fn relabel(&mut self, index: Index) {
+        self.index = index;
+    }
+
+    /// Returns a new series containing the entries selected by label, along with their labels. The selector is a
+    /// single label, a range of labels including both ends, or a vector of labels, see [`raccoon::index`].
+    ///
+    /// [`raccoon::index`]: ../index/index.html
+    ///
+    /// # Errors
+    /// - `RaccoonError::LabelNotFound` if a selected label is not in the index. The ends of a range must be in the
+    ///   index, unless it is a range or a datetime index.
+    /// - `RaccoonError::TypeMismatch` if the ends of a range cannot be compared with the labels.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{Index, Series};
+    /// let series = Series::from(vec![3.5, 1.0, 2.5]).with_index(Index::from(vec!["a", "b", "c"])).unwrap();
+    ///
+    /// assert_eq!(series.loc("b").unwrap(), vec![1.0]);
+    /// assert_eq!(series.loc("b"..="c").unwrap(), vec![1.0, 2.5]);
+    /// assert_eq!(series.loc(vec!["c", "a"]).unwrap().index().to_series(), vec!["c", "a"]);
+    /// assert!(series.loc("d").is_err());
+    /// ```
+    pub fn loc<S>(&self, selector: S) -> RaccoonResult<Series> where S: Into<LabelSelector> {
+        self.take(&self.index.select(&selector.into())?)
+    }
+
+    /// Returns a new series containing the entries selected by position, along with their labels. The selector is a
+    /// single position, a range of positions, or a vector of positions, see [`raccoon::index`]. Ranges are clipped to
+    /// the entries available.
+    ///
+    /// [`raccoon::index`]: ../index/index.html
+    ///
+    /// # Errors
+    /// `RaccoonError::IndexOutOfBounds` if a single position or a position of a vector is not smaller than the length
+    /// of the series.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{Index, Series};
+    /// let series = Series::from(vec![1, 2, 3, 4]).with_index(Index::from(vec!['w', 'x', 'y', 'z'])).unwrap();
+    ///
+    /// assert_eq!(series.iloc(2).unwrap().index().to_series(), vec!['y']);
+    /// assert_eq!(series.iloc(..2).unwrap(), vec![1, 2]);
+    /// assert_eq!(series.iloc(3..10).unwrap(), vec![4]);
+    /// assert_eq!(series.iloc(vec![3, 0]).unwrap(), vec![4, 1]);
+    /// assert!(series.iloc(4).is_err());
+    /// ```
+    pub fn iloc<S>(&self, selector: S) -> RaccoonResult<Series> where S: Into<PositionSelector> {
+        self.take(&selector.into().positions(self.len())?)
+    }
+
+    /// Returns an [`Alignment`] matching the entries of this series and `other` by label, to apply an operation with
+    /// other options than the operators. See [`raccoon::align`].
+    ///
+    /// [`Alignment`]: ../align/struct.Alignment.html
+    /// [`raccoon::align`]: ../align/index.html
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{Index, Series};
+    /// let a = Series::from(vec![1, 2]).with_index(Index::from(vec!['x', 'y'])).unwrap();
+    /// let b = Series::from(vec![5]).with_index(Index::from(vec!['y'])).unwrap();
+    /// assert_eq!(a.align(&b).fill_value(0).add().unwrap(), vec![1, 7]);
+    /// ```
+    pub fn align<'a>(&'a self, other: &'a Series) -> Alignment<'a> {
+        Alignment::new(self, other)
+    }
+
+    /// Writes the series as CSV data with a single column into `writer`, using the default options of
+    /// [`CsvWriter`]. The header contains the name of the series.
+    ///
+    /// [`CsvWriter`]: ../io/csv/struct.CsvWriter.html
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::Series;
+    /// let series = Series::from_vector("flags".to_owned(), vec![true, false]);
+    ///
+    /// let mut buffer = Vec::new();
+    /// series.to_csv(&mut buffer).unwrap();
+    /// assert_eq!(String::from_utf8(buffer).unwrap(), "flags\ntrue\nfalse\n");
+    /// ```
+    pub fn to_csv<W>(&self, writer: W) -> RaccoonResult where W: Write {
+        CsvWriter::new().write_series(self, writer)
+    }
+
     /// Checks if the series is empty.
     ///
     /// # Example
@@ -440,45 +730,816 @@ impl Series {
     /// assert!(!series.is_empty());
     /// ```
     pub fn is_empty(&self) -> bool {
-        self.entries.is_empty()
+        self.len() == 0
+    }
+
+    /// Returns a copy of the entry at `idx`, or `None` if `idx` is out of bounds.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{Series, DataEntry};
+    /// let series = Series::from(vec!['a', 'b']);
+    /// assert_eq!(series.get(1), Some(DataEntry::Character('b')));
+    /// assert_eq!(series.get(2), None);
+    /// ```
+    pub fn get(&self, idx: usize) -> Option<DataEntry> {
+        self.column.get_checked(idx)
+    }
+
+    /// Returns the number of bytes allocated to store the entries of the series.
+    ///
+    /// The entries are stored in a buffer typed after the data type of the series, along with a bitmap marking the
+    /// missing entries. Texts are stored contiguously, along with the position at which each text ends.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{Series, DataType};
+    /// let mut series = Series::with_capacity("numbers".to_owned(), DataType::Integer, 1000);
+    /// for i in 0..1000 {
+    ///     series.push(i).unwrap();
+    /// }
+    /// // 4 bytes per `i32`, and 16 words of 64 bits marking the missing entries
+    /// assert_eq!(series.memory_usage(), 4 * 1000 + 8 * 16);
+    /// ```
+    pub fn memory_usage(&self) -> usize {
+        self.column.memory_usage()
+    }
+
+    /// Returns an iterator over the entries of the series.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{Series, DataEntry};
+    /// let series = Series::from(vec![1, 2]);
+    /// let entries: Vec<DataEntry> = series.iter().collect();
+    /// assert_eq!(entries, vec![DataEntry::Integer(1), DataEntry::Integer(2)]);
+    /// ```
+    pub fn iter(&self) -> Iter<'_> {
+        Iter { series: self, idx: 0 }
+    }
+
+    /// Returns an [`Aggregator`] over the series, allowing to configure how aggregations are computed.
+    ///
+    /// [`Aggregator`]: ../aggregate/struct.Aggregator.html
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{Series, DataEntry};
+    /// let series = Series::from(vec![DataEntry::Integer(1), DataEntry::NA]);
+    /// assert_eq!(series.aggregate().skip_na(false).mean().unwrap(), DataEntry::NA);
+    /// ```
+    pub fn aggregate(&self) -> Aggregator<'_> {
+        Aggregator::new(self)
+    }
+
+    /// Returns a [`DatetimeAccessor`] applying vectorised operations to the dates or date times of the series.
+    ///
+    /// [`DatetimeAccessor`]: ../datetime/struct.DatetimeAccessor.html
+    ///
+    /// # Errors
+    /// `RaccoonError::TypeMismatch` if the series is neither of `DataType::Date`, `DataType::DateTime`, nor
+    /// `DataType::NA`.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataEntry, DataType, Series};
+    /// let mut series = Series::from(vec!["2021-03-14", "2021-03-15"]);
+    /// assert!(series.dt().is_err());
+    ///
+    /// series.convert_to(&DataType::Date);
+    /// assert_eq!(series.dt().unwrap().weekday(), vec![6, 0]);
+    /// ```
+    pub fn dt(&self) -> RaccoonResult<DatetimeAccessor<'_>> {
+        DatetimeAccessor::new(self)
+    }
+
+    /// Returns a [`StrAccessor`] applying vectorised operations to the texts of the series.
+    ///
+    /// [`StrAccessor`]: ../strings/struct.StrAccessor.html
+    ///
+    /// # Errors
+    /// `RaccoonError::TypeMismatch` if the series is neither of `DataType::Text` nor `DataType::NA`.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::Series;
+    /// let series = Series::from(vec!["Basel", "Bern"]);
+    /// assert_eq!(series.str().unwrap().lower(), vec!["basel", "bern"]);
+    /// assert!(Series::from(vec![1.5f64]).str().is_err());
+    /// ```
+    pub fn str(&self) -> RaccoonResult<StrAccessor<'_>> {
+        StrAccessor::new(self)
+    }
+
+    /// Returns a rolling [`Window`] of `size` entries, computing aggregations over the window ending at every entry.
+    ///
+    /// [`Window`]: ../window/struct.Window.html
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataEntry, Series};
+    /// let series = Series::from(vec![4, 8, 6, 2]);
+    /// assert_eq!(series.rolling(2).max().unwrap(), vec![DataEntry::NA, DataEntry::Double(8.0), DataEntry::Double(8.0),
+    ///                                                   DataEntry::Double(6.0)]);
+    /// ```
+    pub fn rolling(&self, size: usize) -> Window<'_> {
+        Window::rolling(self, size)
+    }
+
+    /// Returns an expanding [`Window`], computing aggregations over all entries up to every entry.
+    ///
+    /// [`Window`]: ../window/struct.Window.html
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::Series;
+    /// let series = Series::from(vec![4, 8, 6, 2]);
+    /// assert_eq!(series.expanding().min().unwrap(), vec![4.0f64, 4.0, 4.0, 2.0]);
+    /// ```
+    pub fn expanding(&self) -> Window<'_> {
+        Window::expanding(self)
+    }
+
+    /// Returns the number of entries that are not missing. See [`Aggregator::count`].
+    ///
+    /// [`Aggregator::count`]: ../aggregate/struct.Aggregator.html#method.count
+    pub fn count(&self) -> RaccoonResult<DataEntry> {
+        self.aggregate().count()
+    }
+
+    /// Returns the sum of the entries, skipping missing entries. See [`Aggregator::sum`].
+    ///
+    /// [`Aggregator::sum`]: ../aggregate/struct.Aggregator.html#method.sum
+    pub fn sum(&self) -> RaccoonResult<DataEntry> {
+        self.aggregate().sum()
+    }
+
+    /// Returns the product of the entries, skipping missing entries. See [`Aggregator::prod`].
+    ///
+    /// [`Aggregator::prod`]: ../aggregate/struct.Aggregator.html#method.prod
+    pub fn prod(&self) -> RaccoonResult<DataEntry> {
+        self.aggregate().prod()
+    }
+
+    /// Returns the arithmetic mean of the entries, skipping missing entries. See [`Aggregator::mean`].
+    ///
+    /// [`Aggregator::mean`]: ../aggregate/struct.Aggregator.html#method.mean
+    pub fn mean(&self) -> RaccoonResult<DataEntry> {
+        self.aggregate().mean()
+    }
+
+    /// Returns the sample variance of the entries, skipping missing entries. See [`Aggregator::var`].
+    ///
+    /// [`Aggregator::var`]: ../aggregate/struct.Aggregator.html#method.var
+    pub fn var(&self) -> RaccoonResult<DataEntry> {
+        self.aggregate().var()
+    }
+
+    /// Returns the sample standard deviation of the entries, skipping missing entries. See [`Aggregator::std`].
+    ///
+    /// [`Aggregator::std`]: ../aggregate/struct.Aggregator.html#method.std
+    pub fn std(&self) -> RaccoonResult<DataEntry> {
+        self.aggregate().std()
+    }
+
+    /// Returns the median of the entries, skipping missing entries. See [`Aggregator::median`].
+    ///
+    /// [`Aggregator::median`]: ../aggregate/struct.Aggregator.html#method.median
+    pub fn median(&self) -> RaccoonResult<DataEntry> {
+        self.aggregate().median()
+    }
+
+    /// Returns the `q`-th quantile of the entries, skipping missing entries. See [`Aggregator::quantile`].
+    ///
+    /// [`Aggregator::quantile`]: ../aggregate/struct.Aggregator.html#method.quantile
+    pub fn quantile(&self, q: f64) -> RaccoonResult<DataEntry> {
+        self.aggregate().quantile(q)
+    }
+
+    /// Returns the smallest entry, skipping missing entries. See [`Aggregator::min`].
+    ///
+    /// [`Aggregator::min`]: ../aggregate/struct.Aggregator.html#method.min
+    pub fn min(&self) -> RaccoonResult<DataEntry> {
+        self.aggregate().min()
+    }
+
+    /// Returns the largest entry, skipping missing entries. See [`Aggregator::max`].
+    ///
+    /// [`Aggregator::max`]: ../aggregate/struct.Aggregator.html#method.max
+    pub fn max(&self) -> RaccoonResult<DataEntry> {
+        self.aggregate().max()
+    }
+
+    /// Returns the position of the first smallest entry, skipping missing entries. See [`Aggregator::argmin`].
+    ///
+    /// [`Aggregator::argmin`]: ../aggregate/struct.Aggregator.html#method.argmin
+    pub fn argmin(&self) -> RaccoonResult<DataEntry> {
+        self.aggregate().argmin()
+    }
+
+    /// Returns the position of the first largest entry, skipping missing entries. See [`Aggregator::argmax`].
+    ///
+    /// [`Aggregator::argmax`]: ../aggregate/struct.Aggregator.html#method.argmax
+    pub fn argmax(&self) -> RaccoonResult<DataEntry> {
+        self.aggregate().argmax()
+    }
+
+    /// Compares the entries element-wise with `other`, which is either a series of the same length or a single value,
+    /// and builds a boolean series from the result of `predicate` applied to each comparison.
+    ///
+    /// Entries are compared using [`DataEntry::compare`], hence numerical entries are compared by value across data
+    /// types. `predicate` receives `None` for entries that cannot be ordered, such as NaN. The result is
+    /// `DataEntry::NA` where either entry is `DataEntry::NA`, and is named after this series.
+    ///
+    /// [`DataEntry::compare`]: ../entry/enum.DataEntry.html#method.compare
+    ///
+    /// # Errors
+    /// - `RaccoonError::LengthMismatch` if `other` is a series of another length.
+    /// - `RaccoonError::UnsupportedOperation` if the data types cannot be compared, for instance text and integers.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{Series, DataEntry, DataType};
+    /// # use std::cmp::Ordering;
+    /// let a = Series::from(vec![DataEntry::Integer(1), DataEntry::NA, DataEntry::Integer(3)]);
+    /// let b = Series::from(vec![1.5f64, 2.0, 2.5]);
+    ///
+    /// let mask = a.compare_with(&b, |ordering| ordering == Some(Ordering::Greater)).unwrap();
+    /// assert_eq!(mask.data_type(), &DataType::Boolean);
+    /// assert_eq!(mask, vec![DataEntry::Boolean(false), DataEntry::NA, DataEntry::Boolean(true)]);
+    ///
+    /// assert!(a.compare_with("text", |ordering| ordering.is_some()).is_err());
+    /// ```
+    pub fn compare_with<'a, T, F>(&self, other: T, predicate: F) -> RaccoonResult<Series>
+        where T: Into<Operand<'a>>, F: Fn(Option<Ordering>) -> bool {
+        match other.into() {
+            Operand::Series(other)  => Alignment::new(self, other).compare_with(predicate),
+            Operand::Scalar(scalar) => {
+                self.check_comparable(&scalar.data_type())?;
+                let entries = self.iter().map(|x| compare(x, scalar.clone(), &predicate));
+                Ok(self.with_entries(DataType::Boolean, entries))
+            },
+        }
+    }
+
+    /// Checks element-wise whether the entries are equal to `other`, which is either a series of the same length or a
+    /// single value. See [`compare_with`] for details. This shadows `PartialEq::eq` in method calls; compare whole
+    /// series with `==` instead.
+    ///
+    /// [`compare_with`]: #method.compare_with
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::Series;
+    /// let series = Series::from(vec![1, 2, 3]);
+    /// assert_eq!(series.eq(2u64).unwrap(), vec![false, true, false]);
+    /// assert!(series == series.clone());
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub fn eq<'a, T>(&self, other: T) -> RaccoonResult<Series> where T: Into<Operand<'a>> {
+        self.compare_with(other, |ordering| ordering == Some(Ordering::Equal))
+    }
+
+    /// Checks element-wise whether the entries are not equal to `other`, which is either a series of the same length
+    /// or a single value. See [`compare_with`] for details. This shadows `PartialEq::ne` in method calls; compare
+    /// whole series with `!=` instead.
+    ///
+    /// [`compare_with`]: #method.compare_with
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::Series;
+    /// let series = Series::from(vec![1, 2, 3]);
+    /// assert_eq!(series.ne(2u64).unwrap(), vec![true, false, true]);
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub fn ne<'a, T>(&self, other: T) -> RaccoonResult<Series> where T: Into<Operand<'a>> {
+        self.compare_with(other, |ordering| ordering != Some(Ordering::Equal))
+    }
+
+    /// Checks element-wise whether the entries are less than `other`, which is either a series of the same length or a
+    /// single value. See [`compare_with`] for details.
+    ///
+    /// [`compare_with`]: #method.compare_with
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::Series;
+    /// let series = Series::from(vec![1, 2, 3]);
+    /// assert_eq!(series.lt(2u64).unwrap(), vec![true, false, false]);
+    /// ```
+    pub fn lt<'a, T>(&self, other: T) -> RaccoonResult<Series> where T: Into<Operand<'a>> {
+        self.compare_with(other, |ordering| ordering == Some(Ordering::Less))
+    }
+
+    /// Checks element-wise whether the entries are less than or equal to `other`, which is either a series of the same
+    /// length or a single value. See [`compare_with`] for details.
+    ///
+    /// [`compare_with`]: #method.compare_with
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::Series;
+    /// let series = Series::from(vec![1, 2, 3]);
+    /// assert_eq!(series.le(2u64).unwrap(), vec![true, true, false]);
+    /// ```
+    pub fn le<'a, T>(&self, other: T) -> RaccoonResult<Series> where T: Into<Operand<'a>> {
+        self.compare_with(other, |ordering| ordering.is_some_and(|x| x != Ordering::Greater))
+    }
+
+    /// Checks element-wise whether the entries are greater than `other`, which is either a series of the same length
+    /// or a single value. See [`compare_with`] for details.
+    ///
+    /// [`compare_with`]: #method.compare_with
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::Series;
+    /// let series = Series::from(vec![1, 2, 3]);
+    /// assert_eq!(series.gt(2u64).unwrap(), vec![false, false, true]);
+    /// ```
+    pub fn gt<'a, T>(&self, other: T) -> RaccoonResult<Series> where T: Into<Operand<'a>> {
+        self.compare_with(other, |ordering| ordering == Some(Ordering::Greater))
+    }
+
+    /// Checks element-wise whether the entries are greater than or equal to `other`, which is either a series of the
+    /// same length or a single value. See [`compare_with`] for details.
+    ///
+    /// [`compare_with`]: #method.compare_with
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::Series;
+    /// let series = Series::from(vec![1, 2, 3]);
+    /// assert_eq!(series.ge(2u64).unwrap(), vec![false, true, true]);
+    /// ```
+    pub fn ge<'a, T>(&self, other: T) -> RaccoonResult<Series> where T: Into<Operand<'a>> {
+        self.compare_with(other, |ordering| ordering.is_some_and(|x| x != Ordering::Less))
+    }
+
+    /// Returns a new series containing the entries at the given positions, in the order of `positions`. Positions may
+    /// be repeated.
+    ///
+    /// # Errors
+    /// `RaccoonError::IndexOutOfBounds` if a position is not smaller than the length of the series.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::Series;
+    /// let series = Series::from(vec!['a', 'b', 'c']);
+    /// assert_eq!(series.take(&[2, 0, 2]).unwrap(), vec!['c', 'a', 'c']);
+    /// assert!(series.take(&[3]).is_err());
+    /// ```
+    pub fn take(&self, positions: &[usize]) -> RaccoonResult<Series> {
+        let mut series = self.take_entries(positions)?;
+        series.relabel(self.index.take(positions));
+        Ok(series)
+    }
+
+    /// Returns a new series containing the entries at the given positions, labelled by a range index.
+    pub(crate) fn take_entries(&self, positions: &[usize]) -> RaccoonResult<Series> {
+        let mut column = Column::with_capacity(&self.data_type, positions.len());
+        for &idx in positions {
+            match self.get(idx) {
+                Some(entry) => column.push(entry),
+                None        => return Err(RaccoonError::IndexOutOfBounds { index: idx, length: self.len() }),
+            }
+        }
+        Ok(Series {
+            name: self.name.clone(),
+            column,
+            data_type: self.data_type.clone(),
+            index: Index::range(positions.len()),
+        })
+    }
+
+    /// Returns a new series containing the entries at the given positions, and `DataEntry::NA` for missing positions,
+    /// labelled by a range index. Panics if a position is out of bounds.
+    pub(crate) fn take_or_na(&self, positions: &[Option<usize>]) -> Series {
+        let mut column = Column::with_capacity(&self.data_type, positions.len());
+        for idx in positions {
+            column.push(idx.map_or(DataEntry::NA, |idx| self.column.get(idx)));
+        }
+        Series {
+            name: self.name.clone(),
+            column,
+            data_type: self.data_type.clone(),
+            index: Index::range(positions.len()),
+        }
+    }
+
+    /// Returns the positions of the entries in sorted order. Missing values are placed last, and equal entries keep
+    /// their original order. See [`raccoon::sort`] for details, and [`Sorter`] to place missing values first.
+    ///
+    /// [`raccoon::sort`]: ../sort/index.html
+    /// [`Sorter`]: ../sort/struct.Sorter.html
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataEntry, Series};
+    /// let series = Series::from(vec![DataEntry::Long(3), DataEntry::NA, DataEntry::Long(-2), DataEntry::Long(3)]);
+    /// assert_eq!(series.argsort(true), vec![2, 0, 3, 1]);
+    /// assert_eq!(series.argsort(false), vec![0, 3, 2, 1]);
+    /// ```
+    pub fn argsort(&self, ascending: bool) -> Vec<usize> {
+        let order = if ascending { SortOrder::Ascending } else { SortOrder::Descending };
+        Sorter::new().by(self, order).argsort().expect("a single key cannot have a mismatching length")
+    }
+
+    /// Returns a new series containing the entries in sorted order. Missing values are placed last. See
+    /// [`argsort`] for details.
+    ///
+    /// [`argsort`]: #method.argsort
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::Series;
+    /// let series = Series::from(vec!["pear", "apple", "fig"]);
+    /// assert_eq!(series.sort(true), vec!["apple", "fig", "pear"]);
+    /// assert_eq!(series.sort(false), vec!["pear", "fig", "apple"]);
+    /// ```
+    pub fn sort(&self, ascending: bool) -> Series {
+        self.take(&self.argsort(ascending)).expect("sorted positions are in bounds")
+    }
+
+    /// Returns a new series containing the entries for which `mask` is true. Entries for which the mask is false or
+    /// `DataEntry::NA` are dropped.
+    ///
+    /// # Errors
+    /// - `RaccoonError::TypeMismatch` if `mask` is not a boolean series.
+    /// - `RaccoonError::LengthMismatch` if `mask` does not have the length of the series.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::Series;
+    /// let series = Series::from(vec![3, 8, 1, 9]);
+    /// let mask = series.gt(2).unwrap();
+    /// assert_eq!(series.filter(&mask).unwrap(), vec![3, 8, 9]);
+    /// ```
+    pub fn filter(&self, mask: &Series) -> RaccoonResult<Series> {
+        self.take(&mask.positions(self.len())?)
+    }
+
+    /// Returns a boolean series telling whether each entry is missing, that is `DataEntry::NA` or a NaN floating point
+    /// number. The series keeps the name of this series.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataEntry, Series};
+    /// let series = Series::from(vec![DataEntry::Double(1.0), DataEntry::NA, DataEntry::Double(f64::NAN)]);
+    /// assert_eq!(series.isna(), vec![false, true, true]);
+    /// ```
+    pub fn isna(&self) -> Series {
+        let entries = self.iter().map(|x| DataEntry::from(x.is_missing()));
+        self.with_entries(DataType::Boolean, entries)
+    }
+
+    /// Returns a boolean series telling whether each entry is not missing. This is the negation of [`isna`].
+    ///
+    /// [`isna`]: #method.isna
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataEntry, Series};
+    /// let series = Series::from(vec![DataEntry::from("a"), DataEntry::NA]);
+    /// assert_eq!(series.notna(), vec![true, false]);
+    /// ```
+    pub fn notna(&self) -> Series {
+        let entries = self.iter().map(|x| DataEntry::from(!x.is_missing()));
+        self.with_entries(DataType::Boolean, entries)
+    }
+
+    /// Returns a new series without the missing entries.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataEntry, Series};
+    /// let series = Series::from(vec![DataEntry::NA, DataEntry::Integer(4), DataEntry::NA, DataEntry::Integer(2)]);
+    /// assert_eq!(series.dropna(), vec![4, 2]);
+    /// ```
+    pub fn dropna(&self) -> Series {
+        let positions: Vec<usize> = self.iter().enumerate()
+            .filter(|(_, x)| !x.is_missing())
+            .map(|(idx, _)| idx)
+            .collect();
+        self.take(&positions).expect("positions of entries are in bounds")
+    }
+
+    /// Returns a new series in which missing entries are replaced according to `fill`. A series of `DataType::NA`
+    /// filled with a value takes the data type of the value. A `DataType::Double` value filling a series of
+    /// `DataType::Float` is rounded to the nearest float.
+    ///
+    /// # Errors
+    /// `RaccoonError::TypeMismatch` if the value of `Fill::Value` cannot be converted to the data type of the series
+    /// without losing information, such as `2.7` filling a series of integers.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataEntry, Fill, Series};
+    /// let series = Series::from(vec![DataEntry::NA, DataEntry::Long(3), DataEntry::NA, DataEntry::Long(5)]);
+    /// assert_eq!(series.fillna(Fill::Value(DataEntry::Integer(0))).unwrap(), vec![0i64, 3, 0, 5]);
+    /// assert_eq!(series.fillna(Fill::Backward).unwrap(), vec![3i64, 3, 5, 5]);
+    ///
+    /// let filled = series.fillna(Fill::Forward).unwrap();
+    /// assert_eq!(filled.get(0), Some(DataEntry::NA));
+    /// assert_eq!(filled.get(2), Some(DataEntry::Long(3)));
+    ///
+    /// assert!(series.fillna(Fill::Value(DataEntry::from("none"))).is_err());
+    /// assert!(series.fillna(Fill::Value(DataEntry::Double(2.7))).is_err());
+    /// ```
+    pub fn fillna(&self, fill: Fill) -> RaccoonResult<Series> {
+        let mut entries: Vec<DataEntry> = self.iter().collect();
+        let mut data_type = self.data_type.clone();
+        match fill {
+            Fill::Value(value)  => {
+                let value = if data_type == DataType::NA {
+                    data_type = value.data_type();
+                    value
+                } else {
+                    let converted = value.convert_to(&data_type);
+                    // numbers are compared exactly across types, other entries by converting them back
+                    let lossless = converted == value
+                        || OrderedEntry(converted.convert_to(&value.data_type())) == OrderedEntry(value.clone())
+                        || (data_type == DataType::Float && value.data_type() == DataType::Double);
+                    if (converted == DataEntry::NA || !lossless) && value != DataEntry::NA {
+                        return Err(RaccoonError::TypeMismatch { expected: data_type, found: value.data_type() });
+                    }
+                    converted
+                };
+                for entry in entries.iter_mut().filter(|x| x.is_missing()) {
+                    *entry = value.clone();
+                }
+            },
+            Fill::Forward       => fill_forward(entries.iter_mut()),
+            Fill::Backward      => fill_forward(entries.iter_mut().rev()),
+        }
+        Ok(self.with_entries(data_type, entries))
+    }
+
+    /// Returns a new series in which missing entries are linearly interpolated between the closest preceding and
+    /// following entries that are not missing, assuming evenly spaced entries. Leading and trailing missing entries
+    /// are kept. The resulting series is of `DataType::Float` for a series of floats, and of `DataType::Double`
+    /// otherwise.
+    ///
+    /// # Errors
+    /// `RaccoonError::UnsupportedOperation` if the series is not numerical.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataEntry, Series};
+    /// let series = Series::from(vec![DataEntry::NA, DataEntry::Integer(1), DataEntry::NA, DataEntry::NA,
+    ///                                DataEntry::Integer(4), DataEntry::NA]);
+    /// let interpolated = series.interpolate().unwrap();
+    /// assert_eq!(interpolated.get(0), Some(DataEntry::NA));
+    /// assert_eq!(interpolated.get(2), Some(DataEntry::Double(2.0)));
+    /// assert_eq!(interpolated.get(3), Some(DataEntry::Double(3.0)));
+    /// assert_eq!(interpolated.get(5), Some(DataEntry::NA));
+    /// ```
+    pub fn interpolate(&self) -> RaccoonResult<Series> {
+        let data_type = match self.data_type {
+            DataType::Float                             => DataType::Float,
+            ref data_type if data_type.is_numeric()     => DataType::Double,
+            DataType::NA                                => return Ok(self.clone()),
+            ref data_type                               => {
+                return Err(RaccoonError::UnsupportedOperation(format!("interpolate of {:?}", data_type)))
+            },
+        };
+        let mut entries: Vec<DataEntry> = self.iter().map(|x| x.convert_to(&DataType::Double)).collect();
+        let mut previous: Option<(usize, f64)> = None;
+        for idx in 0..entries.len() {
+            let value = match entries[idx] {
+                DataEntry::Double(value) if !value.is_nan() => value,
+                _                                           => continue,
+            };
+            if let Some((start, start_value)) = previous {
+                let step = (value - start_value) / (idx - start) as f64;
+                for (offset, entry) in entries[start + 1..idx].iter_mut().enumerate() {
+                    *entry = DataEntry::Double(start_value + step * (offset + 1) as f64);
+                }
+            }
+            previous = Some((idx, value));
+        }
+        Ok(self.with_entries(data_type, entries))
+    }
+
+    /// Returns the positions at which this boolean series is true, verifying that it can mask `length` entries.
+    pub(crate) fn positions(&self, length: usize) -> RaccoonResult<Vec<usize>> {
+        if self.data_type != DataType::Boolean {
+            return Err(RaccoonError::TypeMismatch { expected: DataType::Boolean, found: self.data_type.clone() });
+        }
+        if self.len() != length {
+            return Err(RaccoonError::LengthMismatch { expected: length, found: self.len() });
+        }
+        Ok(self.iter().enumerate().filter(|(_, x)| *x == DataEntry::Boolean(true)).map(|(idx, _)| idx).collect())
+    }
+
+    /// Verifies that the entries of the series can be compared with entries of `data_type`.
+    pub(crate) fn check_comparable(&self, data_type: &DataType) -> RaccoonResult {
+        let comparable = self.data_type == *data_type || self.data_type == DataType::NA || *data_type == DataType::NA
+            || (self.data_type.is_numeric() && data_type.is_numeric());
+        if comparable {
+            Ok(())
+        } else {
+            Err(RaccoonError::UnsupportedOperation(format!("comparison of {:?} with {:?}", self.data_type, data_type)))
+        }
+    }
+
+    /// Applies `operator` to the entries of both series matched by label. The result is named after this series.
+    fn zip_with<F>(&self, other: &Series, operator: F) -> RaccoonResult<Series>
+        where F: Fn(DataEntry, DataEntry) -> RaccoonResult<DataEntry> {
+        Alignment::new(self, other).zip_with(operator)
+    }
+
+    /// Applies `operator` to every entry of the series and `scalar`. The result is named after this series.
+    fn broadcast<F>(&self, scalar: DataEntry, operator: F) -> RaccoonResult<Series>
+        where F: Fn(DataEntry, DataEntry) -> RaccoonResult<DataEntry> {
+        let data_type = operator(unit(&self.data_type), unit(&scalar.data_type()))?.data_type();
+        let entries = self.iter().map(|x| operator(x, scalar.clone()).unwrap_or(DataEntry::NA));
+        Ok(self.with_entries(data_type, entries))
     }
 }
 
+/// Returns an entry of the given data type whose value is neutral enough to be used by any arithmetic operation.
+/// It is used to determine the data type resulting from an operation, following the promotion rules of `DataEntry`.
+pub(crate) fn unit(data_type: &DataType) -> DataEntry {
+    match *data_type {
+        DataType::Text      => DataEntry::Text("1".to_owned()),
+        DataType::Integer   => DataEntry::Integer(1),
+        DataType::UInteger  => DataEntry::UInteger(1),
+        DataType::Long      => DataEntry::Long(1),
+        DataType::ULong     => DataEntry::ULong(1),
+        DataType::Float     => DataEntry::Float(1.0),
+        DataType::Double    => DataEntry::Double(1.0),
+        DataType::Boolean   => DataEntry::Boolean(true),
+        DataType::Character => DataEntry::Character('1'),
+        DataType::Date      => DataEntry::Date(datetime::epoch().date()),
+        DataType::DateTime  => DataEntry::DateTime(datetime::epoch(), None),
+        DataType::Duration  => DataEntry::Duration(Duration::seconds(1)),
+        DataType::NA        => DataEntry::NA,
+    }
+}
+
+/// Compares two entries with `predicate`, giving `DataEntry::NA` if either entry is `DataEntry::NA`.
+pub(crate) fn compare<F>(x1: DataEntry, x2: DataEntry, predicate: F) -> DataEntry
+    where F: Fn(Option<Ordering>) -> bool {
+    if x1 == DataEntry::NA || x2 == DataEntry::NA {
+        DataEntry::NA
+    } else {
+        DataEntry::Boolean(predicate(x1.compare(&x2)))
+    }
+}
+
+/// Replaces missing entries with the last preceding entry that is not missing, in the order of `entries`.
+fn fill_forward<'a, I>(entries: I) where I: Iterator<Item = &'a mut DataEntry> {
+    let mut last: Option<DataEntry> = None;
+    for entry in entries {
+        if !entry.is_missing() {
+            last = Some(entry.clone());
+        } else if let Some(ref value) = last {
+            *entry = value.clone();
+        }
+    }
+}
+
+/// The right hand side of an element-wise operation on a series: either another series, or a single value applied to
+/// every entry. It is built implicitly from a `&Series` or from anything convertible into a `DataEntry`.
+#[derive(Debug, Clone)]
+pub enum Operand<'a> {
+    /// A series, whose entries are used pairwise.
+    Series(&'a Series),
+    /// A single value.
+    Scalar(DataEntry),
+}
+
+impl<'a> From<&'a Series> for Operand<'a> {
+    fn from(series: &'a Series) -> Self {
+        Operand::Series(series)
+    }
+}
+
+impl<'a, T> From<T> for Operand<'a> where T: Into<DataEntry> {
+    fn from(scalar: T) -> Self {
+        Operand::Scalar(scalar.into())
+    }
+}
+
+impl fmt::Debug for Series {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Series")
+            .field("name", &self.name)
+            .field("data_type", &self.data_type)
+            .field("entries", &self.iter().collect::<Vec<DataEntry>>())
+            .finish()
+    }
+}
+
+/// An iterator over the entries of a [`Series`], created by [`Series::iter`].
+///
+/// [`Series`]: ./struct.Series.html
+/// [`Series::iter`]: ./struct.Series.html#method.iter
+#[derive(Debug, Clone)]
+pub struct Iter<'a> {
+    series: &'a Series,
+    idx: usize,
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = DataEntry;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let entry = self.series.get(self.idx)?;
+        self.idx += 1;
+        Some(entry)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.series.len() - self.idx;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a> ExactSizeIterator for Iter<'a> {}
+
 impl<T> From<Vec<T>> for Series where T: Into<DataEntry> {
     fn from(vector: Vec<T>) -> Self {
         Series::from_vector("Series1".to_owned(), vector)
     }
 }
 
-impl Index<usize> for Series {
-    type Output = DataEntry;
+impl PartialEq for Series {
+    fn eq(&self, other: &Series) -> bool {
+        self.name == other.name && self.data_type == other.data_type && self.len() == other.len()
+            && self.iter().eq(other.iter())
+    }
+}
+
+impl Add<&Series> for &Series {
+    type Output = RaccoonResult<Series>;
 
-    fn index(&self, idx: usize) -> &Self::Output {
-        &self.entries[idx]
+    fn add(self, other: &Series) -> Self::Output {
+        self.zip_with(other, DataEntry::checked_add)
     }
 }
 
-impl PartialEq for Series {
-    fn eq(&self, other: &Series) -> bool {
-        if self.name == other.name && self.data_type == other.data_type && self.entries.len() == other.entries.len() {
-            if self.entries.iter().zip(other.entries.iter()).all(|(ref x1, ref x2)| { x1 == x2 }) {
-                true
-            } else {
-                false
-            }
-        } else {
-            false
-        }
+impl<T> Add<T> for &Series where T: Into<DataEntry> {
+    type Output = RaccoonResult<Series>;
+
+    fn add(self, scalar: T) -> Self::Output {
+        self.broadcast(scalar.into(), DataEntry::checked_add)
+    }
+}
+
+impl Sub<&Series> for &Series {
+    type Output = RaccoonResult<Series>;
+
+    fn sub(self, other: &Series) -> Self::Output {
+        self.zip_with(other, DataEntry::checked_sub)
+    }
+}
+
+impl<T> Sub<T> for &Series where T: Into<DataEntry> {
+    type Output = RaccoonResult<Series>;
+
+    fn sub(self, scalar: T) -> Self::Output {
+        self.broadcast(scalar.into(), DataEntry::checked_sub)
+    }
+}
+
+impl Mul<&Series> for &Series {
+    type Output = RaccoonResult<Series>;
+
+    fn mul(self, other: &Series) -> Self::Output {
+        self.zip_with(other, DataEntry::checked_mul)
+    }
+}
+
+impl<T> Mul<T> for &Series where T: Into<DataEntry> {
+    type Output = RaccoonResult<Series>;
+
+    fn mul(self, scalar: T) -> Self::Output {
+        self.broadcast(scalar.into(), DataEntry::checked_mul)
+    }
+}
+
+impl Div<&Series> for &Series {
+    type Output = RaccoonResult<Series>;
+
+    fn div(self, other: &Series) -> Self::Output {
+        self.zip_with(other, DataEntry::checked_div)
+    }
+}
+
+impl<T> Div<T> for &Series where T: Into<DataEntry> {
+    type Output = RaccoonResult<Series>;
+
+    fn div(self, scalar: T) -> Self::Output {
+        self.broadcast(scalar.into(), DataEntry::checked_div)
     }
 }
 
 impl<T> PartialEq<Vec<T>> for Series where DataEntry: From<T>, T: Clone {
     fn eq(&self, other: &Vec<T>) -> bool {
-        if self.entries.iter().zip(other.iter()).all(|(x1, x2)| { *x1 == DataEntry::from(x2.clone()) }) {
-            true
-        } else {
-            false
-        }
+        self.len() == other.len() && self.iter().zip(other.iter()).all(|(x1, x2)| x1 == DataEntry::from(x2.clone()))
     }
 }
 
@@ -520,11 +1581,11 @@ mod tests {
         series.convert_to(&DataType::UInteger);
         assert_eq!("Numbers", series.name());
         assert_eq!(&DataType::UInteger, series.data_type());
-        assert_eq!(DataEntry::UInteger(1u32), series[0usize]);
-        assert_eq!(DataEntry::UInteger(2u32), series[1usize]);
-        assert_eq!(DataEntry::UInteger(3u32), series[2usize]);
-        assert_eq!(DataEntry::NA, series[3usize]);
-        assert_eq!(DataEntry::UInteger(4u32), series[4usize]);
+        assert_eq!(DataEntry::UInteger(1u32), series.get(0).unwrap());
+        assert_eq!(DataEntry::UInteger(2u32), series.get(1).unwrap());
+        assert_eq!(DataEntry::UInteger(3u32), series.get(2).unwrap());
+        assert_eq!(DataEntry::NA, series.get(3).unwrap());
+        assert_eq!(DataEntry::UInteger(4u32), series.get(4).unwrap());
     }
 
     #[test]
@@ -532,14 +1593,19 @@ mod tests {
         let mut series = Series::new("name".to_owned(), DataType::Integer);
         let result = series.push_vec(vec![0, 1, 2]);
         assert!(result.is_ok());
-        assert_eq!(DataEntry::Integer(0i32), series[0usize]);
-        assert_eq!(DataEntry::Integer(1i32), series[1usize]);
-        assert_eq!(DataEntry::Integer(2i32), series[2usize]);
+        assert_eq!(DataEntry::Integer(0i32), series.get(0).unwrap());
+        assert_eq!(DataEntry::Integer(1i32), series.get(1).unwrap());
+        assert_eq!(DataEntry::Integer(2i32), series.get(2).unwrap());
         let result = series.push(3);
         assert!(result.is_ok());
-        assert_eq!(DataEntry::Integer(3i32), series[3usize]);
+        assert_eq!(DataEntry::Integer(3i32), series.get(3).unwrap());
         let result = series.push(true);
         assert!(result.is_err());
+
+        let result = series.push_vec(vec![DataEntry::Integer(4), DataEntry::Long(5)]);
+        assert!(matches!(result, Err(RaccoonError::TypeMismatch { expected: DataType::Integer, .. })));
+        assert_eq!(4, series.len());
+        assert!(series.push_entry(DataEntry::NA).is_ok());
     }
 
     #[test]
@@ -560,13 +1626,13 @@ mod tests {
         let series = Series::from_vector("Some series".to_owned(), vec);
         assert_eq!("Some series", series.name());
         assert_eq!(&DataType::Integer, series.data_type());
-        assert_eq!(DataEntry::Integer(1), series[0usize]);
-        assert_eq!(DataEntry::Integer(2), series[1usize]);
-        assert_eq!(DataEntry::Integer(3), series[2usize]);
-        assert_eq!(DataEntry::Integer(4), series[3usize]);
-        assert_eq!(DataEntry::Integer(5), series[4usize]);
-        assert_eq!(DataEntry::Integer(6), series[5usize]);
-        assert_eq!(DataEntry::Integer(7), series[6usize]);
+        assert_eq!(DataEntry::Integer(1), series.get(0).unwrap());
+        assert_eq!(DataEntry::Integer(2), series.get(1).unwrap());
+        assert_eq!(DataEntry::Integer(3), series.get(2).unwrap());
+        assert_eq!(DataEntry::Integer(4), series.get(3).unwrap());
+        assert_eq!(DataEntry::Integer(5), series.get(4).unwrap());
+        assert_eq!(DataEntry::Integer(6), series.get(5).unwrap());
+        assert_eq!(DataEntry::Integer(7), series.get(6).unwrap());
 
         let vec: Vec<u64> = Vec::new();
         let series = Series::from_vector("empty".to_owned(), vec);
@@ -575,11 +1641,10 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected="index out of bounds: the len is 0 but the index is 0")]
-    fn empty_indexing() {
+    fn empty_access() {
         let vec: Vec<u64> = Vec::new();
         let series = Series::from(vec);
-        let _ = &series[0_usize];
+        assert_eq!(None, series.get(0));
     }
 
     #[test]
@@ -590,13 +1655,13 @@ mod tests {
         series.set_name("My new name".to_owned());
         assert_eq!("My new name", series.name());
         assert_eq!(&DataType::Boolean, series.data_type());
-        assert_eq!(DataEntry::Boolean(true), series[0usize]);
-        assert_eq!(DataEntry::Boolean(false), series[1usize]);
-        assert_eq!(DataEntry::Boolean(false), series[2usize]);
-        assert_eq!(DataEntry::Boolean(true), series[3usize]);
-        assert_eq!(DataEntry::Boolean(false), series[4usize]);
-        assert_eq!(DataEntry::Boolean(true), series[5usize]);
-        assert_eq!(DataEntry::Boolean(true), series[6usize]);
+        assert_eq!(DataEntry::Boolean(true), series.get(0).unwrap());
+        assert_eq!(DataEntry::Boolean(false), series.get(1).unwrap());
+        assert_eq!(DataEntry::Boolean(false), series.get(2).unwrap());
+        assert_eq!(DataEntry::Boolean(true), series.get(3).unwrap());
+        assert_eq!(DataEntry::Boolean(false), series.get(4).unwrap());
+        assert_eq!(DataEntry::Boolean(true), series.get(5).unwrap());
+        assert_eq!(DataEntry::Boolean(true), series.get(6).unwrap());
         assert_eq!(7, series.len());
     }
 
@@ -604,6 +1669,9 @@ mod tests {
     fn comparisions() {
         let series1 = Series::from(vec![1, 2, 3, 4]);
         assert_eq!(series1, vec![1, 2, 3, 4]);
+        assert_ne!(series1, vec![1, 2, 3]);
+        assert_ne!(series1, vec![1, 2, 3, 4, 5]);
+        assert_ne!(Series::new("empty".to_owned(), DataType::Integer), vec![1]);
         let series2 = Series::from(vec![1, 2, 3, 4]);
         assert_eq!(series1, series2);
         let mut series3 = Series::from(vec![1, 2, 3, 4]);
@@ -612,4 +1680,205 @@ mod tests {
         let series4 = Series::from(vec![1.0, 2.0, 3.0, 4.0]);
         assert_ne!(series1, series4);
     }
+
+    #[test]
+    fn typed_storage() {
+        let series = Series::from(vec![DataEntry::NA, DataEntry::Long(2), DataEntry::Integer(3)]);
+        assert_eq!(&DataType::Long, series.data_type());
+        assert_eq!(series, vec![DataEntry::NA, DataEntry::Long(2), DataEntry::Long(3)]);
+
+        let mut series = Series::with_capacity("text".to_owned(), DataType::Text, 2);
+        series.push("abc").unwrap();
+        assert_eq!(DataEntry::from("abc"), series.get(0).unwrap());
+        series.push_entry(DataEntry::NA).unwrap();
+        assert_eq!(DataEntry::NA, series.get(1).unwrap());
+        assert_eq!(Some(DataEntry::NA), series.pop_entry());
+        assert_eq!(1, series.len());
+        assert_eq!(None, series.get(1));
+        assert!(series.memory_usage() < 2 * size_of::<DataEntry>());
+    }
+
+    #[test]
+    fn comparison_masks() {
+        let a = Series::from(vec![DataEntry::Integer(1), DataEntry::Integer(5), DataEntry::NA, DataEntry::Integer(7)]);
+        let b = Series::from(vec![1u64, 2, 3, u64::MAX]);
+        assert_eq!(a.eq(&b).unwrap(), vec![DataEntry::Boolean(true), false.into(), DataEntry::NA, false.into()]);
+        assert_eq!(a.ge(&b).unwrap(), vec![DataEntry::Boolean(true), true.into(), DataEntry::NA, false.into()]);
+        assert_eq!(a.lt(5.5f64).unwrap(), vec![DataEntry::Boolean(true), true.into(), DataEntry::NA, false.into()]);
+        assert_eq!(a.ne(DataEntry::NA).unwrap(), vec![DataEntry::NA, DataEntry::NA, DataEntry::NA, DataEntry::NA]);
+
+        let floats = Series::from(vec![f64::NAN, 0.0]);
+        assert_eq!(floats.eq(f64::NAN).unwrap(), vec![false, false]);
+        assert_eq!(floats.ne(-0.0f32).unwrap(), vec![true, false]);
+
+        let text = Series::from(vec!["b", "a"]);
+        assert_eq!(text.le("a").unwrap(), vec![false, true]);
+        assert!(matches!(text.gt(1), Err(RaccoonError::UnsupportedOperation(_))));
+        assert!(matches!(a.eq(&text), Err(RaccoonError::LengthMismatch { expected: 4, found: 2 })));
+    }
+
+    #[test]
+    fn label_selection() {
+        let series = Series::from_vector("x".to_owned(), vec![10, 20, 30, 40])
+            .with_index(Index::from(vec!['d', 'b', 'b', 'a']))
+            .unwrap();
+        let selected = series.loc('b').unwrap();
+        assert_eq!(selected.name(), "x");
+        assert_eq!(selected, vec![20, 30]);
+        assert_eq!(selected.index().to_series(), vec!['b', 'b']);
+        assert_eq!(series.loc('b'..='a').unwrap(), vec![20, 30, 40]);
+        assert!(matches!(series.loc('c'), Err(RaccoonError::LabelNotFound(_))));
+
+        assert_eq!(series.iloc(1..3).unwrap().index().to_series(), vec!['b', 'b']);
+        assert_eq!(series.iloc(vec![3, 0]).unwrap(), vec![40, 10]);
+        assert!(matches!(series.iloc(4), Err(RaccoonError::IndexOutOfBounds { index: 4, length: 4 })));
+
+        let series = Series::from(vec![5, 6, 7]);
+        assert_eq!(series.loc(1i64..=5i64).unwrap().index().to_series(), vec![1i64, 2]);
+    }
+
+    #[test]
+    fn index_labels() {
+        let mut series = Series::from(vec![DataEntry::Integer(3), DataEntry::NA, DataEntry::Integer(1)])
+            .with_index(Index::from(vec!["a", "b", "c"]))
+            .unwrap();
+        assert_eq!(series.sort(true).index().to_series(), vec!["c", "a", "b"]);
+        assert_eq!(series.dropna().index().to_series(), vec!["a", "c"]);
+        assert_eq!(series.isna().index(), series.index());
+        assert_eq!(series.take(&[1]).unwrap().index().to_series(), vec!["b"]);
+
+        series.push(4).unwrap();
+        assert_eq!(series.index().get(3), Some(DataEntry::NA));
+        series.pop_entry();
+        series.pop_entry();
+        assert_eq!(series.index().to_series(), vec!["a", "b"]);
+        assert!(matches!(series.clone().with_index(Index::range(1)), Err(RaccoonError::LengthMismatch { .. })));
+    }
+
+    #[test]
+    fn append() {
+        let series = Series::from(vec![DataEntry::Integer(3), DataEntry::NA])
+            .with_index(Index::from(vec!["a", "b"]))
+            .unwrap();
+        let other = Series::from(vec![2.5]).with_index(Index::from(vec!["a"])).unwrap();
+        let appended = series.append(&other).unwrap();
+        assert_eq!(appended.index().to_series(), vec!["a", "b", "a"]);
+        assert_eq!(appended, vec![DataEntry::Double(3.0), DataEntry::NA, DataEntry::Double(2.5)]);
+        assert!(matches!(series.append(&Series::from(vec![1])), Err(RaccoonError::TypeMismatch { .. })));
+        assert_eq!(Series::from(vec![1, 2]).append(&Series::from(vec![3])).unwrap().index(), &Index::range(3));
+    }
+
+    #[test]
+    fn filtering() {
+        let series = Series::from_vector("values".to_owned(), vec![4, 8, 15, 16, 23, 42]);
+        let mask = series.gt(10).unwrap();
+        let filtered = series.filter(&mask).unwrap();
+        assert_eq!("values", filtered.name());
+        assert_eq!(filtered, vec![15, 16, 23, 42]);
+
+        let mask = Series::from(vec![DataEntry::NA, DataEntry::Boolean(true)]);
+        assert!(matches!(series.filter(&mask), Err(RaccoonError::LengthMismatch { expected: 6, found: 2 })));
+        assert!(matches!(series.filter(&series), Err(RaccoonError::TypeMismatch { expected: DataType::Boolean, .. })));
+
+        let series = Series::from(vec!['a', 'b']);
+        assert_eq!(series.filter(&mask).unwrap(), vec!['b']);
+    }
+
+    #[test]
+    fn arithmetic() {
+        let mut a = Series::from_vector("a".to_owned(), vec![1, 2, 3]);
+        a.push_entry(DataEntry::NA).unwrap();
+        let b = Series::from_vector("b".to_owned(), vec![4u32, 5, 6, 7]);
+
+        let result = (&a - &b).unwrap();
+        assert_eq!("a", result.name());
+        assert_eq!(&DataType::Long, result.data_type());
+        assert_eq!(result, vec![DataEntry::Long(-3), DataEntry::Long(-3), DataEntry::Long(-3), DataEntry::NA]);
+
+        let result = (&b * &a).unwrap();
+        assert_eq!(&DataType::Long, result.data_type());
+        assert_eq!(result, vec![DataEntry::Long(4), DataEntry::Long(10), DataEntry::Long(18), DataEntry::NA]);
+
+        let result = (&a + 1.5f32).unwrap();
+        assert_eq!(&DataType::Float, result.data_type());
+        assert_eq!(result, vec![DataEntry::Float(2.5), DataEntry::Float(3.5), DataEntry::Float(4.5), DataEntry::NA]);
+
+        let result = (&b / DataEntry::NA).unwrap();
+        assert_eq!(&DataType::NA, result.data_type());
+        assert_eq!(4, result.len());
+
+        let text = Series::from(vec!["ab", "c"]);
+        let result = (&text * &Series::from(vec![2, 3])).unwrap();
+        assert_eq!(&DataType::Text, result.data_type());
+        assert_eq!(result, vec!["abab", "ccc"]);
+
+        let result = &a + &Series::from(vec![1, 2]);
+        assert!(matches!(result, Err(RaccoonError::LengthMismatch { expected: 4, found: 2 })));
+        let result = &text - 1;
+        assert!(matches!(result, Err(RaccoonError::UnsupportedOperation(_))));
+    }
+
+    #[test]
+    fn arithmetic_failures_become_na() {
+        let a = Series::from(vec![i32::MAX, 6, 8]);
+        let b = Series::from(vec![1, 0, 2]);
+        let result = (&a / &b).unwrap();
+        assert_eq!(&DataType::Integer, result.data_type());
+        assert_eq!(result, vec![DataEntry::Integer(i32::MAX), DataEntry::NA, DataEntry::Integer(4)]);
+
+        let result = (&a + &b).unwrap();
+        assert_eq!(result, vec![DataEntry::NA, DataEntry::Integer(6), DataEntry::Integer(10)]);
+    }
+
+    #[test]
+    fn sorting() {
+        let series = Series::from_vector("x".to_owned(), vec![
+            DataEntry::Float(0.5), DataEntry::NA, DataEntry::Float(-2.0),
+            DataEntry::Float(f32::NAN), DataEntry::Float(0.5),
+        ]);
+        assert_eq!(vec![2, 0, 4, 1, 3], series.argsort(true));
+        assert_eq!(vec![0, 4, 2, 1, 3], series.argsort(false));
+        let sorted = series.sort(true);
+        assert_eq!("x", sorted.name());
+        assert_eq!(&DataType::Float, sorted.data_type());
+        assert_eq!(DataEntry::Float(-2.0), sorted.get(0).unwrap());
+        assert_eq!(DataEntry::NA, sorted.get(3).unwrap());
+        assert!(Series::new("empty".to_owned(), DataType::Text).sort(false).is_empty());
+    }
+
+    #[test]
+    fn missing_values() {
+        let series = Series::from_vector("x".to_owned(), vec![
+            DataEntry::NA, DataEntry::Float(1.0), DataEntry::Float(f32::NAN), DataEntry::NA, DataEntry::Float(2.5),
+        ]);
+        assert_eq!(series.isna(), vec![true, false, true, true, false]);
+        assert_eq!("x", series.notna().name());
+        assert_eq!(series.dropna(), vec![1.0f32, 2.5]);
+
+        assert_eq!(series.fillna(Fill::Value(DataEntry::Integer(0))).unwrap(), vec![0.0f32, 1.0, 0.0, 0.0, 2.5]);
+        let forward = series.fillna(Fill::Forward).unwrap();
+        assert_eq!(DataEntry::NA, forward.get(0).unwrap());
+        assert_eq!(DataEntry::Float(1.0), forward.get(3).unwrap());
+        assert_eq!(series.fillna(Fill::Backward).unwrap(), vec![1.0f32, 1.0, 2.5, 2.5, 2.5]);
+        assert_eq!(series.fillna(Fill::Value(DataEntry::Double(0.1))).unwrap().get(0).unwrap(), DataEntry::Float(0.1));
+
+        let integers = Series::from(vec![DataEntry::Integer(1), DataEntry::NA]);
+        assert_eq!(integers.fillna(Fill::Value(DataEntry::Double(2.0))).unwrap(), vec![1, 2]);
+        let result = integers.fillna(Fill::Value(DataEntry::Double(2.7)));
+        assert!(matches!(result, Err(RaccoonError::TypeMismatch { .. })));
+        let result = integers.fillna(Fill::Value(DataEntry::Long(1 << 40)));
+        assert!(matches!(result, Err(RaccoonError::TypeMismatch { .. })));
+
+        let interpolated = series.interpolate().unwrap();
+        assert_eq!(&DataType::Float, interpolated.data_type());
+        assert_eq!(DataEntry::NA, interpolated.get(0).unwrap());
+        assert_eq!(DataEntry::Float(1.5), interpolated.get(2).unwrap());
+        assert_eq!(DataEntry::Float(2.0), interpolated.get(3).unwrap());
+
+        let empty = Series::from(vec![DataEntry::NA, DataEntry::NA]);
+        assert_eq!(empty.fillna(Fill::Value(DataEntry::from('a'))).unwrap(), vec!['a', 'a']);
+        assert_eq!(&DataType::NA, empty.interpolate().unwrap().data_type());
+        let result = Series::from(vec!["a"]).interpolate();
+        assert!(matches!(result, Err(RaccoonError::UnsupportedOperation(_))));
+    }
 }
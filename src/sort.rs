@@ -0,0 +1,185 @@
+//! Stable sorting of [`Series`] and [`DataFrame`]s by one or several keys.
+//!
+//! [`Series`]: ../series/struct.Series.html
+//! [`DataFrame`]: ../dataframe/struct.DataFrame.html
+//!
+//! Entries are compared like [`OrderedEntry`]s: numbers are compared by value across data types, and entries of
+//! different kinds are ordered booleans first, then numbers, characters and texts. Missing values, that is
+//! `DataEntry::NA` and NaN floating point numbers, are placed at the end by default, whatever the sort order, and keep
+//! their relative order. A [`Sorter`] allows to place them first instead.
+//!
+//! [`OrderedEntry`]: ../entry/struct.OrderedEntry.html
+//! [`Sorter`]: ./struct.Sorter.html
+//!
+//! # Examples
+//! ```
+//! use raccoon::{DataEntry, DataFrame, Series, SortOrder};
+//!
+//! let series = Series::from(vec![DataEntry::Double(2.5), DataEntry::NA, DataEntry::Double(-1.0)]);
+//! assert_eq!(series.argsort(true), vec![2, 0, 1]);
+//!
+//! let df = DataFrame::from_series(vec![
+//!     Series::from_vector("team".to_owned(), vec!["b", "a", "b", "a"]),
+//!     Series::from_vector("score".to_owned(), vec![3, 5, 4, 1]),
+//! ]).unwrap();
+//!
+//! let sorted = df.sort_by(&[("team", SortOrder::Ascending), ("score", SortOrder::Descending)]).unwrap();
+//! assert_eq!(sorted["team"], vec!["a", "a", "b", "b"]);
+//! assert_eq!(sorted["score"], vec![5, 1, 4, 3]);
+//! ```
+
+use entry::OrderedEntry;
+use error::{RaccoonResult, RaccoonError};
+use series::Series;
+
+use std::cmp::Ordering;
+
+/// The direction in which a key is sorted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    /// From the smallest to the largest entry.
+    Ascending,
+    /// From the largest to the smallest entry.
+    Descending,
+}
+
+/// The placement of missing values in a sorted sequence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NaPosition {
+    /// Missing values come before all other entries.
+    First,
+    /// Missing values come after all other entries.
+    Last,
+}
+
+/// Computes the stable sort order of rows given by one or several key series, using configurable options.
+///
+/// # Example
+/// ```
+/// use raccoon::{DataEntry, DataFrame, NaPosition, Series, Sorter, SortOrder};
+///
+/// let df = DataFrame::from_series(vec![
+///     Series::from_vector("city".to_owned(), vec!["Bern", "Basel", "Chur"]),
+///     Series::from_vector("lake".to_owned(), vec![DataEntry::from("Aare"), DataEntry::NA, DataEntry::NA]),
+/// ]).unwrap();
+///
+/// let order = Sorter::new()
+///     .by(&df["lake"], SortOrder::Descending)
+///     .by(&df["city"], SortOrder::Descending)
+///     .na_position(NaPosition::First)
+///     .argsort()
+///     .unwrap();
+/// assert_eq!(order, vec![2, 1, 0]);
+/// assert_eq!(df.take(&order).unwrap()["city"], vec!["Chur", "Basel", "Bern"]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Sorter<'a> {
+    keys: Vec<(&'a Series, SortOrder)>,
+    na_position: NaPosition,
+}
+
+impl<'a> Sorter<'a> {
+    /// Constructs a new `Sorter` without keys, placing missing values last.
+    pub fn new() -> Sorter<'a> {
+        Sorter {
+            keys: Vec::new(),
+            na_position: NaPosition::Last,
+        }
+    }
+
+    /// Adds a key. Rows are compared by the first key, and rows having equal first keys by the following keys.
+    pub fn by(mut self, series: &'a Series, order: SortOrder) -> Sorter<'a> {
+        self.keys.push((series, order));
+        self
+    }
+
+    /// Sets where missing values are placed. Defaults to `NaPosition::Last`.
+    pub fn na_position(mut self, na_position: NaPosition) -> Sorter<'a> {
+        self.na_position = na_position;
+        self
+    }
+
+    /// Returns the positions of the rows in sorted order. Rows having equal keys keep their original order.
+    ///
+    /// # Errors
+    /// `RaccoonError::LengthMismatch` if the keys do not all have the same length.
+    pub fn argsort(&self) -> RaccoonResult<Vec<usize>> {
+        let length = self.keys.first().map_or(0, |key| key.0.len());
+        if let Some(key) = self.keys.iter().find(|key| key.0.len() != length) {
+            return Err(RaccoonError::LengthMismatch { expected: length, found: key.0.len() });
+        }
+        let keys: Vec<(Vec<OrderedEntry>, SortOrder)> = self.keys.iter()
+            .map(|&(series, order)| (series.iter().map(OrderedEntry).collect(), order))
+            .collect();
+
+        let mut positions: Vec<usize> = (0..length).collect();
+        positions.sort_by(|&a, &b| {
+            for &(ref entries, order) in &keys {
+                let ordering = self.compare(&entries[a], &entries[b], order);
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+            Ordering::Equal
+        });
+        Ok(positions)
+    }
+
+    /// Compares two entries of a key, placing missing values according to `na_position`.
+    fn compare(&self, a: &OrderedEntry, b: &OrderedEntry, order: SortOrder) -> Ordering {
+        let missing = match (a.0.is_missing(), b.0.is_missing()) {
+            (true, true)    => return Ordering::Equal,
+            (false, false)  => None,
+            (true, false)   => Some(Ordering::Less),
+            (false, true)   => Some(Ordering::Greater),
+        };
+        match (missing, self.na_position, order) {
+            (Some(ordering), NaPosition::First, _)  => ordering,
+            (Some(ordering), NaPosition::Last, _)   => ordering.reverse(),
+            (None, _, SortOrder::Ascending)         => a.cmp(b),
+            (None, _, SortOrder::Descending)        => b.cmp(a),
+        }
+    }
+}
+
+impl<'a> Default for Sorter<'a> {
+    fn default() -> Self {
+        Sorter::new()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use entry::DataEntry;
+
+    #[test]
+    fn missing_values() {
+        let series = Series::from(vec![
+            DataEntry::Double(f64::NAN), DataEntry::Double(-1.0), DataEntry::NA, DataEntry::Double(3.0),
+        ]);
+        let ascending = Sorter::new().by(&series, SortOrder::Ascending).argsort().unwrap();
+        assert_eq!(vec![1, 3, 0, 2], ascending);
+        let descending = Sorter::new().by(&series, SortOrder::Descending).argsort().unwrap();
+        assert_eq!(vec![3, 1, 0, 2], descending);
+        let first = Sorter::new().by(&series, SortOrder::Descending).na_position(NaPosition::First).argsort().unwrap();
+        assert_eq!(vec![0, 2, 3, 1], first);
+    }
+
+    #[test]
+    fn stable_multiple_keys() {
+        let a = Series::from(vec![2, 1, 2, 1, 2]);
+        let b = Series::from(vec!['x', 'y', 'x', 'x', 'w']);
+        let order = Sorter::new().by(&a, SortOrder::Descending).by(&b, SortOrder::Ascending).argsort().unwrap();
+        assert_eq!(vec![4, 0, 2, 3, 1], order);
+
+        let order = Sorter::new().by(&a, SortOrder::Ascending).argsort().unwrap();
+        assert_eq!(vec![1, 3, 0, 2, 4], order);
+        assert_eq!(Vec::<usize>::new(), Sorter::new().argsort().unwrap());
+
+        let short = Series::from(vec![1]);
+        let result = Sorter::new().by(&a, SortOrder::Ascending).by(&short, SortOrder::Ascending).argsort();
+        assert!(matches!(result, Err(RaccoonError::LengthMismatch { expected: 5, found: 1 })));
+    }
+}
@@ -0,0 +1,390 @@
+//! Typed, columnar storage backing a `Series`.
+//!
+//! Instead of storing a `DataEntry` per value, a `Column` stores the values in a buffer typed after the data type of
+//! the series, and tracks missing values in a separate validity bitmap. Texts are concatenated into a single byte
+//! buffer, delimited by offsets. The value stored in the buffer for a missing entry is the default value of the type.
+
+use chrono::{Duration, FixedOffset, NaiveDate, NaiveDateTime};
+
+use entry::{DataEntry, DataType};
+
+use std::mem::size_of;
+
+/// A growable sequence of bits, packed into 64 bit words.
+#[derive(Debug, Clone)]
+pub struct Bitmap {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl Bitmap {
+    /// Constructs a new, empty bitmap able to hold `capacity` bits without reallocating.
+    pub fn with_capacity(capacity: usize) -> Bitmap {
+        Bitmap {
+            words: Vec::with_capacity(capacity.div_ceil(64)),
+            len: 0,
+        }
+    }
+
+    /// Returns the number of bits.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the bit at `idx`. Panics if `idx` is out of bounds.
+    pub fn get(&self, idx: usize) -> bool {
+        assert!(idx < self.len, "index out of bounds: the len is {} but the index is {}", self.len, idx);
+        self.words[idx / 64] & (1 << (idx % 64)) != 0
+    }
+
+    /// Appends a bit.
+    pub fn push(&mut self, bit: bool) {
+        if self.len.is_multiple_of(64) {
+            self.words.push(0);
+        }
+        if bit {
+            self.words[self.len / 64] |= 1 << (self.len % 64);
+        }
+        self.len += 1;
+    }
+
+    /// Removes the last bit and returns it, or `None` if the bitmap is empty.
+    pub fn pop(&mut self) -> Option<bool> {
+        if self.len == 0 {
+            return None;
+        }
+        let bit = self.get(self.len - 1);
+        self.len -= 1;
+        if self.len.is_multiple_of(64) {
+            self.words.pop();
+        } else {
+            self.words[self.len / 64] &= !(1 << (self.len % 64));
+        }
+        Some(bit)
+    }
+
+    /// Returns the number of bytes allocated by the bitmap.
+    pub fn memory_usage(&self) -> usize {
+        self.words.capacity() * size_of::<u64>()
+    }
+}
+
+/// The typed buffer of a column.
+#[derive(Debug, Clone)]
+enum Buffer {
+    Text { offsets: Vec<usize>, bytes: String },
+    Integer(Vec<i32>),
+    UInteger(Vec<u32>),
+    Long(Vec<i64>),
+    ULong(Vec<u64>),
+    Float(Vec<f32>),
+    Double(Vec<f64>),
+    Boolean(Bitmap),
+    Character(Vec<char>),
+    Date(Vec<NaiveDate>),
+    DateTime { values: Vec<NaiveDateTime>, offsets: Vec<Option<FixedOffset>> },
+    Duration(Vec<Duration>),
+    NA,
+}
+
+/// The entries of a series, stored in a buffer typed after the data type of the series.
+#[derive(Debug, Clone)]
+pub struct Column {
+    buffer: Buffer,
+    validity: Bitmap,
+}
+
+impl Column {
+    /// Constructs a new, empty column of `data_type`, able to hold `capacity` entries without reallocating.
+    pub fn with_capacity(data_type: &DataType, capacity: usize) -> Column {
+        let buffer = match *data_type {
+            DataType::Text      => {
+                let mut offsets = Vec::with_capacity(capacity + 1);
+                offsets.push(0);
+                Buffer::Text { offsets, bytes: String::new() }
+            },
+            DataType::Integer   => Buffer::Integer(Vec::with_capacity(capacity)),
+            DataType::UInteger  => Buffer::UInteger(Vec::with_capacity(capacity)),
+            DataType::Long      => Buffer::Long(Vec::with_capacity(capacity)),
+            DataType::ULong     => Buffer::ULong(Vec::with_capacity(capacity)),
+            DataType::Float     => Buffer::Float(Vec::with_capacity(capacity)),
+            DataType::Double    => Buffer::Double(Vec::with_capacity(capacity)),
+            DataType::Boolean   => Buffer::Boolean(Bitmap::with_capacity(capacity)),
+            DataType::Character => Buffer::Character(Vec::with_capacity(capacity)),
+            DataType::Date      => Buffer::Date(Vec::with_capacity(capacity)),
+            DataType::DateTime  => Buffer::DateTime {
+                values: Vec::with_capacity(capacity),
+                offsets: Vec::with_capacity(capacity),
+            },
+            DataType::Duration  => Buffer::Duration(Vec::with_capacity(capacity)),
+            DataType::NA        => Buffer::NA,
+        };
+        Column {
+            buffer,
+            validity: Bitmap::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the number of entries.
+    pub fn len(&self) -> usize {
+        self.validity.len()
+    }
+
+    /// Returns the entry at `idx`. Panics if `idx` is out of bounds.
+    pub fn get(&self, idx: usize) -> DataEntry {
+        if !self.validity.get(idx) {
+            return DataEntry::NA;
+        }
+        match self.buffer {
+            Buffer::Text { ref offsets, ref bytes } => {
+                DataEntry::Text(bytes[offsets[idx]..offsets[idx + 1]].to_owned())
+            },
+            Buffer::Integer(ref values)             => DataEntry::Integer(values[idx]),
+            Buffer::UInteger(ref values)            => DataEntry::UInteger(values[idx]),
+            Buffer::Long(ref values)                => DataEntry::Long(values[idx]),
+            Buffer::ULong(ref values)               => DataEntry::ULong(values[idx]),
+            Buffer::Float(ref values)               => DataEntry::Float(values[idx]),
+            Buffer::Double(ref values)              => DataEntry::Double(values[idx]),
+            Buffer::Boolean(ref values)             => DataEntry::Boolean(values.get(idx)),
+            Buffer::Character(ref values)           => DataEntry::Character(values[idx]),
+            Buffer::Date(ref values)                => DataEntry::Date(values[idx]),
+            Buffer::DateTime { ref values, ref offsets }    => DataEntry::DateTime(values[idx], offsets[idx]),
+            Buffer::Duration(ref values)            => DataEntry::Duration(values[idx]),
+            Buffer::NA                              => DataEntry::NA,
+        }
+    }
+
+    /// Returns the data type of the column.
+    pub fn data_type(&self) -> DataType {
+        match self.buffer {
+            Buffer::Text { .. }         => DataType::Text,
+            Buffer::Integer(_)          => DataType::Integer,
+            Buffer::UInteger(_)         => DataType::UInteger,
+            Buffer::Long(_)             => DataType::Long,
+            Buffer::ULong(_)            => DataType::ULong,
+            Buffer::Float(_)            => DataType::Float,
+            Buffer::Double(_)           => DataType::Double,
+            Buffer::Boolean(_)          => DataType::Boolean,
+            Buffer::Character(_)        => DataType::Character,
+            Buffer::Date(_)             => DataType::Date,
+            Buffer::DateTime { .. }     => DataType::DateTime,
+            Buffer::Duration(_)         => DataType::Duration,
+            Buffer::NA                  => DataType::NA,
+        }
+    }
+
+    /// Appends an entry. An entry of another data type than the column is converted with
+    /// [`DataEntry::convert_to`], hence stored as missing if it cannot be converted.
+    ///
+    /// [`DataEntry::convert_to`]: ../entry/enum.DataEntry.html#method.convert_to
+    pub fn push(&mut self, entry: DataEntry) {
+        let data_type = self.data_type();
+        let entry = if entry == DataEntry::NA || entry.data_type() == data_type {
+            entry
+        } else {
+            entry.convert_to(&data_type)
+        };
+        let valid = entry != DataEntry::NA;
+        match (&mut self.buffer, entry) {
+            (&mut Buffer::Text { ref mut offsets, ref mut bytes }, entry) => {
+                if let DataEntry::Text(text) = entry {
+                    bytes.push_str(&text);
+                }
+                offsets.push(bytes.len());
+            },
+            (&mut Buffer::Integer(ref mut values), entry)     => values.push(match entry {
+                DataEntry::Integer(int) => int,
+                _                       => 0,
+            }),
+            (&mut Buffer::UInteger(ref mut values), entry)    => values.push(match entry {
+                DataEntry::UInteger(int)    => int,
+                _                           => 0,
+            }),
+            (&mut Buffer::Long(ref mut values), entry)        => values.push(match entry {
+                DataEntry::Long(int)    => int,
+                _                       => 0,
+            }),
+            (&mut Buffer::ULong(ref mut values), entry)       => values.push(match entry {
+                DataEntry::ULong(int)   => int,
+                _                       => 0,
+            }),
+            (&mut Buffer::Float(ref mut values), entry)       => values.push(match entry {
+                DataEntry::Float(f)     => f,
+                _                       => 0.0,
+            }),
+            (&mut Buffer::Double(ref mut values), entry)      => values.push(match entry {
+                DataEntry::Double(f)    => f,
+                _                       => 0.0,
+            }),
+            (&mut Buffer::Boolean(ref mut values), entry)     => values.push(entry == DataEntry::Boolean(true)),
+            (&mut Buffer::Character(ref mut values), entry)   => values.push(match entry {
+                DataEntry::Character(ch)    => ch,
+                _                           => '\0',
+            }),
+            (&mut Buffer::Date(ref mut values), entry)        => values.push(match entry {
+                DataEntry::Date(date)   => date,
+                _                       => NaiveDate::default(),
+            }),
+            (&mut Buffer::DateTime { ref mut values, ref mut offsets }, entry)    => {
+                let (datetime, offset) = match entry {
+                    DataEntry::DateTime(datetime, offset)   => (datetime, offset),
+                    _                                       => (NaiveDateTime::default(), None),
+                };
+                values.push(datetime);
+                offsets.push(offset);
+            },
+            (&mut Buffer::Duration(ref mut values), entry)    => values.push(match entry {
+                DataEntry::Duration(duration)   => duration,
+                _                               => Duration::zero(),
+            }),
+            (&mut Buffer::NA, _)                              => {},
+        }
+        self.validity.push(valid && !self.is_na());
+    }
+
+    /// Removes the last entry and returns it, or `None` if the column is empty.
+    pub fn pop(&mut self) -> Option<DataEntry> {
+        let entry = self.get_checked(self.len().checked_sub(1)?);
+        match self.buffer {
+            Buffer::Text { ref mut offsets, ref mut bytes } => {
+                offsets.pop();
+                let end = offsets[offsets.len() - 1];
+                bytes.truncate(end);
+            },
+            Buffer::Integer(ref mut values)     => { values.pop(); },
+            Buffer::UInteger(ref mut values)    => { values.pop(); },
+            Buffer::Long(ref mut values)        => { values.pop(); },
+            Buffer::ULong(ref mut values)       => { values.pop(); },
+            Buffer::Float(ref mut values)       => { values.pop(); },
+            Buffer::Double(ref mut values)      => { values.pop(); },
+            Buffer::Boolean(ref mut values)     => { values.pop(); },
+            Buffer::Character(ref mut values)   => { values.pop(); },
+            Buffer::Date(ref mut values)        => { values.pop(); },
+            Buffer::DateTime { ref mut values, ref mut offsets }    => {
+                values.pop();
+                offsets.pop();
+            },
+            Buffer::Duration(ref mut values)    => { values.pop(); },
+            Buffer::NA                          => {},
+        }
+        self.validity.pop();
+        entry
+    }
+
+    /// Returns the entry at `idx`, or `None` if `idx` is out of bounds.
+    pub fn get_checked(&self, idx: usize) -> Option<DataEntry> {
+        if idx < self.len() {
+            Some(self.get(idx))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of bytes allocated by the buffers of the column.
+    pub fn memory_usage(&self) -> usize {
+        let buffer = match self.buffer {
+            Buffer::Text { ref offsets, ref bytes } => offsets.capacity() * size_of::<usize>() + bytes.capacity(),
+            Buffer::Integer(ref values)             => values.capacity() * size_of::<i32>(),
+            Buffer::UInteger(ref values)            => values.capacity() * size_of::<u32>(),
+            Buffer::Long(ref values)                => values.capacity() * size_of::<i64>(),
+            Buffer::ULong(ref values)               => values.capacity() * size_of::<u64>(),
+            Buffer::Float(ref values)               => values.capacity() * size_of::<f32>(),
+            Buffer::Double(ref values)              => values.capacity() * size_of::<f64>(),
+            Buffer::Boolean(ref values)             => values.memory_usage(),
+            Buffer::Character(ref values)           => values.capacity() * size_of::<char>(),
+            Buffer::Date(ref values)                => values.capacity() * size_of::<NaiveDate>(),
+            Buffer::DateTime { ref values, ref offsets }    => {
+                values.capacity() * size_of::<NaiveDateTime>() + offsets.capacity() * size_of::<Option<FixedOffset>>()
+            },
+            Buffer::Duration(ref values)            => values.capacity() * size_of::<Duration>(),
+            Buffer::NA                              => 0,
+        };
+        buffer + self.validity.memory_usage()
+    }
+
+    /// Checks whether the column can only contain missing entries.
+    fn is_na(&self) -> bool {
+        matches!(self.buffer, Buffer::NA)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bitmap() {
+        let mut bitmap = Bitmap::with_capacity(130);
+        for idx in 0..130 {
+            bitmap.push(idx % 3 == 0);
+        }
+        assert_eq!(130, bitmap.len());
+        assert!(bitmap.get(129));
+        assert!(!bitmap.get(128));
+        assert_eq!(Some(true), bitmap.pop());
+        assert_eq!(Some(false), bitmap.pop());
+        bitmap.push(true);
+        assert!(bitmap.get(128));
+        assert_eq!(129, bitmap.len());
+        assert_eq!(3 * 8, bitmap.memory_usage());
+    }
+
+    #[test]
+    fn text_column() {
+        let mut column = Column::with_capacity(&DataType::Text, 2);
+        column.push(DataEntry::Text("Zürich".to_owned()));
+        column.push(DataEntry::NA);
+        column.push(DataEntry::Text("".to_owned()));
+        column.push(DataEntry::Text("Bern".to_owned()));
+        assert_eq!(4, column.len());
+        assert_eq!(DataEntry::Text("Zürich".to_owned()), column.get(0));
+        assert_eq!(DataEntry::NA, column.get(1));
+        assert_eq!(DataEntry::Text("".to_owned()), column.get(2));
+        assert_eq!(Some(DataEntry::Text("Bern".to_owned())), column.pop());
+        assert_eq!(Some(DataEntry::Text("".to_owned())), column.pop());
+        column.push(DataEntry::Text("Genf".to_owned()));
+        assert_eq!(DataEntry::Text("Genf".to_owned()), column.get(2));
+        assert_eq!(None, column.get_checked(3));
+    }
+
+    #[test]
+    fn typed_columns() {
+        let entries = vec![DataEntry::Boolean(true), DataEntry::NA, DataEntry::Boolean(false)];
+        let mut column = Column::with_capacity(&DataType::Boolean, 0);
+        for entry in entries.clone() {
+            column.push(entry);
+        }
+        assert_eq!(entries, (0..3).map(|idx| column.get(idx)).collect::<Vec<_>>());
+
+        let mut column = Column::with_capacity(&DataType::Long, 4);
+        column.push(DataEntry::Long(-3));
+        column.push(DataEntry::NA);
+        assert_eq!(DataEntry::Long(-3), column.get(0));
+        assert_eq!(Some(DataEntry::NA), column.pop());
+        assert_eq!(Some(DataEntry::Long(-3)), column.pop());
+        assert_eq!(None, column.pop());
+
+        let mut column = Column::with_capacity(&DataType::NA, 1);
+        column.push(DataEntry::NA);
+        assert_eq!(1, column.len());
+        assert_eq!(DataEntry::NA, column.get(0));
+        assert_eq!(8, column.memory_usage());
+    }
+
+    #[test]
+    fn converting_entries() {
+        let mut column = Column::with_capacity(&DataType::Double, 3);
+        column.push(DataEntry::Integer(-3));
+        column.push(DataEntry::Text("Zürich".to_owned()));
+        column.push(DataEntry::Boolean(true));
+        assert_eq!(DataType::Double, column.data_type());
+        assert!(matches!(column.get(0), DataEntry::Double(value) if value == -3.0));
+        assert_eq!(DataEntry::NA, column.get(1));
+        assert!(matches!(column.get(2), DataEntry::Double(value) if value == 1.0));
+
+        let mut column = Column::with_capacity(&DataType::Text, 1);
+        column.push(DataEntry::UInteger(7));
+        assert_eq!(DataEntry::Text("7".to_owned()), column.get(0));
+    }
+}
@@ -0,0 +1,337 @@
+//! Vectorised text operations on [`Series`] of `DataType::Text`.
+//!
+//! [`Series`]: ../series/struct.Series.html
+//!
+//! [`Series::str`] returns a [`StrAccessor`], which applies an operation to every text of a series and returns a new
+//! series named after the original one. Missing entries stay missing. Lengths and positions are counted in characters
+//! rather than bytes. Patterns are matched literally, except by the methods ending in `_regex` and by `extract`, which
+//! take a regular expression in the syntax of the [`regex`] crate.
+//!
+//! [`Series::str`]: ../series/struct.Series.html#method.str
+//! [`StrAccessor`]: ./struct.StrAccessor.html
+//! [`regex`]: https://docs.rs/regex
+//!
+//! # Examples
+//! ```
+//! use raccoon::{DataEntry, Series};
+//!
+//! let series = Series::from(vec![DataEntry::from("  Zürich "), DataEntry::NA, DataEntry::from("Bern")]);
+//! let text = series.str().unwrap();
+//!
+//! assert_eq!(text.strip().str().unwrap().upper(), vec![DataEntry::from("ZÜRICH"), DataEntry::NA, "BERN".into()]);
+//! assert_eq!(text.len(), vec![DataEntry::Long(9), DataEntry::NA, DataEntry::Long(4)]);
+//! assert_eq!(text.contains("er"), vec![DataEntry::Boolean(false), DataEntry::NA, DataEntry::Boolean(true)]);
+//!
+//! let codes = Series::from(vec!["ZH-8001", "BE-3011"]);
+//! let parts = codes.str().unwrap().extract(r"(?P<canton>[A-Z]+)-(\d+)").unwrap();
+//! assert_eq!(parts.columns(), vec!["canton", "1"]);
+//! assert_eq!(parts["canton"], vec!["ZH", "BE"]);
+//!
+//! // only text series have an accessor
+//! assert!(Series::from(vec![1, 2]).str().is_err());
+//! ```
+
+use regex::Regex;
+
+use dataframe::DataFrame;
+use entry::{DataEntry, DataType};
+use error::{RaccoonResult, RaccoonError};
+use series::Series;
+
+/// The side on which `StrAccessor::pad` adds fill characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PadSide {
+    /// Adds the fill characters before the text, aligning it to the right.
+    Left,
+    /// Adds the fill characters after the text, aligning it to the left.
+    Right,
+    /// Adds the fill characters on both sides, centering the text. An odd number of fill characters has the extra
+    /// character on the right.
+    Both,
+}
+
+/// Vectorised operations on a series of texts, returned by [`Series::str`].
+///
+/// [`Series::str`]: ../series/struct.Series.html#method.str
+///
+/// # Example
+/// ```
+/// use raccoon::{DataEntry, PadSide, Series};
+///
+/// let series = Series::from(vec!["apple", "kiwi"]);
+/// let text = series.str().unwrap();
+///
+/// assert_eq!(text.startswith("ap"), vec![true, false]);
+/// assert_eq!(text.find("i"), vec![DataEntry::Long(-1), DataEntry::Long(1)]);
+/// assert_eq!(text.slice(1, Some(3)), vec!["pp", "iw"]);
+/// assert_eq!(text.pad(6, PadSide::Left, '.'), vec![".apple", "..kiwi"]);
+/// assert_eq!(text.replace_regex("[aeiou]", "_").unwrap(), vec!["_ppl_", "k_w_"]);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct StrAccessor<'a> {
+    series: &'a Series,
+}
+
+impl<'a> StrAccessor<'a> {
+    /// Constructs a new accessor on a series of texts.
+    ///
+    /// # Errors
+    /// `RaccoonError::TypeMismatch` if the series is neither of `DataType::Text` nor `DataType::NA`.
+    pub(crate) fn new(series: &'a Series) -> RaccoonResult<StrAccessor<'a>> {
+        match *series.data_type() {
+            DataType::Text | DataType::NA   => Ok(StrAccessor { series }),
+            ref data_type                   => Err(RaccoonError::TypeMismatch {
+                expected: DataType::Text,
+                found: data_type.clone(),
+            }),
+        }
+    }
+
+    /// Returns the number of characters of each text as `DataType::Long`.
+    pub fn len(&self) -> Series {
+        self.map(DataType::Long, |text| DataEntry::Long(text.chars().count() as i64))
+    }
+
+    /// Checks whether every text is empty, returning a series of `DataType::Boolean`.
+    pub fn is_empty(&self) -> Series {
+        self.map(DataType::Boolean, |text| DataEntry::Boolean(text.is_empty()))
+    }
+
+    /// Converts the texts to lowercase.
+    pub fn lower(&self) -> Series {
+        self.map(DataType::Text, |text| DataEntry::Text(text.to_lowercase()))
+    }
+
+    /// Converts the texts to uppercase.
+    pub fn upper(&self) -> Series {
+        self.map(DataType::Text, |text| DataEntry::Text(text.to_uppercase()))
+    }
+
+    /// Removes leading and trailing whitespace from the texts.
+    pub fn strip(&self) -> Series {
+        self.map(DataType::Text, |text| DataEntry::from(text.trim()))
+    }
+
+    /// Checks whether every text contains `pattern`, returning a series of `DataType::Boolean`.
+    pub fn contains(&self, pattern: &str) -> Series {
+        self.map(DataType::Boolean, |text| DataEntry::Boolean(text.contains(pattern)))
+    }
+
+    /// Checks whether every text starts with `prefix`, returning a series of `DataType::Boolean`.
+    pub fn startswith(&self, prefix: &str) -> Series {
+        self.map(DataType::Boolean, |text| DataEntry::Boolean(text.starts_with(prefix)))
+    }
+
+    /// Checks whether every text ends with `suffix`, returning a series of `DataType::Boolean`.
+    pub fn endswith(&self, suffix: &str) -> Series {
+        self.map(DataType::Boolean, |text| DataEntry::Boolean(text.ends_with(suffix)))
+    }
+
+    /// Replaces all occurrences of `from` with `to` in the texts.
+    pub fn replace(&self, from: &str, to: &str) -> Series {
+        self.map(DataType::Text, |text| DataEntry::Text(text.replace(from, to)))
+    }
+
+    /// Returns the position, in characters, of the first occurrence of `pattern` in every text as `DataType::Long`,
+    /// or -1 if the text does not contain it.
+    pub fn find(&self, pattern: &str) -> Series {
+        self.map(DataType::Long, |text| {
+            DataEntry::Long(text.find(pattern).map_or(-1, |idx| text[..idx].chars().count() as i64))
+        })
+    }
+
+    /// Returns the characters from position `start` up to, but excluding, position `stop` of the texts, or up to their
+    /// end if `stop` is `None`. Positions beyond the end of a text are clamped.
+    pub fn slice(&self, start: usize, stop: Option<usize>) -> Series {
+        let length = stop.map_or(usize::MAX, |stop| stop.saturating_sub(start));
+        self.map(DataType::Text, |text| DataEntry::Text(text.chars().skip(start).take(length).collect()))
+    }
+
+    /// Pads the texts with `fill` up to `width` characters. Texts that are at least `width` characters long are kept.
+    pub fn pad(&self, width: usize, side: PadSide, fill: char) -> Series {
+        self.map(DataType::Text, |text| {
+            let missing = width.saturating_sub(text.chars().count());
+            let (left, right) = match side {
+                PadSide::Left   => (missing, 0),
+                PadSide::Right  => (0, missing),
+                PadSide::Both   => (missing / 2, missing - missing / 2),
+            };
+            let mut padded = String::with_capacity(text.len() + missing * fill.len_utf8());
+            padded.extend((0..left).map(|_| fill));
+            padded.push_str(text);
+            padded.extend((0..right).map(|_| fill));
+            DataEntry::Text(padded)
+        })
+    }
+
+    /// Splits the texts around `separator`, into at most `n + 1` parts if `n` is set, and returns the parts in a
+    /// `DataFrame` whose columns are named after their position, starting at "0". Texts with fewer parts than others
+    /// have `DataEntry::NA` in the remaining columns.
+    ///
+    /// # Errors
+    /// `RaccoonError::InvalidArgument` if `separator` is empty.
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataEntry, Series};
+    /// let series = Series::from(vec!["a,b,c", "d"]);
+    /// let parts = series.str().unwrap().split(",", Some(1)).unwrap();
+    /// assert_eq!(parts.columns(), vec!["0", "1"]);
+    /// assert_eq!(parts["1"], vec![DataEntry::from("b,c"), DataEntry::NA]);
+    /// ```
+    pub fn split(&self, separator: &str, n: Option<usize>) -> RaccoonResult<DataFrame> {
+        if separator.is_empty() {
+            return Err(RaccoonError::InvalidArgument("split requires a non-empty separator".to_owned()));
+        }
+        let texts = self.texts();
+        let rows: Vec<Option<Vec<&str>>> = texts.iter()
+            .map(|text| text.as_ref().map(|text| match n {
+                Some(n) => text.splitn(n.saturating_add(1), separator).collect(),
+                None    => text.split(separator).collect(),
+            }))
+            .collect();
+        let width = rows.iter().filter_map(|parts| parts.as_ref().map(Vec::len)).max().unwrap_or(1);
+        let columns = (0..width).map(|idx| {
+            let entries = rows.iter().map(|parts| match parts.as_ref().and_then(|parts| parts.get(idx)) {
+                Some(part)  => DataEntry::from(*part),
+                None        => DataEntry::NA,
+            });
+            Series::from_entries(idx.to_string(), DataType::Text, entries)
+        });
+        DataFrame::from_series(columns.collect())
+    }
+
+    /// Checks whether every text contains a match of the regular expression `pattern`, returning a series of
+    /// `DataType::Boolean`.
+    ///
+    /// # Errors
+    /// `RaccoonError::InvalidArgument` if `pattern` is not a valid regular expression.
+    pub fn contains_regex(&self, pattern: &str) -> RaccoonResult<Series> {
+        let regex = compile(pattern)?;
+        Ok(self.map(DataType::Boolean, |text| DataEntry::Boolean(regex.is_match(text))))
+    }
+
+    /// Replaces all matches of the regular expression `pattern` with `replacement` in the texts. The replacement can
+    /// refer to capture groups, such as `$1` or `${name}`.
+    ///
+    /// # Errors
+    /// `RaccoonError::InvalidArgument` if `pattern` is not a valid regular expression.
+    pub fn replace_regex(&self, pattern: &str, replacement: &str) -> RaccoonResult<Series> {
+        let regex = compile(pattern)?;
+        Ok(self.map(DataType::Text, |text| DataEntry::Text(regex.replace_all(text, replacement).into_owned())))
+    }
+
+    /// Extracts the capture groups of the first match of the regular expression `pattern` in every text into a
+    /// `DataFrame` having a column of `DataType::Text` per group. Columns are named after named groups, and after the
+    /// position of the group, starting at "0", otherwise. Groups that do not participate in the match, and all groups
+    /// of texts that do not match, are `DataEntry::NA`.
+    ///
+    /// # Errors
+    /// `RaccoonError::InvalidArgument` if `pattern` is not a valid regular expression, or has no capture group.
+    pub fn extract(&self, pattern: &str) -> RaccoonResult<DataFrame> {
+        let regex = compile(pattern)?;
+        if regex.captures_len() < 2 {
+            return Err(RaccoonError::InvalidArgument(format!("pattern {:?} has no capture group", pattern)));
+        }
+        let texts = self.texts();
+        let captures: Vec<_> = texts.iter().map(|text| text.as_ref().and_then(|text| regex.captures(text))).collect();
+        let columns = regex.capture_names().enumerate().skip(1).map(|(group, name)| {
+            let name = name.map_or_else(|| (group - 1).to_string(), str::to_owned);
+            let entries = captures.iter().map(|captures| match captures.as_ref().and_then(|c| c.get(group)) {
+                Some(capture)   => DataEntry::from(capture.as_str()),
+                None            => DataEntry::NA,
+            });
+            Series::from_entries(name, DataType::Text, entries)
+        });
+        DataFrame::from_series(columns.collect())
+    }
+
+    /// Applies `operation` to every text, returning a series of `data_type`.
+    fn map<F>(&self, data_type: DataType, operation: F) -> Series where F: Fn(&str) -> DataEntry {
+        let entries = self.series.iter().map(|entry| match entry {
+            DataEntry::Text(ref text)   => operation(text),
+            _                           => DataEntry::NA,
+        });
+        self.series.with_entries(data_type, entries)
+    }
+
+    /// Returns the texts of the series, `None` standing for missing entries.
+    fn texts(&self) -> Vec<Option<String>> {
+        self.series.iter()
+            .map(|entry| match entry {
+                DataEntry::Text(text)   => Some(text),
+                _                       => None,
+            })
+            .collect()
+    }
+}
+
+/// Compiles a regular expression, reporting invalid patterns as an invalid argument.
+fn compile(pattern: &str) -> RaccoonResult<Regex> {
+    Regex::new(pattern).map_err(|error| RaccoonError::InvalidArgument(error.to_string()))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn literal_operations() {
+        let series = Series::from(vec![DataEntry::from("Grüezi"), DataEntry::NA, DataEntry::from("")]);
+        let text = series.str().unwrap();
+        assert_eq!(text.len(), vec![DataEntry::Long(6), DataEntry::NA, DataEntry::Long(0)]);
+        assert_eq!(text.is_empty(), vec![DataEntry::Boolean(false), DataEntry::NA, DataEntry::Boolean(true)]);
+        assert_eq!(text.lower(), vec![DataEntry::from("grüezi"), DataEntry::NA, DataEntry::from("")]);
+        assert_eq!(text.endswith("zi"), vec![DataEntry::Boolean(true), DataEntry::NA, DataEntry::Boolean(false)]);
+        assert_eq!(text.find("e"), vec![DataEntry::Long(3), DataEntry::NA, DataEntry::Long(-1)]);
+        assert_eq!(text.replace("ü", "ue").get(0).unwrap(), DataEntry::from("Grueezi"));
+        assert_eq!(text.slice(4, None).get(0).unwrap(), DataEntry::from("zi"));
+        assert_eq!(text.slice(4, Some(2)).get(0).unwrap(), DataEntry::from(""));
+        assert_eq!(text.slice(10, Some(12)).get(0).unwrap(), DataEntry::from(""));
+        assert_eq!(text.pad(9, PadSide::Both, '*').get(0).unwrap(), DataEntry::from("*Grüezi**"));
+        assert_eq!(text.pad(3, PadSide::Right, '*').get(0).unwrap(), DataEntry::from("Grüezi"));
+        assert_eq!(text.upper().name(), series.name());
+        assert_eq!(text.upper().data_type(), &DataType::Text);
+
+        let characters = Series::from(vec!['a']);
+        let result = characters.str();
+        assert!(matches!(result, Err(RaccoonError::TypeMismatch { expected: DataType::Text, .. })));
+        assert_eq!(Series::from(vec![DataEntry::NA]).str().unwrap().len(), vec![DataEntry::NA]);
+    }
+
+    #[test]
+    fn splitting() {
+        let series = Series::from(vec![DataEntry::from("a-b-c"), DataEntry::NA, DataEntry::from("d")]);
+        let parts = series.str().unwrap().split("-", None).unwrap();
+        assert_eq!((3, 3), parts.shape());
+        assert_eq!(parts["0"], vec![DataEntry::from("a"), DataEntry::NA, DataEntry::from("d")]);
+        assert_eq!(parts["2"], vec![DataEntry::from("c"), DataEntry::NA, DataEntry::NA]);
+        let parts = series.str().unwrap().split("-", Some(0)).unwrap();
+        assert_eq!(parts.columns(), vec!["0"]);
+        assert_eq!(parts["0"].get(0).unwrap(), DataEntry::from("a-b-c"));
+        assert!(matches!(series.str().unwrap().split("", None), Err(RaccoonError::InvalidArgument(_))));
+
+        let empty = Series::from(vec![DataEntry::NA]).str().unwrap().split(",", None).unwrap();
+        assert_eq!((1, 1), empty.shape());
+    }
+
+    #[test]
+    fn regular_expressions() {
+        let series = Series::from(vec![DataEntry::from("2021-03-14"), DataEntry::from("14.03.2021"), DataEntry::NA]);
+        let text = series.str().unwrap();
+        let matches = text.contains_regex(r"^\d{4}-").unwrap();
+        assert_eq!(matches, vec![DataEntry::Boolean(true), DataEntry::Boolean(false), DataEntry::NA]);
+        let replaced = text.replace_regex(r"(\d+)-(\d+)-(\d+)", "$3.$2.$1").unwrap();
+        assert_eq!(replaced, vec![DataEntry::from("14.03.2021"), DataEntry::from("14.03.2021"), DataEntry::NA]);
+
+        let parts = text.extract(r"^(?P<year>\d{4})-(\d+)(x)?").unwrap();
+        assert_eq!(parts.columns(), vec!["year", "1", "2"]);
+        assert_eq!(parts["year"], vec![DataEntry::from("2021"), DataEntry::NA, DataEntry::NA]);
+        assert_eq!(parts["1"], vec![DataEntry::from("03"), DataEntry::NA, DataEntry::NA]);
+        assert_eq!(parts["2"], vec![DataEntry::NA, DataEntry::NA, DataEntry::NA]);
+
+        assert!(matches!(text.extract(r"\d+"), Err(RaccoonError::InvalidArgument(_))));
+        assert!(matches!(text.contains_regex("("), Err(RaccoonError::InvalidArgument(_))));
+    }
+}
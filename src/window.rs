@@ -0,0 +1,683 @@
+//! Rolling and expanding window computations over a [`Series`].
+//!
+//! [`Series`]: ../series/struct.Series.html
+//!
+//! A [`Window`] aggregates, for every position of a series, the entries of a window around that position, and returns
+//! the results as a series of `DataType::Double` named after the original series. A rolling window, obtained using
+//! [`Series::rolling`], has a fixed size and ends at the position by default. An expanding window, obtained using
+//! [`Series::expanding`], starts at the beginning of the series and ends at the position.
+//!
+//! [`Window`]: ./struct.Window.html
+//! [`Series::rolling`]: ../series/struct.Series.html#method.rolling
+//! [`Series::expanding`]: ../series/struct.Series.html#method.expanding
+//!
+//! # Missing Values
+//! Missing entries, that is `DataEntry::NA` and NaN floating point numbers, are skipped by default, and a window yields
+//! a result as soon as it contains `min_periods` entries that are not missing. If missing entries are not skipped, a
+//! window containing one yields `DataEntry::NA`.
+//!
+//! # Performance
+//! The windows are updated incrementally as they slide along the series instead of being recomputed: `sum`, `mean`,
+//! `var` and `std` keep running sums, `min` and `max` keep monotonic deques of candidates, and `median` keeps the lower
+//! and the upper half of the window in two heaps. All but `median` and `apply` hence run in linear time, whatever the
+//! window size, and `median` takes logarithmic time in the window size per entry. Running sums are compensated using
+//! Neumaier's summation, such that entries leaving a window do not leave rounding errors behind.
+//!
+//! # Examples
+//! ```
+//! use raccoon::{DataEntry, Series};
+//!
+//! let series = Series::from(vec![DataEntry::Double(1.0), DataEntry::Double(3.0), DataEntry::NA,
+//!                                DataEntry::Double(5.0)]);
+//!
+//! let sums = series.rolling(2).sum().unwrap();
+//! assert_eq!(sums, vec![DataEntry::NA, DataEntry::Double(4.0), DataEntry::NA, DataEntry::NA]);
+//!
+//! let sums = series.rolling(2).min_periods(1).sum().unwrap();
+//! assert_eq!(sums, vec![1.0f64, 4.0, 3.0, 5.0]);
+//!
+//! let maxima = series.expanding().max().unwrap();
+//! assert_eq!(maxima, vec![1.0f64, 3.0, 3.0, 5.0]);
+//! ```
+
+use entry::{DataEntry, DataType};
+use error::{RaccoonResult, RaccoonError};
+use series::Series;
+
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, VecDeque};
+
+/// Computes aggregations over rolling or expanding windows of a series using configurable options.
+///
+/// # Example
+/// ```
+/// use raccoon::{DataEntry, Series};
+///
+/// let series = Series::from(vec![1, 2, 6, 3, 4]);
+///
+/// let means = series.rolling(3).center(true).mean().unwrap();
+/// assert_eq!(means, vec![DataEntry::NA, DataEntry::Double(3.0), DataEntry::Double(11.0 / 3.0),
+///                        DataEntry::Double(13.0 / 3.0), DataEntry::NA]);
+///
+/// let ranges = series.rolling(2).apply(|values| values[1] - values[0]).unwrap();
+/// assert_eq!(ranges, vec![DataEntry::NA, DataEntry::Double(1.0), DataEntry::Double(4.0),
+///                         DataEntry::Double(-3.0), DataEntry::Double(1.0)]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Window<'a> {
+    series: &'a Series,
+    size: Option<usize>,
+    min_periods: Option<usize>,
+    center: bool,
+    skip_na: bool,
+    ddof: usize,
+}
+
+impl<'a> Window<'a> {
+    /// Constructs a rolling window of `size` entries over `series`. By default, the window ends at the position it
+    /// belongs to, and a result requires `size` entries that are not missing.
+    pub fn rolling(series: &'a Series, size: usize) -> Window<'a> {
+        Window {
+            series,
+            size: Some(size),
+            min_periods: None,
+            center: false,
+            skip_na: true,
+            ddof: 1,
+        }
+    }
+
+    /// Constructs an expanding window over `series`, containing all entries up to the position it belongs to. By
+    /// default, a result requires a single entry that is not missing.
+    pub fn expanding(series: &'a Series) -> Window<'a> {
+        Window {
+            size: None,
+            ..Window::rolling(series, 0)
+        }
+    }
+
+    /// Sets the minimum number of entries that are not missing a window requires to yield a result. Defaults to the
+    /// size of a rolling window, and to 1 for an expanding window.
+    pub fn min_periods(mut self, min_periods: usize) -> Window<'a> {
+        self.min_periods = Some(min_periods);
+        self
+    }
+
+    /// Sets whether rolling windows are centered on the position they belong to, rather than ending at it. A window of
+    /// even size has one entry more before the position than after it. Has no effect on expanding windows.
+    pub fn center(mut self, center: bool) -> Window<'a> {
+        self.center = center;
+        self
+    }
+
+    /// Sets whether missing entries are skipped. If they are not skipped, a window containing a missing entry yields
+    /// `DataEntry::NA`. Defaults to `true`.
+    pub fn skip_na(mut self, skip_na: bool) -> Window<'a> {
+        self.skip_na = skip_na;
+        self
+    }
+
+    /// Sets the delta degrees of freedom used by `var` and `std`. The divisor used is `n - ddof`, where `n` is the
+    /// number of entries in the window. Defaults to 1, giving the sample variance.
+    pub fn ddof(mut self, ddof: usize) -> Window<'a> {
+        self.ddof = ddof;
+        self
+    }
+
+    /// Returns the sums of the windows. A window without entries sums to 0 if `min_periods` is 0.
+    ///
+    /// # Errors
+    /// - `RaccoonError::UnsupportedOperation` if the series is not numerical.
+    /// - `RaccoonError::InvalidArgument` if the size of a rolling window is 0, or smaller than `min_periods`.
+    pub fn sum(&self) -> RaccoonResult<Series> {
+        self.compute("sum", Sum::default())
+    }
+
+    /// Returns the arithmetic means of the windows.
+    ///
+    /// # Errors
+    /// See [`sum`](#method.sum).
+    pub fn mean(&self) -> RaccoonResult<Series> {
+        self.compute("mean", Mean::default())
+    }
+
+    /// Returns the variances of the windows, using `ddof` delta degrees of freedom.
+    ///
+    /// # Errors
+    /// See [`sum`](#method.sum).
+    pub fn var(&self) -> RaccoonResult<Series> {
+        self.compute("var", Variance::new(self.ddof, false))
+    }
+
+    /// Returns the standard deviations of the windows, using `ddof` delta degrees of freedom.
+    ///
+    /// # Errors
+    /// See [`sum`](#method.sum).
+    ///
+    /// # Example
+    /// ```
+    /// # use raccoon::{DataEntry, Series};
+    /// let series = Series::from(vec![2.0f64, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]);
+    /// let std = series.expanding().ddof(0).std().unwrap();
+    /// assert_eq!(std.get(7), Some(DataEntry::Double(2.0)));
+    /// ```
+    pub fn std(&self) -> RaccoonResult<Series> {
+        self.compute("std", Variance::new(self.ddof, true))
+    }
+
+    /// Returns the minima of the windows.
+    ///
+    /// # Errors
+    /// See [`sum`](#method.sum).
+    pub fn min(&self) -> RaccoonResult<Series> {
+        self.compute("min", Extremum::new(Ordering::Less))
+    }
+
+    /// Returns the maxima of the windows.
+    ///
+    /// # Errors
+    /// See [`sum`](#method.sum).
+    pub fn max(&self) -> RaccoonResult<Series> {
+        self.compute("max", Extremum::new(Ordering::Greater))
+    }
+
+    /// Returns the medians of the windows.
+    ///
+    /// # Errors
+    /// See [`sum`](#method.sum).
+    pub fn median(&self) -> RaccoonResult<Series> {
+        self.compute("median", Median::default())
+    }
+
+    /// Applies `function` to the entries of every window that are not missing, in the order of the series. Windows
+    /// with less than `min_periods` entries yield `DataEntry::NA` without calling `function`.
+    ///
+    /// # Errors
+    /// See [`sum`](#method.sum).
+    pub fn apply<F>(&self, function: F) -> RaccoonResult<Series> where F: Fn(&[f64]) -> f64 {
+        self.compute("apply", Apply { values: VecDeque::new(), function })
+    }
+
+    /// Slides the windows along the series, adding entries entering a window to `accumulator` and removing entries
+    /// leaving it, and collects the result of every window.
+    fn compute<A>(&self, operation: &str, mut accumulator: A) -> RaccoonResult<Series> where A: Accumulator {
+        let values = self.values(operation)?;
+        let min_periods = self.min_periods.unwrap_or_else(|| self.size.unwrap_or(1));
+        if let Some(size) = self.size {
+            if size == 0 || size < min_periods {
+                let message = format!("rolling window of size {} with min_periods {}", size, min_periods);
+                return Err(RaccoonError::InvalidArgument(message));
+            }
+        }
+
+        let length = values.len();
+        let offset = match self.size {
+            Some(size) if self.center   => (size - 1) / 2,
+            _                           => 0,
+        };
+        let (mut start, mut end) = (0, 0);
+        let (mut count, mut missing) = (0, 0);
+        let mut results = Vec::with_capacity(length);
+        for idx in 0..length {
+            let window_end = idx + 1 + offset;
+            let window_start = self.size.map_or(0, |size| window_end.saturating_sub(size));
+            while end < window_end.min(length) {
+                match values[end] {
+                    Some(value) => {
+                        accumulator.add(end, value);
+                        count += 1;
+                    },
+                    None        => missing += 1,
+                }
+                end += 1;
+            }
+            while start < window_start {
+                match values[start] {
+                    Some(value) => {
+                        accumulator.remove(start, value);
+                        count -= 1;
+                    },
+                    None        => missing -= 1,
+                }
+                start += 1;
+            }
+            let result = if count < min_periods || (missing > 0 && !self.skip_na) {
+                None
+            } else {
+                accumulator.result(count)
+            };
+            results.push(result.map_or(DataEntry::NA, DataEntry::Double));
+        }
+        Ok(self.series.with_entries(DataType::Double, results))
+    }
+
+    /// Returns the entries of the series as floating point numbers, `None` standing for missing entries.
+    ///
+    /// Fails with `RaccoonError::UnsupportedOperation` if the series is not numerical.
+    fn values(&self, operation: &str) -> RaccoonResult<Vec<Option<f64>>> {
+        let data_type = self.series.data_type();
+        if !data_type.is_numeric() && *data_type != DataType::NA {
+            let kind = if self.size.is_some() { "rolling" } else { "expanding" };
+            return Err(RaccoonError::UnsupportedOperation(format!("{} {} of {:?}", kind, operation, data_type)));
+        }
+        Ok(self.series.iter()
+            .map(|entry| match entry {
+                DataEntry::Integer(int)     => Some(int as f64),
+                DataEntry::UInteger(int)    => Some(int as f64),
+                DataEntry::Long(int)        => Some(int as f64),
+                DataEntry::ULong(int)       => Some(int as f64),
+                DataEntry::Float(f)         => Some(f as f64).filter(|f| !f.is_nan()),
+                DataEntry::Double(f)        => Some(f).filter(|f| !f.is_nan()),
+                _                           => None,
+            })
+            .collect())
+    }
+}
+
+/// The state of an aggregation over a sliding window. Entries are added in the order of the series, and removed in the
+/// same order. Missing entries are never added.
+trait Accumulator {
+    /// Adds the entry at position `idx` to the window.
+    fn add(&mut self, idx: usize, value: f64);
+
+    /// Removes the entry at position `idx`, the oldest entry of the window.
+    fn remove(&mut self, idx: usize, value: f64);
+
+    /// Returns the aggregation of the `count` entries of the window, or `None` if it is undefined.
+    fn result(&mut self, count: usize) -> Option<f64>;
+}
+
+/// A sum compensated using Neumaier's algorithm: the low-order bits lost when adding a value are accumulated
+/// separately and added back to the result.
+#[derive(Default, Clone, Copy)]
+struct CompensatedSum {
+    sum: f64,
+    compensation: f64,
+}
+
+impl CompensatedSum {
+    fn add(&mut self, value: f64) {
+        let sum = self.sum + value;
+        if self.sum.abs() >= value.abs() {
+            self.compensation += (self.sum - sum) + value;
+        } else {
+            self.compensation += (value - sum) + self.sum;
+        }
+        self.sum = sum;
+    }
+
+    fn value(&self) -> f64 {
+        self.sum + self.compensation
+    }
+}
+
+/// The running sum of the window.
+#[derive(Default)]
+struct Sum {
+    sum: CompensatedSum,
+}
+
+impl Accumulator for Sum {
+    fn add(&mut self, _: usize, value: f64) {
+        self.sum.add(value);
+    }
+
+    fn remove(&mut self, _: usize, value: f64) {
+        self.sum.add(-value);
+    }
+
+    fn result(&mut self, count: usize) -> Option<f64> {
+        if count == 0 {
+            // avoid carrying rounding errors into the next window
+            self.sum = CompensatedSum::default();
+        }
+        Some(self.sum.value())
+    }
+}
+
+/// The running mean of the window.
+#[derive(Default)]
+struct Mean {
+    sum: Sum,
+}
+
+impl Accumulator for Mean {
+    fn add(&mut self, idx: usize, value: f64) {
+        self.sum.add(idx, value);
+    }
+
+    fn remove(&mut self, idx: usize, value: f64) {
+        self.sum.remove(idx, value);
+    }
+
+    fn result(&mut self, count: usize) -> Option<f64> {
+        let sum = self.sum.result(count)?;
+        if count == 0 { None } else { Some(sum / count as f64) }
+    }
+}
+
+/// The running mean and sum of squared deviations of the window, updated using Welford's algorithm with compensated
+/// sums.
+struct Variance {
+    count: usize,
+    mean: CompensatedSum,
+    squares: CompensatedSum,
+    ddof: usize,
+    sqrt: bool,
+}
+
+impl Variance {
+    /// Constructs the accumulator of the variance, or of the standard deviation if `sqrt` is set.
+    fn new(ddof: usize, sqrt: bool) -> Variance {
+        Variance { count: 0, mean: CompensatedSum::default(), squares: CompensatedSum::default(), ddof, sqrt }
+    }
+}
+
+impl Accumulator for Variance {
+    fn add(&mut self, _: usize, value: f64) {
+        self.count += 1;
+        let delta = value - self.mean.value();
+        self.mean.add(delta / self.count as f64);
+        self.squares.add(delta * (value - self.mean.value()));
+    }
+
+    fn remove(&mut self, _: usize, value: f64) {
+        self.count -= 1;
+        if self.count == 0 {
+            self.mean = CompensatedSum::default();
+            self.squares = CompensatedSum::default();
+            return;
+        }
+        let delta = value - self.mean.value();
+        self.mean.add(-delta / self.count as f64);
+        self.squares.add(-delta * (value - self.mean.value()));
+    }
+
+    fn result(&mut self, count: usize) -> Option<f64> {
+        if count <= self.ddof {
+            return None;
+        }
+        // rounding errors could make the sum slightly negative for windows of equal entries
+        let variance = self.squares.value().max(0.0) / (count - self.ddof) as f64;
+        Some(if self.sqrt { variance.sqrt() } else { variance })
+    }
+}
+
+/// A monotonic deque of the candidates for the extremum of the window: each entry is followed only by entries ordered
+/// after it as `ordering`, such that the front of the deque is the extremum.
+struct Extremum {
+    candidates: VecDeque<(usize, f64)>,
+    ordering: Ordering,
+}
+
+impl Extremum {
+    /// Constructs the accumulator of the minimum for `Ordering::Less`, or of the maximum for `Ordering::Greater`.
+    fn new(ordering: Ordering) -> Extremum {
+        Extremum { candidates: VecDeque::new(), ordering }
+    }
+}
+
+impl Accumulator for Extremum {
+    fn add(&mut self, idx: usize, value: f64) {
+        // entries that are not better than the new one can never be the extremum again
+        while self.candidates.back().is_some_and(|&(_, last)| value.total_cmp(&last) != self.ordering.reverse()) {
+            self.candidates.pop_back();
+        }
+        self.candidates.push_back((idx, value));
+    }
+
+    fn remove(&mut self, idx: usize, _: f64) {
+        if self.candidates.front().is_some_and(|&(first, _)| first == idx) {
+            self.candidates.pop_front();
+        }
+    }
+
+    fn result(&mut self, _: usize) -> Option<f64> {
+        self.candidates.front().map(|&(_, value)| value)
+    }
+}
+
+/// An entry of the window, ordered by value and then by position such that entries are distinct.
+#[derive(Clone, Copy)]
+struct Key(f64, usize);
+
+impl PartialEq for Key {
+    fn eq(&self, other: &Key) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Key {}
+
+impl PartialOrd for Key {
+    fn partial_cmp(&self, other: &Key) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Key {
+    fn cmp(&self, other: &Key) -> Ordering {
+        self.0.total_cmp(&other.0).then(self.1.cmp(&other.1))
+    }
+}
+
+/// The lower half of the window in a max-heap and the upper half in a min-heap, the lower half holding one entry more
+/// if the window has an odd number of entries. Removed entries are only dropped once they reach the top of a heap:
+/// since entries leave the window in the order of the series, an entry is removed if its position is before `start`.
+#[derive(Default)]
+struct Median {
+    lower: BinaryHeap<Key>,
+    upper: BinaryHeap<Reverse<Key>>,
+    lower_len: usize,
+    upper_len: usize,
+    start: usize,
+}
+
+impl Median {
+    /// Drops the removed entries at the top of both heaps.
+    fn prune(&mut self) {
+        while self.lower.peek().is_some_and(|key| key.1 < self.start) {
+            self.lower.pop();
+        }
+        while self.upper.peek().is_some_and(|&Reverse(key)| key.1 < self.start) {
+            self.upper.pop();
+        }
+    }
+
+    /// Moves entries between the heaps until the lower half holds as many entries as the upper half, or one more.
+    fn rebalance(&mut self) {
+        while self.lower_len > self.upper_len + 1 {
+            let key = self.lower.pop().expect("the lower half is not empty");
+            self.upper.push(Reverse(key));
+            self.lower_len -= 1;
+            self.upper_len += 1;
+            self.prune();
+        }
+        while self.upper_len > self.lower_len {
+            let Reverse(key) = self.upper.pop().expect("the upper half is not empty");
+            self.lower.push(key);
+            self.upper_len -= 1;
+            self.lower_len += 1;
+            self.prune();
+        }
+        // removed entries buried in the heaps are dropped once they make up most of them
+        if self.lower.len() + self.upper.len() > 2 * (self.lower_len + self.upper_len) + 16 {
+            let start = self.start;
+            self.lower.retain(|key| key.1 >= start);
+            self.upper.retain(|&Reverse(key)| key.1 >= start);
+        }
+    }
+}
+
+impl Accumulator for Median {
+    fn add(&mut self, idx: usize, value: f64) {
+        let key = Key(value, idx);
+        if self.lower.peek().is_none_or(|&top| key <= top) {
+            self.lower.push(key);
+            self.lower_len += 1;
+        } else {
+            self.upper.push(Reverse(key));
+            self.upper_len += 1;
+        }
+        self.rebalance();
+    }
+
+    fn remove(&mut self, idx: usize, value: f64) {
+        // the tops are pruned, hence not removed yet, and tell in which half the entry is
+        if self.lower.peek().is_some_and(|&top| Key(value, idx) <= top) {
+            self.lower_len -= 1;
+        } else {
+            self.upper_len -= 1;
+        }
+        self.start = idx + 1;
+        self.prune();
+        self.rebalance();
+    }
+
+    fn result(&mut self, count: usize) -> Option<f64> {
+        let lower = self.lower.peek()?.0;
+        match count {
+            _ if count % 2 == 1     => Some(lower),
+            _                       => self.upper.peek().map(|&Reverse(upper)| (lower + upper.0) / 2.0),
+        }
+    }
+}
+
+/// The entries of the window in the order of the series, passed to a custom function.
+struct Apply<F> {
+    values: VecDeque<f64>,
+    function: F,
+}
+
+impl<F> Accumulator for Apply<F> where F: Fn(&[f64]) -> f64 {
+    fn add(&mut self, _: usize, value: f64) {
+        self.values.push_back(value);
+    }
+
+    fn remove(&mut self, _: usize, _: f64) {
+        self.values.pop_front();
+    }
+
+    fn result(&mut self, _: usize) -> Option<f64> {
+        Some((self.function)(self.values.make_contiguous()))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Computes the rolling aggregation naively, for comparison with the incremental implementation.
+    fn naive<F>(values: &[Option<f64>], size: usize, min_periods: usize, aggregate: F) -> Vec<DataEntry>
+        where F: Fn(&mut Vec<f64>) -> f64 {
+        (0..values.len())
+            .map(|idx| {
+                let window = &values[(idx + 1).saturating_sub(size)..=idx];
+                let mut window: Vec<f64> = window.iter().flatten().cloned().collect();
+                if window.len() < min_periods { DataEntry::NA } else { DataEntry::Double(aggregate(&mut window)) }
+            })
+            .collect()
+    }
+
+    fn close(result: &Series, expected: &[DataEntry]) -> bool {
+        result.len() == expected.len() && result.iter().zip(expected).all(|(a, b)| match (a, b) {
+            (DataEntry::Double(a), &DataEntry::Double(b))   => (a - b).abs() < 1e-9,
+            (a, b)                                          => a == *b,
+        })
+    }
+
+    #[test]
+    fn incremental_matches_naive() {
+        let values: Vec<Option<f64>> = (0..200)
+            .map(|idx: i64| if idx % 7 == 3 { None } else { Some(((idx * 37) % 23 - 11) as f64 / 4.0) })
+            .collect();
+        let entries: Vec<DataEntry> = values.iter().map(|x| x.map_or(DataEntry::NA, DataEntry::Double)).collect();
+        let series = Series::from(entries);
+        for &(size, min_periods) in &[(1, 1), (3, 2), (5, 5), (16, 3), (64, 20)] {
+            let window = series.rolling(size).min_periods(min_periods);
+            let sum = naive(&values, size, min_periods, |w| w.iter().sum());
+            assert!(close(&window.sum().unwrap(), &sum));
+            let mean = naive(&values, size, min_periods, |w| w.iter().sum::<f64>() / w.len() as f64);
+            assert!(close(&window.mean().unwrap(), &mean));
+            let min = naive(&values, size, min_periods, |w| w.iter().cloned().fold(f64::INFINITY, f64::min));
+            assert!(close(&window.min().unwrap(), &min));
+            let max = naive(&values, size, min_periods, |w| w.iter().cloned().fold(f64::NEG_INFINITY, f64::max));
+            assert!(close(&window.max().unwrap(), &max));
+            let median = naive(&values, size, min_periods, |w| {
+                w.sort_by(|a, b| a.total_cmp(b));
+                let n = w.len();
+                if n % 2 == 1 { w[n / 2] } else { (w[n / 2 - 1] + w[n / 2]) / 2.0 }
+            });
+            assert!(close(&window.median().unwrap(), &median));
+            let var = naive(&values, size, min_periods, |w| {
+                let mean = w.iter().sum::<f64>() / w.len() as f64;
+                w.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (w.len() as f64 - 1.0)
+            });
+            let var: Vec<DataEntry> = var.into_iter()
+                .map(|x| match x {
+                    DataEntry::Double(f) if !f.is_finite() => DataEntry::NA,
+                    x                                      => x,
+                })
+                .collect();
+            assert!(close(&window.var().unwrap(), &var));
+        }
+    }
+
+    #[test]
+    fn compensated_sums() {
+        let series = Series::from(vec![1e16, 1.0, 1.0, -1e16, 1.0]);
+        let sums = series.expanding().sum().unwrap();
+        assert_eq!(sums.get(3).unwrap(), DataEntry::Double(2.0));
+        let sums = series.rolling(3).sum().unwrap();
+        assert_eq!(sums.get(4).unwrap(), DataEntry::Double(-1e16 + 2.0));
+
+        let values: Vec<f64> = (0..1000).map(|idx| 1e9 + (idx % 4) as f64).collect();
+        let vars = Series::from(values).rolling(4).ddof(0).var().unwrap();
+        assert!(vars.iter().skip(3).all(|var| var == DataEntry::Double(1.25)));
+    }
+
+    #[test]
+    fn options() {
+        let series = Series::from(vec![DataEntry::Integer(1), DataEntry::NA, DataEntry::Integer(3),
+                                       DataEntry::Integer(4)]);
+        let sums = series.rolling(2).min_periods(1).skip_na(false).sum().unwrap();
+        assert_eq!(sums, vec![DataEntry::Double(1.0), DataEntry::NA, DataEntry::NA, DataEntry::Double(7.0)]);
+        let sums = series.rolling(2).min_periods(0).sum().unwrap();
+        assert_eq!(sums, vec![1.0f64, 1.0, 3.0, 7.0]);
+        let means = series.rolling(2).min_periods(0).mean().unwrap();
+        assert_eq!(means, vec![1.0f64, 1.0, 3.0, 3.5]);
+
+        let series = Series::from_vector("x".to_owned(), vec![1u32, 2, 3, 4, 5, 6]);
+        let sums = series.rolling(4).center(true).sum().unwrap();
+        assert_eq!(sums.name(), "x");
+        assert_eq!(sums, vec![DataEntry::NA, DataEntry::NA, DataEntry::Double(10.0), DataEntry::Double(14.0),
+                              DataEntry::Double(18.0), DataEntry::NA]);
+        let sums = series.rolling(4).center(true).min_periods(1).sum().unwrap();
+        assert_eq!(sums, vec![3.0f64, 6.0, 10.0, 14.0, 18.0, 15.0]);
+        let sums = series.expanding().min_periods(3).sum().unwrap();
+        assert_eq!(sums, vec![DataEntry::NA, DataEntry::NA, DataEntry::Double(6.0), DataEntry::Double(10.0),
+                              DataEntry::Double(15.0), DataEntry::Double(21.0)]);
+        let firsts = series.expanding().center(true).apply(|values| values[0]).unwrap();
+        assert_eq!(firsts, vec![1.0f64; 6]);
+        let std = series.rolling(2).ddof(0).std().unwrap();
+        assert_eq!(std.get(1).unwrap(), DataEntry::Double(0.5));
+        let var = series.rolling(1).var().unwrap();
+        assert_eq!(var, vec![DataEntry::NA; 6]);
+    }
+
+    #[test]
+    fn errors() {
+        let series = Series::from(vec![1, 2, 3]);
+        assert!(matches!(series.rolling(0).sum(), Err(RaccoonError::InvalidArgument(_))));
+        assert!(matches!(series.rolling(2).min_periods(3).sum(), Err(RaccoonError::InvalidArgument(_))));
+        assert!(series.expanding().min_periods(5).sum().is_ok());
+
+        let series = Series::from(vec!["a", "b"]);
+        assert!(matches!(series.rolling(1).max(), Err(RaccoonError::UnsupportedOperation(_))));
+        assert!(matches!(series.expanding().mean(), Err(RaccoonError::UnsupportedOperation(_))));
+
+        let series = Series::from(vec![DataEntry::NA, DataEntry::NA]);
+        assert_eq!(series.rolling(1).mean().unwrap(), vec![DataEntry::NA, DataEntry::NA]);
+    }
+}